@@ -0,0 +1,61 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use std::num::ParseIntError;
+
+pub fn part1(values: &[i64]) -> i64 {
+    values.iter().map(|x| x / 3 - 2).sum()
+}
+
+pub fn part2(values: &[i64]) -> i64 {
+    values.iter().map(|&x| fuel_requirement(x)).sum()
+}
+
+fn fuel_requirement(value: i64) -> i64 {
+    let mut x = value;
+    let mut result = 0;
+    while x > 0 {
+        x = x / 3 - 2;
+        if x > 0 {
+            result += x;
+        }
+    }
+    result
+}
+
+pub fn parse_values(input: &str) -> anyhow::Result<Vec<i64>> {
+    let values: Result<Vec<i64>, ParseIntError> = input.lines().map(|l| l.parse::<i64>()).collect();
+    let values = values?;
+    Ok(values)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse_values(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuel_requirement() {
+        assert_eq!(fuel_requirement(1969), 966);
+        assert_eq!(fuel_requirement(100756), 50346);
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use intcode::{parse_program, Vm};
+use std::fmt::Display;
+
+pub fn part1(program: &[i64]) -> anyhow::Result<i64> {
+    Ok(run_program(program, 12, 2)?)
+}
+
+pub fn part2(program: &[i64]) -> anyhow::Result<i64> {
+    for noun in 0..=99 {
+        for verb in 0..=99 {
+            if run_program(program, noun, verb)? == 19690720 {
+                return Ok(100 * noun + verb);
+            }
+        }
+    }
+    Err(anyhow!("no solution"))
+}
+
+fn run_program(program: &[i64], noun: i64, verb: i64) -> Result<i64, intcode::Error> {
+    let mut vm = Vm::new(program);
+    vm.write(1, noun)?;
+    vm.write(2, verb)?;
+    vm.run_to_halt()?;
+    Ok(vm.read(0))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_program(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
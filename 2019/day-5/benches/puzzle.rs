@@ -0,0 +1 @@
+aoc_bench::main!(y2019_day_5::Puzzle);
@@ -0,0 +1,41 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use intcode::parse_program;
+use std::fmt::Display;
+
+pub fn part1(program: &[i64]) -> anyhow::Result<i64> {
+    diagnostic_code(program, 1)
+}
+
+pub fn part2(program: &[i64]) -> anyhow::Result<i64> {
+    diagnostic_code(program, 5)
+}
+
+fn diagnostic_code(program: &[i64], system_id: i64) -> anyhow::Result<i64> {
+    let output = intcode::run(program, &[system_id])?;
+    output
+        .last()
+        .copied()
+        .ok_or_else(|| anyhow!("no diagnostic code"))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_program(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
@@ -0,0 +1,193 @@
+extern crate core;
+
+use anyhow::anyhow;
+use aoc_core::Solution;
+use graph_export::{Graph, Shape, Style};
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, line_ending};
+use nom::multi::separated_list0;
+use nom::IResult;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+
+#[derive(Debug, Clone)]
+struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+/// The objects orbiting each object.
+pub type Orbits<'a> = BTreeMap<&'a str, Vec<&'a str>>;
+
+pub fn part1(adj: &Orbits) -> anyhow::Result<i32> {
+    let mut q = VecDeque::from([(0, "COM")]);
+    let mut result = 0;
+
+    while let Some((d, current)) = q.pop_front() {
+        result += d;
+        if let Some(a) = adj.get(&current) {
+            for b in a.iter() {
+                q.push_back((d + 1, b))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part2(adj: &Orbits) -> anyhow::Result<i32> {
+    let inv = invert(adj);
+    let mut q = VecDeque::from([(0, "YOU")]);
+    let mut visited: BTreeSet<&str> = BTreeSet::from(["YOU"]);
+
+    while let Some((d, current)) = q.pop_front() {
+        if current == "SAN" {
+            return Ok(d - 2);
+        }
+        if let Some(a) = adj.get(&current) {
+            for b in a.iter() {
+                if visited.insert(b) {
+                    q.push_back((d + 1, b))
+                }
+            }
+        }
+        if let Some(a) = inv.get(&current) {
+            for b in a.iter() {
+                if visited.insert(b) {
+                    q.push_back((d + 1, b))
+                }
+            }
+        }
+    }
+
+    Err(anyhow!("'SAN' not found"))
+}
+
+/// The orbit map as a graph from every object to the objects orbiting it.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let adj = parse(input)?;
+    let mut graph = Graph::directed();
+    graph.style("center", Style::new().shape(Shape::Circle).fill("#ffd700"));
+    graph.style("ship", Style::new().shape(Shape::Hexagon).fill("#a8c8f0"));
+    graph.node("COM").kind("center");
+    for (from, to) in adj.iter() {
+        for to in to.iter() {
+            graph.edge(from, to);
+        }
+    }
+    for ship in ["YOU", "SAN"] {
+        if adj.values().flatten().any(|&o| o == ship) {
+            graph.node(ship).kind("ship");
+        }
+    }
+    Ok(graph)
+}
+
+fn invert<'a>(adj: &BTreeMap<&'a str, Vec<&'a str>>) -> BTreeMap<&'a str, Vec<&'a str>> {
+    let mut result = BTreeMap::new();
+    for (from, bla) in adj.iter() {
+        for to in bla.iter() {
+            let v: &mut Vec<&str> = result.entry(*to).or_default();
+            v.push(from);
+        }
+    }
+    result
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Orbits<'_>> {
+    let edges = parse_error::nom(input, parse_edges)?;
+    let adj = edges
+        .into_iter()
+        .fold(BTreeMap::new(), |mut acc: BTreeMap<&str, Vec<&str>>, e| {
+            let ent = acc.entry(e.from).or_default();
+            ent.push(e.to);
+            acc
+        });
+    Ok(adj)
+}
+
+fn parse_edges(input: &str) -> IResult<&str, Vec<Edge>> {
+    separated_list0(line_ending, parse_edge)(input)
+}
+
+fn parse_edge(input: &str) -> IResult<&str, Edge> {
+    let (rest, from) = alphanumeric1(input)?;
+    let (rest, _) = tag(")")(rest)?;
+    let (rest, to) = alphanumeric1(rest)?;
+    Ok((rest, Edge { from: from, to: to }))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Orbits<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let input = r#"COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L"#;
+        let result = part1(&parse(input)?)?;
+        assert_eq!(result, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let input = r#"COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L
+K)YOU
+I)SAN"#;
+        let result = part2(&parse(input)?)?;
+        assert_eq!(result, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph("COM)B\nB)C\nB)YOU\nC)SAN")?;
+        assert_eq!(graph.node_count(), 5);
+        assert_eq!(graph.edge_count(), 4);
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("n0((\"COM\"))"));
+        assert!(mermaid.contains("class n3,n4 ship"));
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use intcode::{parse_program, Network, Outcome, Vm};
+use itertools::Itertools;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+pub fn part1(program: &[i64]) -> anyhow::Result<i64> {
+    max_signal(program, 0..=4, false)
+}
+
+pub fn part2(program: &[i64]) -> anyhow::Result<i64> {
+    max_signal(program, 5..=9, true)
+}
+
+fn max_signal(program: &[i64], phases: RangeInclusive<i64>, feedback: bool) -> anyhow::Result<i64> {
+    let mut max_result = 0;
+    for phases in phases.permutations(5) {
+        max_result = max_result.max(amplify(program, &phases, feedback)?);
+    }
+    Ok(max_result)
+}
+
+/// Runs one amplifier per phase setting, connected in a chain or, with `feedback`, in a
+/// ring, and returns the last signal of the final amplifier.
+fn amplify(program: &[i64], phases: &[i64], feedback: bool) -> anyhow::Result<i64> {
+    let mut network = Network::new();
+    let amps = phases
+        .iter()
+        .map(|&phase| {
+            let mut amp = Vm::new(program);
+            amp.push_input(phase);
+            network.add(amp)
+        })
+        .collect_vec();
+    for (from, to) in amps.iter().tuple_windows() {
+        network.connect(*from, *to)?;
+    }
+    let (first, last) = (amps[0], amps[amps.len() - 1]);
+    if feedback {
+        network.connect(last, first)?;
+    }
+    network.push_input(first, 0)?;
+    match network.run()? {
+        Outcome::Halted => network
+            .log(last)?
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("amplifier produced no output")),
+        outcome => Err(anyhow!("amplifiers stopped with {outcome:?}")),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_program(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let input = r#"3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"#;
+        let result = part1(&parse_program(input)?)?;
+        assert_eq!(result, 43210);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_works2() -> anyhow::Result<()> {
+        let input = r#"3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0"#;
+        let result = part1(&parse_program(input)?)?;
+        assert_eq!(result, 54321);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_works3() -> anyhow::Result<()> {
+        let input = r#"3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0"#;
+        let result = part1(&parse_program(input)?)?;
+        assert_eq!(result, 65210);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_assembled_amplifier() -> anyhow::Result<()> {
+        // Same amplifier as the first example: outputs `signal * 10 + phase`.
+        let program = intcode::assemble(
+            "
+                    in [phase]
+                    in [signal]
+                    mul [signal], 10, [signal]
+                    add [signal], [phase], [phase]
+                    out [phase]
+                    hlt
+            phase:  data 0
+            signal: data 0
+            ",
+        )?;
+        assert_eq!(
+            program,
+            parse_program("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0")?
+        );
+        assert_eq!(part1(&program)?, 43210);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let input = r#"3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5"#;
+        let result = part2(&parse_program(input)?)?;
+        assert_eq!(result, 139629729);
+        Ok(())
+    }
+}
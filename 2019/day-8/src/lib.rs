@@ -0,0 +1,89 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use std::fmt::Display;
+
+extern crate core;
+
+pub fn part1(pixels: &[u32]) -> anyhow::Result<usize> {
+    let (layer, _) = pixels
+        .chunks(25 * 6)
+        .map(|layer| {
+            let zero_count = layer.iter().filter(|p| **p == 0).count();
+            (layer, zero_count)
+        })
+        .min_by(|(l1, count1), (l2, count2)| count1.cmp(count2))
+        .unwrap();
+
+    let num_ones = layer.iter().filter(|p| **p == 1).count();
+    let num_twos = layer.iter().filter(|p| **p == 2).count();
+
+    Ok(num_ones * num_twos)
+}
+
+pub fn part2(pixels: &[u32]) -> anyhow::Result<String> {
+    ocr::decode(&render_image(pixels, 25, 6))
+}
+
+/// Stacks the layers and draws the resulting image, one line per row.
+pub fn render_image(pixels: &[u32], width: usize, height: usize) -> String {
+    let size = width * height;
+    let mut img = vec![2u32; size];
+
+    for layer in pixels.chunks(size).rev() {
+        for i in 0..size {
+            let p1 = img[i];
+            let p2 = layer[i];
+            img[i] = if p2 == 2 { p1 } else { p2 }
+        }
+    }
+
+    let mut result = String::with_capacity(size + height);
+    for i in 0..height {
+        for j in 0..width {
+            result.push(if img[i * width + j] == 0 { ' ' } else { '#' });
+        }
+        result.push('\n');
+    }
+    result
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+    input
+        .trim()
+        .chars()
+        .map(|c| c.to_digit(10).ok_or_else(|| anyhow!("invalid pixel {c:?}")))
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_image() -> anyhow::Result<()> {
+        let input = r#"0222112222120000"#;
+        assert_eq!(render_image(&parse(input)?, 2, 2), " #\n# \n");
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use intcode::parse_program;
+use std::fmt::Display;
+
+pub fn part1(program: &[i64]) -> anyhow::Result<i64> {
+    boost_keycode(program, 1)
+}
+
+pub fn part2(program: &[i64]) -> anyhow::Result<i64> {
+    boost_keycode(program, 2)
+}
+
+fn boost_keycode(program: &[i64], mode: i64) -> anyhow::Result<i64> {
+    let output = intcode::run(program, &[mode])?;
+    match output.as_slice() {
+        [keycode] => Ok(*keycode),
+        _ => Err(anyhow!("malfunctioning opcodes: {output:?}")),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(parse_program(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let input = r#"1102,34915192,34915192,7,4,7,99,0"#;
+        let result = part1(&parse_program(input)?)?;
+        assert_eq!(result, 1219070632396864);
+        Ok(())
+    }
+}
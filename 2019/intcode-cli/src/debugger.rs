@@ -0,0 +1,258 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+use intcode::disasm::line_at;
+use intcode::{Event, Vm};
+
+pub const HELP: &str = "\
+step [n]          execute n instructions (default 1)
+continue          run until a breakpoint, watchpoint, input request or halt
+break <addr>      set a breakpoint, without an address list all breakpoints
+delete <addr>     remove a breakpoint
+watch <addr>      stop when the memory cell is written
+unwatch <addr>    remove a watchpoint
+input <v>...      queue input values
+print <addr> [n]  print n memory cells (default 1)
+registers         print ip, relative base and queued input
+list [addr] [n]   disassemble n instructions from addr (default ip, 10)
+output            print all output produced so far
+quit              leave the debugger
+An empty line repeats the previous command.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Option<usize>),
+    Delete(usize),
+    Watch(usize),
+    Unwatch(usize),
+    Input(Vec<i64>),
+    Print(usize, usize),
+    Registers,
+    List(Option<usize>, usize),
+    Output,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> anyhow::Result<Self> {
+        let mut words = line.split_whitespace();
+        let name = words.next().ok_or_else(|| anyhow!("empty command"))?;
+        let arguments: Vec<&str> = words.collect();
+        let number = |index: usize| -> anyhow::Result<Option<usize>> {
+            arguments
+                .get(index)
+                .map(|argument| {
+                    argument
+                        .parse()
+                        .with_context(|| format!("invalid number {argument:?}"))
+                })
+                .transpose()
+        };
+        let address =
+            |index: usize| number(index)?.ok_or_else(|| anyhow!("{name} needs an address"));
+        let command = match name {
+            "s" | "step" => Command::Step(number(0)?.unwrap_or(1)),
+            "c" | "continue" => Command::Continue,
+            "b" | "break" => Command::Break(number(0)?),
+            "d" | "delete" => Command::Delete(address(0)?),
+            "w" | "watch" => Command::Watch(address(0)?),
+            "unwatch" => Command::Unwatch(address(0)?),
+            "i" | "input" => Command::Input(
+                arguments
+                    .iter()
+                    .map(|value| {
+                        value
+                            .parse()
+                            .with_context(|| format!("invalid input value {value:?}"))
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+            ),
+            "p" | "print" => Command::Print(address(0)?, number(1)?.unwrap_or(1)),
+            "r" | "registers" => Command::Registers,
+            "l" | "list" => Command::List(number(0)?, number(1)?.unwrap_or(10)),
+            "o" | "output" => Command::Output,
+            "h" | "help" => Command::Help,
+            "q" | "quit" => Command::Quit,
+            _ => bail!("unknown command {name:?}, try `help`"),
+        };
+        Ok(command)
+    }
+}
+
+/// Interactive debugger state: the VM plus breakpoints, watchpoints and the output so far.
+pub struct Debugger {
+    vm: Vm,
+    breakpoints: BTreeSet<usize>,
+    watchpoints: BTreeSet<usize>,
+    output: Vec<i64>,
+}
+
+impl Debugger {
+    pub fn new(vm: Vm) -> Self {
+        Self {
+            vm,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            output: Vec::new(),
+        }
+    }
+
+    /// Executes a command and returns the text to show to the user.
+    pub fn execute(&mut self, command: &Command) -> anyhow::Result<String> {
+        let mut out = String::new();
+        match command {
+            Command::Step(count) => {
+                for _ in 0..*count {
+                    let trace = self.vm.trace_step()?;
+                    writeln!(out, "{trace}")?;
+                    if let Some(Event::Output(value)) = trace.event {
+                        self.output.push(value);
+                    }
+                    if matches!(trace.event, Some(Event::NeedsInput | Event::Halted)) {
+                        break;
+                    }
+                }
+            }
+            Command::Continue => {
+                let reason = self.resume()?;
+                writeln!(out, "{reason}")?;
+            }
+            Command::Break(None) => {
+                for address in &self.breakpoints {
+                    writeln!(out, "breakpoint at {address}")?;
+                }
+            }
+            Command::Break(Some(address)) => {
+                self.breakpoints.insert(*address);
+            }
+            Command::Delete(address) => {
+                if !self.breakpoints.remove(address) {
+                    bail!("no breakpoint at {address}");
+                }
+            }
+            Command::Watch(address) => {
+                self.watchpoints.insert(*address);
+            }
+            Command::Unwatch(address) => {
+                if !self.watchpoints.remove(address) {
+                    bail!("no watchpoint at {address}");
+                }
+            }
+            Command::Input(values) => self.vm.extend_input(values.iter().copied()),
+            Command::Print(address, count) => {
+                let end = address.checked_add(*count).ok_or_else(|| {
+                    anyhow!("{count} values from {address} run past the last address")
+                })?;
+                for address in *address..end {
+                    writeln!(out, "[{address}] = {}", self.vm.read(address))?;
+                }
+            }
+            Command::Registers => {
+                writeln!(out, "ip = {}", self.vm.ip())?;
+                writeln!(out, "rb = {}", self.vm.relative_base())?;
+                writeln!(out, "input = {:?}", self.vm.input())?;
+            }
+            Command::List(address, count) => {
+                let address = address.unwrap_or(self.vm.ip());
+                let memory = self.vm.memory();
+                let mut lines = Vec::new();
+                let mut next = address;
+                while next < memory.len() && lines.len() < *count {
+                    let line = line_at(memory, next);
+                    next += line.size();
+                    lines.push(line);
+                }
+                for line in lines {
+                    let marker = if line.address == self.vm.ip() {
+                        "=>"
+                    } else {
+                        "  "
+                    };
+                    writeln!(out, "{marker} {line}")?;
+                }
+            }
+            Command::Output => writeln!(out, "{:?}", self.output)?,
+            Command::Help => writeln!(out, "{HELP}")?,
+            Command::Quit => {}
+        }
+        Ok(out)
+    }
+
+    /// Runs until something stops the program and describes why it stopped.
+    fn resume(&mut self) -> anyhow::Result<String> {
+        let mut first = true;
+        loop {
+            if !first && self.breakpoints.contains(&self.vm.ip()) {
+                return Ok(format!("breakpoint at {}", self.vm.ip()));
+            }
+            first = false;
+            let trace = self.vm.trace_step()?;
+            match trace.event {
+                Some(Event::Output(value)) => self.output.push(value),
+                Some(Event::NeedsInput) => return Ok(format!("waiting for input at {}", trace.ip)),
+                Some(Event::Halted) => return Ok(format!("halted at {}", trace.ip)),
+                None => {}
+            }
+            if let Some(write) = trace
+                .write
+                .filter(|write| self.watchpoints.contains(&write.address))
+            {
+                return Ok(format!(
+                    "watchpoint [{}] {} -> {} at {}",
+                    write.address, write.old, write.new, trace.ip
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(debugger: &mut Debugger, line: &str) -> String {
+        let command: Command = line.parse().unwrap();
+        debugger.execute(&command).unwrap()
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!("s".parse::<Command>().unwrap(), Command::Step(1));
+        assert_eq!("step 5".parse::<Command>().unwrap(), Command::Step(5));
+        assert_eq!(
+            "print 4 2".parse::<Command>().unwrap(),
+            Command::Print(4, 2)
+        );
+        assert_eq!(
+            "input 1 -2".parse::<Command>().unwrap(),
+            Command::Input(vec![1, -2])
+        );
+        assert!("break x".parse::<Command>().is_err());
+        assert!("watch".parse::<Command>().is_err());
+        assert!("frobnicate".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn breakpoints_and_watchpoints() {
+        // Reads two numbers, stores their sum at 13 and prints it.
+        let program = [3, 11, 3, 12, 1, 11, 12, 13, 4, 13, 99, 0, 0, 0];
+        let mut debugger = Debugger::new(Vm::new(&program));
+        assert_eq!(run(&mut debugger, "continue"), "waiting for input at 0\n");
+        run(&mut debugger, "input 20 22");
+        run(&mut debugger, "break 8");
+        run(&mut debugger, "watch 12");
+        assert_eq!(run(&mut debugger, "c"), "watchpoint [12] 0 -> 22 at 2\n");
+        assert_eq!(run(&mut debugger, "c"), "breakpoint at 8\n");
+        assert_eq!(run(&mut debugger, "print 13"), "[13] = 42\n");
+        assert!(debugger.execute(&Command::Print(usize::MAX, 2)).is_err());
+        assert_eq!(run(&mut debugger, "c"), "halted at 10\n");
+        assert_eq!(run(&mut debugger, "output"), "[42]\n");
+    }
+}
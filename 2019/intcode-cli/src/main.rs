@@ -0,0 +1,131 @@
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand};
+use intcode::{assemble, disassemble, parse_program, Event, Vm, DEFAULT_MEMORY_LIMIT};
+
+use crate::debugger::{Command as DebugCommand, Debugger};
+
+mod debugger;
+
+#[derive(Debug, Parser)]
+#[command(name = "intcode", about = "Tools for inspecting Intcode programs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Assembles a source file and prints the comma separated program
+    Asm { source: PathBuf },
+    /// Prints the program as assembly
+    Disasm { program: PathBuf },
+    /// Runs the program and prints every executed instruction
+    Trace {
+        program: PathBuf,
+        /// Comma separated input values
+        #[arg(short, long, value_delimiter = ',', allow_negative_numbers = true)]
+        input: Vec<i64>,
+        /// Number of memory cells the program may use
+        #[arg(long, default_value_t = DEFAULT_MEMORY_LIMIT)]
+        memory_limit: usize,
+    },
+    /// Starts an interactive debugger
+    Debug {
+        program: PathBuf,
+        /// Comma separated input values
+        #[arg(short, long, value_delimiter = ',', allow_negative_numbers = true)]
+        input: Vec<i64>,
+        /// Number of memory cells the program may use
+        #[arg(long, default_value_t = DEFAULT_MEMORY_LIMIT)]
+        memory_limit: usize,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Asm { source } => {
+            let text = std::fs::read_to_string(&source)
+                .with_context(|| format!("cannot read {}", source.display()))?;
+            let program = assemble(&text)?;
+            let values: Vec<_> = program.iter().map(|value| value.to_string()).collect();
+            println!("{}", values.join(","));
+        }
+        Command::Disasm { program } => {
+            for line in disassemble(&load(&program)?) {
+                println!("{line}");
+            }
+        }
+        Command::Trace {
+            program,
+            input,
+            memory_limit,
+        } => {
+            let mut vm = Vm::new(&load(&program)?).memory_limit(memory_limit);
+            vm.extend_input(input);
+            loop {
+                let trace = vm.trace_step()?;
+                println!("{trace}");
+                match trace.event {
+                    Some(Event::Halted) => break,
+                    Some(Event::NeedsInput) => bail!("program needs more input at {}", trace.ip),
+                    _ => {}
+                }
+            }
+        }
+        Command::Debug {
+            program,
+            input,
+            memory_limit,
+        } => {
+            let mut vm = Vm::new(&load(&program)?).memory_limit(memory_limit);
+            vm.extend_input(input);
+            debug(Debugger::new(vm))?;
+        }
+    }
+    Ok(())
+}
+
+fn load(path: &PathBuf) -> anyhow::Result<Vec<i64>> {
+    let text =
+        std::fs::read_to_string(path).with_context(|| format!("cannot read {}", path.display()))?;
+    Ok(parse_program(&text)?)
+}
+
+fn debug(mut debugger: Debugger) -> anyhow::Result<()> {
+    let stdin = io::stdin();
+    let mut previous: Option<DebugCommand> = None;
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        let command = if line.trim().is_empty() {
+            match previous.clone() {
+                Some(command) => command,
+                None => continue,
+            }
+        } else {
+            match line.parse::<DebugCommand>() {
+                Ok(command) => command,
+                Err(error) => {
+                    println!("{error:#}");
+                    continue;
+                }
+            }
+        };
+        if command == DebugCommand::Quit {
+            return Ok(());
+        }
+        match debugger.execute(&command) {
+            Ok(text) => print!("{text}"),
+            Err(error) => println!("error: {error:#}"),
+        }
+        previous = Some(command);
+    }
+}
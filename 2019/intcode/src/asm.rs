@@ -0,0 +1,432 @@
+//! Assembler for the syntax printed by the [disassembler](crate::disasm).
+//!
+//! ```text
+//! ; Echoes its input until it reads a zero.
+//! macro jmp target
+//!     jt 1, target
+//! endm
+//!
+//! loop:   in [value]
+//!         jf [value], done
+//!         out [value]
+//!         jmp loop
+//! done:   hlt
+//! value:  data 0
+//! ```
+//!
+//! Operands are immediate (`5`), position (`[5]`) or relative (`[rb+5]`). Wherever a number
+//! is expected a label or a sum like `value+1` may be used; labels evaluate to their
+//! address. `data` emits its values verbatim. A `macro name params...` block up to `endm`
+//! defines a macro whose parameters are replaced by the arguments of each invocation.
+
+use std::collections::HashMap;
+
+use crate::{Error, Instruction, Mode, Opcode, Operand};
+
+const MAX_MACRO_DEPTH: usize = 32;
+
+/// Assembles `source` into an Intcode program.
+pub fn assemble(source: &str) -> Result<Vec<i64>, Error> {
+    let (macros, lines) = collect_macros(source)?;
+    let mut expanded = Vec::new();
+    for (line, text) in lines {
+        expand(&macros, line, text.to_string(), 0, &mut expanded)?;
+    }
+
+    let mut statements = Vec::new();
+    let mut labels = HashMap::new();
+    let mut address = 0;
+    for (line, text) in &expanded {
+        let (names, statement) = parse_line(text).map_err(|message| error(*line, message))?;
+        for name in names {
+            if labels.insert(name.to_string(), address as i64).is_some() {
+                return Err(error(*line, format!("duplicate label {name:?}")));
+            }
+        }
+        if let Some(statement) = statement {
+            address += statement.size();
+            statements.push((*line, statement));
+        }
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (line, statement) in statements {
+        statement
+            .emit(&labels, &mut program)
+            .map_err(|message| error(line, message))?;
+    }
+    Ok(program)
+}
+
+fn error(line: usize, message: impl Into<String>) -> Error {
+    Error::Assemble {
+        line,
+        message: message.into(),
+    }
+}
+
+struct Macro<'a> {
+    parameters: Vec<&'a str>,
+    body: Vec<&'a str>,
+}
+
+type Macros<'a> = HashMap<&'a str, Macro<'a>>;
+
+/// A source line with its 1-based line number.
+type SourceLine<'a> = (usize, &'a str);
+
+/// Splits the source into macro definitions and the remaining numbered, comment free lines.
+fn collect_macros(source: &str) -> Result<(Macros<'_>, Vec<SourceLine<'_>>), Error> {
+    let mut macros = HashMap::new();
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, &str, Macro)> = None;
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let text = text.split(';').next().unwrap_or_default().trim();
+        if text.is_empty() {
+            continue;
+        }
+        let (keyword, rest) = split_word(text);
+        match (keyword, &mut current) {
+            ("macro", Some(_)) => return Err(error(line, "nested macro definition")),
+            ("macro", None) => {
+                let (name, parameters) = split_word(rest);
+                if !is_identifier(name) || is_reserved(name) {
+                    return Err(error(line, format!("invalid macro name {name:?}")));
+                }
+                let parameters: Vec<_> = parameters
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|parameter| !parameter.is_empty())
+                    .collect();
+                if let Some(parameter) = parameters.iter().find(|p| !is_identifier(p)) {
+                    return Err(error(
+                        line,
+                        format!("invalid macro parameter {parameter:?}"),
+                    ));
+                }
+                let body = Vec::new();
+                current = Some((line, name, Macro { parameters, body }));
+            }
+            ("endm", Some(_)) => {
+                let (line, name, definition) = current.take().unwrap();
+                if macros.insert(name, definition).is_some() {
+                    return Err(error(line, format!("duplicate macro {name:?}")));
+                }
+            }
+            ("endm", None) => return Err(error(line, "endm without macro")),
+            (_, Some((_, _, definition))) => definition.body.push(text),
+            (_, None) => lines.push((line, text)),
+        }
+    }
+    if let Some((line, name, _)) = current {
+        return Err(error(line, format!("macro {name:?} is missing endm")));
+    }
+    Ok((macros, lines))
+}
+
+/// Expands macro invocations in `text` recursively. Expanded lines keep the line number of
+/// the outermost invocation.
+fn expand(
+    macros: &Macros,
+    line: usize,
+    text: String,
+    depth: usize,
+    out: &mut Vec<(usize, String)>,
+) -> Result<(), Error> {
+    let (labels, rest) = split_labels(&text);
+    let (name, arguments) = split_word(rest);
+    let Some(definition) = macros.get(name) else {
+        out.push((line, text));
+        return Ok(());
+    };
+    if depth == MAX_MACRO_DEPTH {
+        return Err(error(line, format!("macro {name:?} expands too deeply")));
+    }
+    let arguments: Vec<_> = split_operands(arguments);
+    if arguments.len() != definition.parameters.len() {
+        return Err(error(
+            line,
+            format!(
+                "macro {name:?} takes {} arguments, got {}",
+                definition.parameters.len(),
+                arguments.len()
+            ),
+        ));
+    }
+    if !labels.is_empty() {
+        out.push((
+            line,
+            labels.iter().map(|label| format!("{label}:")).collect(),
+        ));
+    }
+    for body in &definition.body {
+        let text = substitute(body, &definition.parameters, &arguments);
+        expand(macros, line, text, depth + 1, out)?;
+    }
+    Ok(())
+}
+
+/// Replaces every identifier in `text` that names a parameter with its argument.
+fn substitute(text: &str, parameters: &[&str], arguments: &[&str]) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(is_identifier_start) {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !is_identifier_char(c))
+            .unwrap_or(rest.len());
+        let word = &rest[..end];
+        match parameters.iter().position(|parameter| *parameter == word) {
+            Some(index) => result.push_str(arguments[index]),
+            None => result.push_str(word),
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+enum Statement<'a> {
+    Instruction(Opcode, Vec<&'a str>),
+    Data(Vec<&'a str>),
+}
+
+impl Statement<'_> {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Instruction(opcode, _) => 1 + opcode.parameter_count(),
+            Statement::Data(values) => values.len(),
+        }
+    }
+
+    fn emit(&self, labels: &HashMap<String, i64>, program: &mut Vec<i64>) -> Result<(), String> {
+        match self {
+            Statement::Instruction(opcode, operands) => {
+                let operands = operands
+                    .iter()
+                    .map(|operand| parse_operand(operand, labels))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut modes = [Mode::Position; 3];
+                for (mode, operand) in modes.iter_mut().zip(&operands) {
+                    *mode = operand.mode;
+                }
+                if let Some(index) = opcode.write_parameter() {
+                    if modes[index] == Mode::Immediate {
+                        return Err(format!("{opcode} cannot write to an immediate operand"));
+                    }
+                }
+                program.push(Instruction::new(*opcode, modes).encode());
+                program.extend(operands.iter().map(|operand| operand.value));
+            }
+            Statement::Data(values) => {
+                for value in values {
+                    program.push(evaluate(value, labels)?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a line without comments into its labels and statement.
+fn parse_line(text: &str) -> Result<(Vec<&str>, Option<Statement<'_>>), String> {
+    let (labels, rest) = split_labels(text);
+    if let Some(label) = labels.iter().find(|label| is_reserved(label)) {
+        return Err(format!("{label:?} cannot be used as a label"));
+    }
+    if rest.is_empty() {
+        return Ok((labels, None));
+    }
+    let (name, operands) = split_word(rest);
+    let operands = split_operands(operands);
+    if name == "data" {
+        if operands.is_empty() {
+            return Err("data needs at least one value".to_string());
+        }
+        return Ok((labels, Some(Statement::Data(operands))));
+    }
+    let opcode = Opcode::from_mnemonic(name).ok_or_else(|| format!("unknown mnemonic {name:?}"))?;
+    if operands.len() != opcode.parameter_count() {
+        return Err(format!(
+            "{opcode} takes {} operands, got {}",
+            opcode.parameter_count(),
+            operands.len()
+        ));
+    }
+    Ok((labels, Some(Statement::Instruction(opcode, operands))))
+}
+
+fn parse_operand(text: &str, labels: &HashMap<String, i64>) -> Result<Operand, String> {
+    let Some(inner) = text.strip_prefix('[') else {
+        return Ok(Operand::new(Mode::Immediate, evaluate(text, labels)?));
+    };
+    let inner = inner
+        .strip_suffix(']')
+        .ok_or_else(|| format!("missing ] in {text:?}"))?
+        .trim();
+    match inner.strip_prefix("rb").map(str::trim_start) {
+        Some("") => Ok(Operand::new(Mode::Relative, 0)),
+        Some(offset) if offset.starts_with(['+', '-']) => {
+            Ok(Operand::new(Mode::Relative, evaluate(offset, labels)?))
+        }
+        _ => Ok(Operand::new(Mode::Position, evaluate(inner, labels)?)),
+    }
+}
+
+/// Evaluates a sum of numbers and labels such as `-3`, `buffer` or `buffer+2-offset`.
+fn evaluate(text: &str, labels: &HashMap<String, i64>) -> Result<i64, String> {
+    let mut total: i64 = 0;
+    let mut rest = text.trim();
+    loop {
+        let mut sign = 1;
+        loop {
+            rest = rest.trim_start();
+            if let Some(next) = rest.strip_prefix('-') {
+                sign = -sign;
+                rest = next;
+            } else if let Some(next) = rest.strip_prefix('+') {
+                rest = next;
+            } else {
+                break;
+            }
+        }
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let term = rest[..end].trim();
+        let value = if term.starts_with(|c: char| c.is_ascii_digit()) {
+            term.parse::<i64>()
+                .map_err(|_| format!("invalid number {term:?}"))?
+        } else if is_identifier(term) {
+            *labels
+                .get(term)
+                .ok_or_else(|| format!("unknown label {term:?}"))?
+        } else {
+            return Err(format!("invalid value {text:?}"));
+        };
+        total += sign * value;
+        rest = &rest[end..];
+        if rest.is_empty() {
+            return Ok(total);
+        }
+    }
+}
+
+/// Splits leading `name:` label definitions off a line.
+fn split_labels(text: &str) -> (Vec<&str>, &str) {
+    let mut labels = Vec::new();
+    let mut rest = text.trim();
+    while let Some((label, next)) = rest.split_once(':') {
+        let label = label.trim();
+        if !is_identifier(label) {
+            break;
+        }
+        labels.push(label);
+        rest = next.trim_start();
+    }
+    (labels, rest)
+}
+
+fn split_word(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    match text.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim()),
+        None => (text, ""),
+    }
+}
+
+fn split_operands(text: &str) -> Vec<&str> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+    text.split(',').map(str::trim).collect()
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_identifier(text: &str) -> bool {
+    text.starts_with(is_identifier_start) && text.chars().all(is_identifier_char)
+}
+
+fn is_reserved(name: &str) -> bool {
+    matches!(name, "rb" | "data" | "macro" | "endm") || Opcode::from_mnemonic(name).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{disassemble, parse_program, run};
+
+    #[test]
+    fn assemble_with_labels_and_macros() {
+        let source = "
+            ; Echoes its input until it reads a zero.
+            macro jmp target
+                jt 1, target
+            endm
+
+            loop:   in [value]
+                    jf [value], done
+                    out [value]
+                    jmp loop
+            done:   hlt
+            value:  data 0
+        ";
+        let program = assemble(source).unwrap();
+        assert_eq!(program, vec![3, 11, 1006, 11, 10, 4, 11, 1105, 1, 0, 99, 0]);
+        assert_eq!(run(&program, &[4, -2, 0]).unwrap(), vec![4, -2]);
+    }
+
+    #[test]
+    fn operand_syntax() {
+        let program =
+            assemble("start: add [rb-1], [rb], [rb+end]\nend: data start+1, -end").unwrap();
+        assert_eq!(program, vec![22201, -1, 0, 4, 1, -4]);
+    }
+
+    #[test]
+    fn round_trips_with_disassembler() {
+        let programs = [
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+            "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99",
+            "1099,7,1,0,0",
+        ];
+        for program in programs {
+            let program = parse_program(program).unwrap();
+            let source: Vec<_> = disassemble(&program)
+                .iter()
+                .map(|line| line.to_string())
+                .collect();
+            assert_eq!(assemble(&source.join("\n")).unwrap(), program);
+        }
+    }
+
+    #[test]
+    fn report_errors() {
+        let cases = [
+            ("add 1, 2", 1, "add takes 3 operands, got 2"),
+            (
+                "\nmul 1, 2, 3",
+                2,
+                "mul cannot write to an immediate operand",
+            ),
+            ("jt 1, nowhere", 1, "unknown label \"nowhere\""),
+            ("a: hlt\na: hlt", 2, "duplicate label \"a\""),
+            ("nop", 1, "unknown mnemonic \"nop\""),
+            ("macro m\nm\nendm\nm", 4, "macro \"m\" expands too deeply"),
+            (
+                "macro m x\nendm\nm",
+                3,
+                "macro \"m\" takes 1 arguments, got 0",
+            ),
+        ];
+        for (source, line, message) in cases {
+            assert_eq!(assemble(source), Err(error(line, message)), "{source:?}");
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Disassembler turning Intcode memory back into readable instructions.
+//!
+//! The disassembly is a linear sweep from address 0. Cells that do not hold a canonical
+//! instruction, or hold one whose parameters run past the end of memory, are printed as
+//! `data` lines so that self-modifying programs and embedded constants stay readable.
+
+use std::fmt;
+
+use crate::{Instruction, Operand};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    Instruction {
+        instruction: Instruction,
+        operands: Vec<Operand>,
+    },
+    Data(Vec<i64>),
+}
+
+/// A disassembled range of memory starting at `address`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub address: usize,
+    pub item: Item,
+}
+
+impl Line {
+    /// Number of memory cells covered by the line.
+    pub fn size(&self) -> usize {
+        match &self.item {
+            Item::Instruction { instruction, .. } => instruction.size(),
+            Item::Data(values) => values.len(),
+        }
+    }
+}
+
+impl fmt::Display for Line {
+    /// Formats the line as assembly source with the address in a trailing comment.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match &self.item {
+            Item::Instruction {
+                instruction,
+                operands,
+            } => {
+                let operands = operands
+                    .iter()
+                    .map(|operand| operand.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{} {operands}", instruction.opcode)
+            }
+            Item::Data(values) => {
+                let values = values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("data {values}")
+            }
+        };
+        write!(f, "{:<32}; {}", text.trim_end(), self.address)
+    }
+}
+
+/// Decodes the instruction at `address`, if the memory there holds a complete one.
+pub fn decode_at(memory: &[i64], address: usize) -> Option<Line> {
+    let value = *memory.get(address)?;
+    let instruction = Instruction::decode(address, value).ok()?;
+    if !instruction.is_canonical(value) {
+        return None;
+    }
+    let parameters = memory.get(address + 1..address + instruction.size())?;
+    let operands = parameters
+        .iter()
+        .zip(instruction.modes)
+        .map(|(value, mode)| Operand::new(mode, *value))
+        .collect();
+    Some(Line {
+        address,
+        item: Item::Instruction {
+            instruction,
+            operands,
+        },
+    })
+}
+
+/// Decodes the instruction at `address`, falling back to a single data cell.
+///
+/// Panics if `address` is outside of `memory`.
+pub fn line_at(memory: &[i64], address: usize) -> Line {
+    decode_at(memory, address).unwrap_or_else(|| Line {
+        address,
+        item: Item::Data(vec![memory[address]]),
+    })
+}
+
+/// Disassembles the whole memory. Consecutive data cells are merged into one line.
+pub fn disassemble(memory: &[i64]) -> Vec<Line> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        let line = line_at(memory, address);
+        address += line.size();
+        match (lines.last_mut(), &line.item) {
+            (
+                Some(Line {
+                    item: Item::Data(values),
+                    ..
+                }),
+                Item::Data(value),
+            ) => values.extend(value),
+            _ => lines.push(line),
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassemble_program() {
+        let lines: Vec<_> = disassemble(&[1002, 4, 3, 4, 33, 109, -1, 204, 1, 1099, 7, 99, 1])
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "mul [4], 3, [4]                 ; 0",
+                "data 33                         ; 4",
+                "arb -1                          ; 5",
+                "out [rb+1]                      ; 7",
+                "data 1099, 7                    ; 9",
+                "hlt                             ; 11",
+                "data 1                          ; 12",
+            ]
+        );
+    }
+
+    #[test]
+    fn truncated_instruction_is_data() {
+        assert_eq!(
+            disassemble(&[1, 0, 0]),
+            vec![Line {
+                address: 0,
+                item: Item::Data(vec![1, 0, 0])
+            }]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The program text contains something that is not an integer.
+    Parse {
+        index: usize,
+        text: String,
+    },
+    InvalidOpcode {
+        ip: usize,
+        value: i64,
+    },
+    InvalidMode {
+        ip: usize,
+        value: i64,
+    },
+    /// An instruction tried to write to an immediate mode parameter.
+    ImmediateWrite {
+        ip: usize,
+    },
+    /// A parameter or jump target points to a negative address.
+    NegativeAddress {
+        ip: usize,
+        address: i64,
+    },
+    /// An arithmetic instruction or a relative address overflowed.
+    Overflow {
+        ip: usize,
+    },
+    /// A write would grow the memory beyond the limit of the machine.
+    MemoryLimit {
+        ip: usize,
+        address: usize,
+        limit: usize,
+    },
+    /// The program asked for input after all queued input was consumed.
+    MissingInput {
+        ip: usize,
+    },
+    /// A network node id that was never added.
+    UnknownNode {
+        node: usize,
+    },
+    /// Network packets need at least the address.
+    PacketSize {
+        size: usize,
+    },
+    /// Assembly source could not be assembled.
+    Assemble {
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { index, text } => write!(f, "invalid value {text:?} at index {index}"),
+            Error::InvalidOpcode { ip, value } => write!(f, "invalid opcode {value} at {ip}"),
+            Error::InvalidMode { ip, value } => {
+                write!(f, "invalid parameter mode in {value} at {ip}")
+            }
+            Error::ImmediateWrite { ip } => {
+                write!(f, "write to an immediate mode parameter at {ip}")
+            }
+            Error::NegativeAddress { ip, address } => {
+                write!(f, "negative address {address} at {ip}")
+            }
+            Error::Overflow { ip } => write!(f, "arithmetic overflow at {ip}"),
+            Error::MemoryLimit { ip, address, limit } => {
+                write!(
+                    f,
+                    "write to {address} beyond the memory limit of {limit} at {ip}"
+                )
+            }
+            Error::MissingInput { ip } => write!(f, "no input available at {ip}"),
+            Error::UnknownNode { node } => write!(f, "unknown network node {node}"),
+            Error::PacketSize { size } => write!(f, "packets of {size} values have no address"),
+            Error::Assemble { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,247 @@
+use std::fmt;
+
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Multiply,
+        Opcode::Input,
+        Opcode::Output,
+        Opcode::JumpIfTrue,
+        Opcode::JumpIfFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustRelativeBase,
+        Opcode::Halt,
+    ];
+
+    pub fn from_code(code: i64) -> Option<Self> {
+        Self::ALL.into_iter().find(|opcode| opcode.code() == code)
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Opcode::Add => 1,
+            Opcode::Multiply => 2,
+            Opcode::Input => 3,
+            Opcode::Output => 4,
+            Opcode::JumpIfTrue => 5,
+            Opcode::JumpIfFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustRelativeBase => 9,
+            Opcode::Halt => 99,
+        }
+    }
+
+    pub fn parameter_count(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// Index of the parameter the instruction writes its result to, if any.
+    pub fn write_parameter(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Multiply => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jt",
+            Opcode::JumpIfFalse => "jf",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustRelativeBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Mode {
+    #[default]
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
+        }
+    }
+}
+
+/// A single instruction parameter. Formats as `5` in immediate, `[5]` in position and
+/// `[rb+5]` in relative mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Operand {
+    pub mode: Mode,
+    pub value: i64,
+}
+
+impl Operand {
+    pub fn new(mode: Mode, value: i64) -> Self {
+        Self { mode, value }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "[{}]", self.value),
+            Mode::Immediate => write!(f, "{}", self.value),
+            Mode::Relative if self.value < 0 => write!(f, "[rb{}]", self.value),
+            Mode::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+/// A decoded instruction header: the opcode and the modes of its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, modes: [Mode; 3]) -> Self {
+        Self { opcode, modes }
+    }
+
+    /// Decodes the instruction stored at `ip`.
+    pub fn decode(ip: usize, value: i64) -> Result<Self, Error> {
+        let opcode = Opcode::from_code(value % 100).ok_or(Error::InvalidOpcode { ip, value })?;
+        let mut modes = [Mode::Position; 3];
+        let mut rest = value / 100;
+        for mode in modes.iter_mut() {
+            *mode = Mode::from_code(rest % 10).ok_or(Error::InvalidMode { ip, value })?;
+            rest /= 10;
+        }
+        if rest != 0 {
+            return Err(Error::InvalidMode { ip, value });
+        }
+        if let Some(index) = opcode.write_parameter() {
+            if modes[index] == Mode::Immediate {
+                return Err(Error::ImmediateWrite { ip });
+            }
+        }
+        Ok(Self { opcode, modes })
+    }
+
+    pub fn encode(&self) -> i64 {
+        self.modes
+            .iter()
+            .rev()
+            .fold(0, |acc, mode| acc * 10 + mode.code())
+            * 100
+            + self.opcode.code()
+    }
+
+    /// Whether `value` is the canonical encoding of this instruction, i.e. it has no mode
+    /// digits for parameters the opcode does not take.
+    pub fn is_canonical(&self, value: i64) -> bool {
+        let count = self.opcode.parameter_count();
+        self.modes[count..]
+            .iter()
+            .all(|mode| *mode == Mode::Position)
+            && self.encode() == value
+    }
+
+    /// Number of memory cells the instruction occupies including its parameters.
+    pub fn size(&self) -> usize {
+        1 + self.opcode.parameter_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_modes() {
+        let instruction = Instruction::decode(0, 1002).unwrap();
+        assert_eq!(instruction.opcode, Opcode::Multiply);
+        assert_eq!(
+            instruction.modes,
+            [Mode::Position, Mode::Immediate, Mode::Position]
+        );
+        assert_eq!(instruction.encode(), 1002);
+        assert!(instruction.is_canonical(1002));
+        assert!(!Instruction::decode(0, 1099).unwrap().is_canonical(1099));
+    }
+
+    #[test]
+    fn format_operands() {
+        assert_eq!(Operand::new(Mode::Position, 4).to_string(), "[4]");
+        assert_eq!(Operand::new(Mode::Immediate, -4).to_string(), "-4");
+        assert_eq!(Operand::new(Mode::Relative, 4).to_string(), "[rb+4]");
+        assert_eq!(Operand::new(Mode::Relative, -4).to_string(), "[rb-4]");
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            Instruction::decode(3, 42),
+            Err(Error::InvalidOpcode { ip: 3, value: 42 })
+        );
+        assert_eq!(
+            Instruction::decode(0, 301),
+            Err(Error::InvalidMode { ip: 0, value: 301 })
+        );
+        assert_eq!(
+            Instruction::decode(7, 10001),
+            Err(Error::ImmediateWrite { ip: 7 })
+        );
+    }
+}
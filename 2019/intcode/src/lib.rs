@@ -0,0 +1,50 @@
+//! Intcode virtual machine shared by the 2019 puzzles, with an assembler and disassembler.
+
+mod asm;
+pub mod disasm;
+mod error;
+mod instruction;
+pub mod network;
+mod trace;
+mod vm;
+
+pub use asm::assemble;
+pub use disasm::disassemble;
+pub use error::Error;
+pub use instruction::{Instruction, Mode, Opcode, Operand};
+pub use network::{Network, Outcome};
+pub use trace::Trace;
+pub use vm::{run, Event, Vm, Write, DEFAULT_MEMORY_LIMIT};
+
+/// Parses a comma separated Intcode program.
+pub fn parse_program(input: &str) -> Result<Vec<i64>, Error> {
+    input
+        .trim()
+        .split(',')
+        .enumerate()
+        .map(|(index, value)| {
+            let value = value.trim();
+            value.parse().map_err(|_| Error::Parse {
+                index,
+                text: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_program("1,0,-3,99\n"), Ok(vec![1, 0, -3, 99]));
+        assert_eq!(
+            parse_program("1,x,99"),
+            Err(Error::Parse {
+                index: 1,
+                text: "x".to_string()
+            })
+        );
+    }
+}
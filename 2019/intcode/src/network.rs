@@ -0,0 +1,323 @@
+//! A round robin scheduler for several Intcode machines that talk to each other.
+//!
+//! Every node owns a [`Vm`] whose input queue buffers the values sent to it. The output of a
+//! node is logged and then either forwarded to a fixed set of nodes, which covers pipelines,
+//! rings and broadcasts, or split into packets whose first value is the address of the
+//! receiving node, as in a router.
+
+use crate::{Error, Event, Vm};
+
+/// Number of instructions a node may execute before the next node gets its turn.
+const SLICE: usize = 10_000;
+
+/// Why [`Network::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every node halted.
+    Halted,
+    /// A full round passed without output or consumed input, and some node is blocked on
+    /// an empty queue.
+    Deadlock,
+    /// A full round passed without output or consumed input, and every running node was
+    /// fed its idle input.
+    Idle,
+}
+
+#[derive(Debug, Clone)]
+enum Route {
+    Direct(Vec<usize>),
+    Packets { size: usize, buffer: Vec<i64> },
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    vm: Vm,
+    route: Route,
+    idle_input: Option<i64>,
+    log: Vec<i64>,
+}
+
+/// A packet whose address does not belong to any node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub from: usize,
+    pub address: i64,
+    pub payload: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    nodes: Vec<Node>,
+    undelivered: Vec<Packet>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a machine and returns its node id. Ids are assigned from 0 in insertion order.
+    pub fn add(&mut self, vm: Vm) -> usize {
+        self.nodes.push(Node {
+            vm,
+            route: Route::Direct(Vec::new()),
+            idle_input: None,
+            log: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Forwards every output of `from` to the input of `to`. A node connected to several
+    /// nodes broadcasts its output to all of them.
+    pub fn connect(&mut self, from: usize, to: usize) -> Result<(), Error> {
+        self.node(to)?;
+        match &mut self.node_mut(from)?.route {
+            Route::Direct(targets) => targets.push(to),
+            route => *route = Route::Direct(vec![to]),
+        }
+        Ok(())
+    }
+
+    /// Groups the output of `node` into packets of `size` values. The first value is the
+    /// address of the receiving node, the rest is delivered to it.
+    pub fn route_packets(&mut self, node: usize, size: usize) -> Result<(), Error> {
+        if size == 0 {
+            return Err(Error::PacketSize { size });
+        }
+        self.node_mut(node)?.route = Route::Packets {
+            size,
+            buffer: Vec::new(),
+        };
+        Ok(())
+    }
+
+    /// Makes `node` read `value` instead of blocking when its input queue is empty.
+    pub fn set_idle_input(&mut self, node: usize, value: i64) -> Result<(), Error> {
+        self.node_mut(node)?.idle_input = Some(value);
+        Ok(())
+    }
+
+    pub fn push_input(&mut self, node: usize, value: i64) -> Result<(), Error> {
+        self.node_mut(node)?.vm.push_input(value);
+        Ok(())
+    }
+
+    pub fn vm(&self, node: usize) -> Result<&Vm, Error> {
+        Ok(&self.node(node)?.vm)
+    }
+
+    /// Everything `node` has output so far.
+    pub fn log(&self, node: usize) -> Result<&[i64], Error> {
+        Ok(&self.node(node)?.log)
+    }
+
+    /// Removes and returns the packets sent to addresses without a node.
+    pub fn take_undelivered(&mut self) -> Vec<Packet> {
+        std::mem::take(&mut self.undelivered)
+    }
+
+    fn node(&self, node: usize) -> Result<&Node, Error> {
+        self.nodes.get(node).ok_or(Error::UnknownNode { node })
+    }
+
+    fn node_mut(&mut self, node: usize) -> Result<&mut Node, Error> {
+        self.nodes.get_mut(node).ok_or(Error::UnknownNode { node })
+    }
+
+    /// Runs the nodes in turn until all of them halt or the network stops making progress.
+    pub fn run(&mut self) -> Result<Outcome, Error> {
+        loop {
+            let mut progress = false;
+            for index in 0..self.nodes.len() {
+                progress |= self.turn(index)?;
+            }
+            if self.nodes.iter().all(|node| node.vm.is_halted()) {
+                return Ok(Outcome::Halted);
+            }
+            if !progress {
+                let idle = self
+                    .nodes
+                    .iter()
+                    .all(|node| node.vm.is_halted() || node.idle_input.is_some());
+                return Ok(if idle {
+                    Outcome::Idle
+                } else {
+                    Outcome::Deadlock
+                });
+            }
+        }
+    }
+
+    /// Runs a single node for up to one time slice. Returns whether it produced output,
+    /// consumed queued input or is still busy computing.
+    fn turn(&mut self, index: usize) -> Result<bool, Error> {
+        let queued = self.nodes[index].vm.input().len();
+        let mut fed_idle = false;
+        let mut progress = false;
+        for _ in 0..SLICE {
+            let node = &mut self.nodes[index];
+            match node.vm.step()? {
+                None => {}
+                Some(Event::Output(value)) => {
+                    node.log.push(value);
+                    self.send(index, value);
+                    progress = true;
+                }
+                Some(Event::NeedsInput) => match node.idle_input {
+                    Some(value) if !fed_idle => {
+                        node.vm.push_input(value);
+                        fed_idle = true;
+                    }
+                    _ => return Ok(progress || node.vm.input().len() < queued),
+                },
+                Some(Event::Halted) => return Ok(progress || node.vm.input().len() < queued),
+            }
+        }
+        Ok(true)
+    }
+
+    fn send(&mut self, from: usize, value: i64) {
+        match &mut self.nodes[from].route {
+            Route::Direct(targets) => {
+                for target in targets.clone() {
+                    self.nodes[target].vm.push_input(value);
+                }
+            }
+            Route::Packets { size, buffer } => {
+                buffer.push(value);
+                if buffer.len() < *size {
+                    return;
+                }
+                let packet = std::mem::take(buffer);
+                let (address, payload) = (packet[0], packet[1..].to_vec());
+                match usize::try_from(address) {
+                    Ok(to) if to < self.nodes.len() => self.nodes[to].vm.extend_input(payload),
+                    _ => self.undelivered.push(Packet {
+                        from,
+                        address,
+                        payload,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, parse_program};
+
+    #[test]
+    fn feedback_ring() {
+        let program = parse_program(
+            "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+        )
+        .unwrap();
+        let mut network = Network::new();
+        let amps: Vec<_> = [9, 8, 7, 6, 5]
+            .into_iter()
+            .map(|phase| {
+                let mut vm = Vm::new(&program);
+                vm.push_input(phase);
+                network.add(vm)
+            })
+            .collect();
+        for (from, to) in amps.iter().zip(amps.iter().cycle().skip(1)) {
+            network.connect(*from, *to).unwrap();
+        }
+        network.push_input(amps[0], 0).unwrap();
+        assert_eq!(network.run(), Ok(Outcome::Halted));
+        assert_eq!(network.log(amps[4]).unwrap().last(), Some(&139629729));
+    }
+
+    #[test]
+    fn detects_deadlock() {
+        let relay = assemble("in [value]\nout [value]\nhlt\nvalue: data 0").unwrap();
+        let mut network = Network::new();
+        let a = network.add(Vm::new(&relay));
+        let b = network.add(Vm::new(&relay));
+        network.connect(a, b).unwrap();
+        network.connect(b, a).unwrap();
+        assert_eq!(network.run(), Ok(Outcome::Deadlock));
+        network.push_input(a, 7).unwrap();
+        assert_eq!(network.run(), Ok(Outcome::Halted));
+        assert_eq!(network.log(a), Ok(&[7][..]));
+        assert_eq!(network.log(b), Ok(&[7][..]));
+    }
+
+    #[test]
+    fn routes_packets_until_idle() {
+        // Forwards every (x, y) pair it receives to `address`, polling with -1 when idle.
+        let forward = |address: i64| {
+            assemble(&format!(
+                "
+                x:      in [value]
+                        eq [value], -1, [flag]
+                        jt [flag], x
+                        in [y]
+                        out {address}
+                        out [value]
+                        out [y]
+                        jt 1, x
+                value:  data 0
+                y:      data 0
+                flag:   data 0
+                "
+            ))
+            .unwrap()
+        };
+        let mut network = Network::new();
+        let first = network.add(Vm::new(&forward(1)));
+        let second = network.add(Vm::new(&forward(255)));
+        for node in [first, second] {
+            network.route_packets(node, 3).unwrap();
+            network.set_idle_input(node, -1).unwrap();
+        }
+        network.push_input(first, 3).unwrap();
+        network.push_input(first, 4).unwrap();
+        assert_eq!(network.run(), Ok(Outcome::Idle));
+        assert_eq!(
+            network.take_undelivered(),
+            vec![Packet {
+                from: second,
+                address: 255,
+                payload: vec![3, 4]
+            }]
+        );
+        assert_eq!(network.log(first), Ok(&[1, 3, 4][..]));
+    }
+
+    #[test]
+    fn rejects_unknown_nodes() {
+        let mut network = Network::new();
+        let node = network.add(Vm::new(&[99]));
+        assert_eq!(
+            network.connect(node, 1),
+            Err(Error::UnknownNode { node: 1 })
+        );
+        assert_eq!(
+            network.connect(2, node),
+            Err(Error::UnknownNode { node: 2 })
+        );
+        assert_eq!(
+            network.route_packets(3, 2),
+            Err(Error::UnknownNode { node: 3 })
+        );
+        assert_eq!(
+            network.set_idle_input(4, -1),
+            Err(Error::UnknownNode { node: 4 })
+        );
+        assert_eq!(
+            network.push_input(5, 0),
+            Err(Error::UnknownNode { node: 5 })
+        );
+        assert!(network.vm(6).is_err());
+        assert_eq!(network.log(7), Err(Error::UnknownNode { node: 7 }));
+        assert_eq!(
+            network.route_packets(node, 0),
+            Err(Error::PacketSize { size: 0 })
+        );
+        assert_eq!(network.route_packets(node, 2), Ok(()));
+    }
+}
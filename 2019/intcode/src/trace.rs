@@ -0,0 +1,71 @@
+use std::fmt;
+
+use crate::{Event, Instruction, Mode, Operand, Write};
+
+/// A record of a single executed instruction, produced by [`crate::Vm::trace_step`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub ip: usize,
+    /// Relative base before the instruction was executed.
+    pub relative_base: i64,
+    pub instruction: Instruction,
+    /// Raw parameter values as stored in memory.
+    pub parameters: Vec<i64>,
+    /// Values the instruction read for each parameter, `None` for the parameter it writes to.
+    pub values: Vec<Option<i64>>,
+    pub write: Option<Write>,
+    pub event: Option<Event>,
+}
+
+impl fmt::Display for Trace {
+    /// Formats the trace as `ip rb=base mnemonic operands`. Operands that are not immediate are
+    /// followed by the value they resolved to, e.g. `[4]=33`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>6}  rb={:<6} {}",
+            self.ip, self.relative_base, self.instruction.opcode
+        )?;
+        for (index, (parameter, value)) in self.parameters.iter().zip(&self.values).enumerate() {
+            let mode = self.instruction.modes[index];
+            let separator = if index == 0 { " " } else { ", " };
+            write!(f, "{separator}{}", Operand::new(mode, *parameter))?;
+            match value {
+                Some(value) if mode != Mode::Immediate => write!(f, "={value}")?,
+                _ => {}
+            }
+        }
+        if let Some(write) = self.write {
+            write!(f, "  [{}] {} -> {}", write.address, write.old, write.new)?;
+        }
+        match self.event {
+            Some(Event::Output(value)) => write!(f, "  => output {value}"),
+            Some(Event::NeedsInput) => write!(f, "  => waiting for input"),
+            Some(Event::Halted) => write!(f, "  => halted"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Event, Vm};
+
+    #[test]
+    fn trace_instructions() {
+        let mut vm = Vm::new(&[109, 2, 1202, 3, 5, 2, 204, 0, 99]);
+        let traces: Vec<_> = (0..4).map(|_| vm.trace_step().unwrap()).collect();
+        assert_eq!(traces[1].values, vec![Some(2), Some(5), None]);
+        assert_eq!(traces[2].event, Some(Event::Output(10)));
+        let lines: Vec<_> = traces.iter().map(|trace| trace.to_string()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "     0  rb=0      arb 2",
+                "     2  rb=2      mul [rb+3]=2, 5, [2]  [2] 1202 -> 10",
+                "     6  rb=2      out [rb+0]=10  => output 10",
+                "     8  rb=2      hlt  => halted",
+            ]
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::VecDeque;
+
+use crate::{Error, Instruction, Mode, Opcode, Trace};
+
+/// Why [`Vm::run`] returned control to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Output(i64),
+    /// The program executes an input instruction but no input is queued. The instruction is
+    /// retried by the next call to [`Vm::run`].
+    NeedsInput,
+    Halted,
+}
+
+/// A memory cell changed by an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Write {
+    pub address: usize,
+    pub old: i64,
+    pub new: i64,
+}
+
+/// Number of memory cells a machine may use unless told otherwise, 128 MiB worth.
+pub const DEFAULT_MEMORY_LIMIT: usize = 1 << 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vm {
+    memory: Vec<i64>,
+    memory_limit: usize,
+    ip: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    halted: bool,
+    last_write: Option<Write>,
+}
+
+impl Vm {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: program.to_vec(),
+            memory_limit: DEFAULT_MEMORY_LIMIT.max(program.len()),
+            ip: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            halted: false,
+            last_write: None,
+        }
+    }
+
+    /// Limits the memory to `cells` cells, or to the size of the program if that is larger.
+    /// Writes beyond the limit fail with [`Error::MemoryLimit`].
+    pub fn memory_limit(mut self, cells: usize) -> Self {
+        self.memory_limit = cells.max(self.memory.len());
+        self
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn extend_input(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.input.extend(values);
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn input(&self) -> &VecDeque<i64> {
+        &self.input
+    }
+
+    /// The memory cell written by the most recent call to [`Vm::step`], if any.
+    pub fn last_write(&self) -> Option<Write> {
+        self.last_write
+    }
+
+    /// Reads a memory cell. Memory beyond the program is zero.
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or_default()
+    }
+
+    /// Writes a memory cell, growing the memory as needed up to the memory limit.
+    pub fn write(&mut self, address: usize, value: i64) -> Result<(), Error> {
+        if address >= self.memory.len() {
+            if address >= self.memory_limit {
+                return Err(Error::MemoryLimit {
+                    ip: self.ip,
+                    address,
+                    limit: self.memory_limit,
+                });
+            }
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+        Ok(())
+    }
+
+    /// Decodes the instruction at the instruction pointer without executing it.
+    pub fn instruction(&self) -> Result<Instruction, Error> {
+        Instruction::decode(self.ip, self.read(self.ip))
+    }
+
+    /// Executes a single instruction and returns the event it caused, if any.
+    pub fn step(&mut self) -> Result<Option<Event>, Error> {
+        self.last_write = None;
+        if self.halted {
+            return Ok(Some(Event::Halted));
+        }
+        let instruction = self.instruction()?;
+        let next = self.ip + instruction.size();
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => {
+                let a = self.load(&instruction, 0)?;
+                let b = self.load(&instruction, 1)?;
+                let value = match instruction.opcode {
+                    Opcode::Add => self.checked(a.checked_add(b))?,
+                    Opcode::Multiply => self.checked(a.checked_mul(b))?,
+                    Opcode::LessThan => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                self.store(&instruction, 2, value)?;
+                self.ip = next;
+            }
+            Opcode::Input => {
+                let Some(value) = self.input.pop_front() else {
+                    return Ok(Some(Event::NeedsInput));
+                };
+                self.store(&instruction, 0, value)?;
+                self.ip = next;
+            }
+            Opcode::Output => {
+                let value = self.load(&instruction, 0)?;
+                self.ip = next;
+                return Ok(Some(Event::Output(value)));
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.load(&instruction, 0)?;
+                let target = self.load(&instruction, 1)?;
+                if (condition != 0) == (instruction.opcode == Opcode::JumpIfTrue) {
+                    self.ip = self.address(target)?;
+                } else {
+                    self.ip = next;
+                }
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.load(&instruction, 0)?;
+                self.relative_base = self.checked(self.relative_base.checked_add(offset))?;
+                self.ip = next;
+            }
+            Opcode::Halt => {
+                self.halted = true;
+                return Ok(Some(Event::Halted));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Executes a single instruction like [`Vm::step`] and records what it did.
+    pub fn trace_step(&mut self) -> Result<Trace, Error> {
+        let ip = self.ip;
+        let relative_base = self.relative_base;
+        let instruction = self.instruction()?;
+        let count = instruction.opcode.parameter_count();
+        let parameters = (0..count).map(|index| self.parameter(index)).collect();
+        let values = (0..count)
+            .map(|index| match instruction.opcode.write_parameter() {
+                Some(write) if write == index => Ok(None),
+                _ => self.load(&instruction, index).map(Some),
+            })
+            .collect::<Result<_, _>>()?;
+        let event = self.step()?;
+        Ok(Trace {
+            ip,
+            relative_base,
+            instruction,
+            parameters,
+            values,
+            write: self.last_write,
+            event,
+        })
+    }
+
+    /// Runs until the program produces output, waits for input or halts.
+    pub fn run(&mut self) -> Result<Event, Error> {
+        loop {
+            if let Some(event) = self.step()? {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Runs until the program halts and returns all of its output.
+    pub fn run_to_halt(&mut self) -> Result<Vec<i64>, Error> {
+        let mut output = Vec::new();
+        loop {
+            match self.run()? {
+                Event::Output(value) => output.push(value),
+                Event::NeedsInput => return Err(Error::MissingInput { ip: self.ip }),
+                Event::Halted => return Ok(output),
+            }
+        }
+    }
+
+    fn parameter(&self, index: usize) -> i64 {
+        self.read(self.ip + 1 + index)
+    }
+
+    fn address(&self, address: i64) -> Result<usize, Error> {
+        usize::try_from(address).map_err(|_| Error::NegativeAddress {
+            ip: self.ip,
+            address,
+        })
+    }
+
+    fn relative_address(&self, offset: i64) -> Result<usize, Error> {
+        self.address(self.checked(self.relative_base.checked_add(offset))?)
+    }
+
+    /// The result of a checked operation, or an overflow error at the current instruction.
+    fn checked(&self, value: Option<i64>) -> Result<i64, Error> {
+        value.ok_or(Error::Overflow { ip: self.ip })
+    }
+
+    fn load(&self, instruction: &Instruction, index: usize) -> Result<i64, Error> {
+        let parameter = self.parameter(index);
+        match instruction.modes[index] {
+            Mode::Position => Ok(self.read(self.address(parameter)?)),
+            Mode::Immediate => Ok(parameter),
+            Mode::Relative => Ok(self.read(self.relative_address(parameter)?)),
+        }
+    }
+
+    fn store(&mut self, instruction: &Instruction, index: usize, value: i64) -> Result<(), Error> {
+        let parameter = self.parameter(index);
+        let address = match instruction.modes[index] {
+            Mode::Position => self.address(parameter)?,
+            Mode::Relative => self.relative_address(parameter)?,
+            Mode::Immediate => return Err(Error::ImmediateWrite { ip: self.ip }),
+        };
+        let old = self.read(address);
+        self.write(address, value)?;
+        self.last_write = Some(Write {
+            address,
+            old,
+            new: value,
+        });
+        Ok(())
+    }
+}
+
+/// Runs a program with the given input and returns all of its output.
+pub fn run(program: &[i64], input: &[i64]) -> Result<Vec<i64>, Error> {
+    let mut vm = Vm::new(program);
+    vm.extend_input(input.iter().copied());
+    vm.run_to_halt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_program;
+
+    #[test]
+    fn position_mode_arithmetic() {
+        let mut vm = Vm::new(&[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        vm.run_to_halt().unwrap();
+        assert_eq!(vm.read(0), 3500);
+    }
+
+    #[test]
+    fn comparisons_and_jumps() {
+        let program = parse_program(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+        )
+        .unwrap();
+        assert_eq!(run(&program, &[7]).unwrap(), vec![999]);
+        assert_eq!(run(&program, &[8]).unwrap(), vec![1000]);
+        assert_eq!(run(&program, &[9]).unwrap(), vec![1001]);
+    }
+
+    #[test]
+    fn relative_mode_and_large_memory() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(run(&quine, &[]).unwrap(), quine.to_vec());
+        let program = [1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+        assert_eq!(run(&program, &[]).unwrap(), vec![1219070632396864]);
+    }
+
+    #[test]
+    fn pauses_on_missing_input() {
+        let mut vm = Vm::new(&[3, 9, 4, 9, 3, 9, 4, 9, 99, 0]);
+        assert_eq!(vm.run(), Ok(Event::NeedsInput));
+        vm.push_input(7);
+        assert_eq!(vm.run(), Ok(Event::Output(7)));
+        assert_eq!(vm.run(), Ok(Event::NeedsInput));
+        assert_eq!(vm.run_to_halt(), Err(Error::MissingInput { ip: 4 }));
+        vm.push_input(8);
+        assert_eq!(vm.run_to_halt(), Ok(vec![8]));
+        assert!(vm.is_halted());
+    }
+
+    #[test]
+    fn records_writes() {
+        let mut vm = Vm::new(&[1002, 4, 3, 4, 33]);
+        vm.step().unwrap();
+        assert_eq!(
+            vm.last_write(),
+            Some(Write {
+                address: 4,
+                old: 33,
+                new: 99
+            })
+        );
+        assert_eq!(vm.step(), Ok(Some(Event::Halted)));
+        assert_eq!(vm.last_write(), None);
+    }
+
+    #[test]
+    fn reports_negative_addresses() {
+        assert_eq!(
+            run(&[1, -1, 0, 0, 99], &[]),
+            Err(Error::NegativeAddress { ip: 0, address: -1 })
+        );
+    }
+
+    #[test]
+    fn limits_memory_growth() {
+        // Writes 1 to the address read from the input.
+        let program = [3, 5, 1101, 0, 1, 0, 99];
+        let mut vm = Vm::new(&program).memory_limit(100);
+        vm.push_input(100);
+        assert_eq!(
+            vm.run_to_halt(),
+            Err(Error::MemoryLimit {
+                ip: 2,
+                address: 100,
+                limit: 100
+            })
+        );
+        assert_eq!(vm.last_write(), None);
+        let mut vm = Vm::new(&program).memory_limit(100);
+        vm.push_input(99);
+        assert_eq!(vm.run_to_halt(), Ok(vec![]));
+        assert_eq!(vm.read(99), 1);
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(
+            run(&[1101, i64::MAX, 1, 0, 99], &[]),
+            Err(Error::Overflow { ip: 0 })
+        );
+        assert_eq!(
+            run(&[1102, i64::MIN, -1, 0, 99], &[]),
+            Err(Error::Overflow { ip: 0 })
+        );
+        assert_eq!(
+            run(&[109, i64::MAX, 109, 1, 99], &[]),
+            Err(Error::Overflow { ip: 2 })
+        );
+        assert_eq!(
+            run(&[109, i64::MAX, 204, 1, 99], &[]),
+            Err(Error::Overflow { ip: 2 })
+        );
+    }
+}
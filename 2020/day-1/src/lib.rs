@@ -0,0 +1,77 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use nom::character::complete;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::{character::complete::line_ending, IResult};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub fn part1(numbers: &HashSet<i32>) -> anyhow::Result<(i32, i32)> {
+    let result = find_solution1(numbers, 2020).ok_or_else(|| anyhow!("no solution found"))?;
+    Ok(result)
+}
+
+pub fn part2(numbers: &HashSet<i32>) -> anyhow::Result<(i32, i32, i32)> {
+    let result = find_solutions2(numbers, 2020).ok_or_else(|| anyhow!("no solution found"))?;
+    Ok(result)
+}
+
+fn find_solutions2(numbers: &HashSet<i32>, goal: i32) -> Option<(i32, i32, i32)> {
+    let nums: Vec<i32> = Vec::from_iter(numbers.iter().copied());
+    let size = nums.len();
+    for i in 0..size {
+        let a = nums[i];
+        for j in (i + 1)..size {
+            let b = nums[j];
+            if a + b < goal && numbers.contains(&(goal - a - b)) {
+                return Some((a, b, goal - a - b));
+            }
+        }
+    }
+    None
+}
+
+fn find_solution1(numbers: &HashSet<i32>, goal: i32) -> Option<(i32, i32)> {
+    numbers
+        .iter()
+        .filter(|n| {
+            let second = goal - *n;
+            numbers.contains(&second)
+        })
+        .map(|n| (*n, goal - *n))
+        .next()
+}
+
+pub fn parse(input: &str) -> anyhow::Result<HashSet<i32>> {
+    Ok(parse_error::nom(input, parse_input)?)
+}
+
+fn parse_input(input: &str) -> IResult<&str, HashSet<i32>> {
+    map(separated_list1(line_ending, complete::i32), |numbers| {
+        HashSet::from_iter(numbers.into_iter())
+    })(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+
+    type Input<'a> = HashSet<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        let (a, b) = part1(input)?;
+        Ok(a * b)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        let (a, b, c) = part2(input)?;
+        Ok(a * b * c)
+    }
+}
@@ -0,0 +1 @@
+aoc_bench::main!(y2020_day_10::Puzzle);
@@ -0,0 +1,283 @@
+use anyhow::bail;
+use aoc_core::Solution;
+use cycle::brent;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::{many1, separated_list0};
+use nom::IResult;
+use visualize::{Frame, Palette, Recorder};
+
+pub fn part1(layout: &Layout) -> anyhow::Result<usize> {
+    Ok(settle(layout, Layout::step)?.num_occupied())
+}
+
+pub fn part2(layout: &Layout) -> anyhow::Result<usize> {
+    Ok(settle(layout, Layout::step2)?.num_occupied())
+}
+
+/// Applies `step` until no seat changes any more, which is a cycle of length 1.
+fn settle(layout: &Layout, step: impl Fn(&Layout) -> (i32, Layout)) -> anyhow::Result<Layout> {
+    let (cycle, stable) = brent(layout.clone(), |layout| step(layout).1);
+    if cycle.length != 1 {
+        bail!("the seats keep changing in a cycle of {} rounds", cycle.length);
+    }
+    Ok(stable)
+}
+
+/// Animates part 1 until the seats stop changing.
+pub fn animate(input: &str) -> anyhow::Result<Recorder> {
+    let palette = Palette::new([40, 40, 40])
+        .with('L', [60, 140, 220])
+        .with('#', [230, 90, 60]);
+    let mut layout = parse(input)?;
+    let mut recorder = Recorder::new().scale(4).delay(25);
+    recorder.push(Frame::from_text(&layout.to_string(), &palette));
+    loop {
+        let (num_changes, next) = layout.step();
+        if num_changes == 0 {
+            break;
+        }
+        layout = next;
+        recorder.push(Frame::from_text(&layout.to_string(), &palette));
+    }
+    Ok(recorder)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Position::Floor => '.',
+            Position::Empty => 'L',
+            Position::Occupied => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    positions: Vec<Vec<Position>>,
+}
+
+impl Layout {
+    fn step(&self) -> (i32, Layout) {
+        let mut n = self.clone();
+        let mut num_changes = 0;
+        for row in 0..self.positions.len() {
+            for col in 0..self.positions[0].len() {
+                let pos = self.positions[row][col];
+                if pos == Position::Occupied || pos == Position::Empty {
+                    let occupied_count =
+                        self.adj_count(row as isize, col as isize, Position::Occupied);
+                    if self.positions[row][col] == Position::Empty && occupied_count == 0 {
+                        n.positions[row][col] = Position::Occupied;
+                        num_changes += 1;
+                    }
+                    if self.positions[row][col] == Position::Occupied && occupied_count >= 4 {
+                        n.positions[row][col] = Position::Empty;
+                        num_changes += 1;
+                    }
+                }
+            }
+        }
+        (num_changes, n)
+    }
+
+    fn step2(&self) -> (i32, Layout) {
+        let mut n = self.clone();
+        let mut num_changes = 0;
+        for row in 0..self.positions.len() {
+            for col in 0..self.positions[0].len() {
+                let pos = self.positions[row][col];
+                if pos == Position::Occupied || pos == Position::Empty {
+                    let occupied_count = self.adj_count2(row as isize, col as isize);
+                    if self.positions[row][col] == Position::Empty && occupied_count == 0 {
+                        n.positions[row][col] = Position::Occupied;
+                        num_changes += 1;
+                    }
+                    if self.positions[row][col] == Position::Occupied && occupied_count >= 5 {
+                        n.positions[row][col] = Position::Empty;
+                        num_changes += 1;
+                    }
+                }
+            }
+        }
+        (num_changes, n)
+    }
+
+    fn adj_count2(&self, row: isize, col: isize) -> i32 {
+        let directions = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        let mut result = 0;
+        for (dx, dy) in directions {
+            if self.occupied_2(row, col, dx, dy) {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    fn occupied_2(&self, row: isize, col: isize, dx: isize, dy: isize) -> bool {
+        let mut r = row + dy;
+        let mut c = col + dx;
+
+        while r >= 0
+            && r < self.positions.len() as isize
+            && c >= 0
+            && c < self.positions[0].len() as isize
+        {
+            if self.positions[r as usize][c as usize] == Position::Empty {
+                return false;
+            }
+            if self.positions[r as usize][c as usize] == Position::Occupied {
+                return true;
+            }
+            c += dx;
+            r += dy;
+        }
+        return false;
+    }
+
+    fn adj_count(&self, row: isize, col: isize, value: Position) -> i32 {
+        let mut result = 0;
+        for r in row - 1..=row + 1 {
+            for c in col - 1..=col + 1 {
+                if (r != row || c != col)
+                    && r >= 0
+                    && r < self.positions.len() as isize
+                    && c >= 0
+                    && c < self.positions[0].len() as isize
+                    && self.positions[r as usize][c as usize] == value
+                {
+                    result += 1;
+                }
+            }
+        }
+        result
+    }
+
+    fn num_occupied(&self) -> usize {
+        self.positions
+            .iter()
+            .map(|r| r.iter().filter(|&p| *p == Position::Occupied).count())
+            .sum()
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for r in self.positions.iter() {
+            for v in r.iter() {
+                write!(f, "{v}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Layout> {
+    Ok(parse_error::nom(input, layout)?)
+}
+
+fn layout(input: &str) -> IResult<&str, Layout> {
+    let (input, positions) = separated_list0(line_ending, row)(input)?;
+    Ok((input, Layout { positions }))
+}
+
+fn row(input: &str) -> IResult<&str, Vec<Position>> {
+    many1(position)(input)
+}
+
+fn position(input: &str) -> IResult<&str, Position> {
+    let floor = map(tag("."), |_| Position::Floor);
+    let empty = map(tag("L"), |_| Position::Empty);
+    let occupied = map(tag("#"), |_| Position::Occupied);
+    alt((floor, empty, occupied))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Layout;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 37;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 26;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn animate_works() -> anyhow::Result<()> {
+        let recorder = animate(INPUT)?;
+        // the example settles after five rounds
+        assert_eq!(recorder.len(), 6);
+        let last = recorder.frames().last().unwrap();
+        assert_eq!((last.width(), last.height()), (10, 10));
+        assert_eq!(last.get(0, 0), [230, 90, 60]);
+        assert_eq!(last.get(1, 0), [40, 40, 40]);
+        Ok(())
+    }
+}
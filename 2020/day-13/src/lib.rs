@@ -0,0 +1,128 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, multispace0};
+use nom::combinator::{all_consuming, map};
+use nom::multi::separated_list0;
+use nom::sequence::terminated;
+use nom::IResult;
+use std::fmt::Display;
+
+use crate::Departure::Frequency;
+
+pub struct ProgramInput {
+    earliest_time: i32,
+    departures: Vec<Departure>,
+}
+
+enum Departure {
+    X,
+    Frequency(i32),
+}
+
+pub fn part1(program_input: &ProgramInput) -> anyhow::Result<i32> {
+    let (min_time, min_delta): (i32, i32) = program_input
+        .departures
+        .iter()
+        .filter_map(|d| match d {
+            Departure::X => None,
+            Frequency(x) => Some(x),
+        })
+        .copied()
+        .map(|x| {
+            let m = ((program_input.earliest_time / x) * x + x) - program_input.earliest_time;
+            (x, m)
+        })
+        .min_by(|(_, y1), (_, y2)| y1.cmp(y2))
+        .ok_or_else(|| anyhow!("no departures given"))?;
+    Ok(min_time * min_delta)
+}
+
+pub fn part2(program_input: &ProgramInput) -> anyhow::Result<i64> {
+    // bus x departs at t + offset, i.e. t ≡ -offset (mod x)
+    let congruences: Vec<(i64, i64)> = program_input
+        .departures
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, d)| match d {
+            Departure::X => None,
+            Frequency(x) => Some((-(offset as i64), *x as i64)),
+        })
+        .collect();
+    let (time, _) = number_theory::crt(congruences)?;
+    Ok(time)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<ProgramInput> {
+    let result = parse_error::nom(input, all_consuming(terminated(program_input, multispace0)))?;
+    Ok(result)
+}
+
+fn program_input(input: &str) -> IResult<&str, ProgramInput> {
+    let (input, earliest_time) = complete::i32(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, departures) = departures(input)?;
+    Ok((
+        input,
+        ProgramInput {
+            earliest_time,
+            departures,
+        },
+    ))
+}
+fn departures(input: &str) -> IResult<&str, Vec<Departure>> {
+    separated_list0(tag(","), departure)(input)
+}
+
+fn departure(input: &str) -> IResult<&str, Departure> {
+    let x = map(tag("x"), |_| Departure::X);
+    let frequency = map(complete::i32, Frequency);
+    alt((x, frequency))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+
+    type Input<'a> = ProgramInput;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"939
+7,13,x,x,59,x,31,19"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 295;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 1068781;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
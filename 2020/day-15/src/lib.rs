@@ -0,0 +1,93 @@
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::multi::separated_list0;
+use nom::IResult;
+use std::fmt::Display;
+
+pub const INPUT: &str = "0,12,6,13,20,1,17";
+
+pub fn part1(numbers: &[i32]) -> anyhow::Result<i32> {
+    let last_spoken = game(numbers, 2020);
+    Ok(last_spoken)
+}
+
+pub fn part2(numbers: &[i32]) -> anyhow::Result<i32> {
+    let last_spoken = game(numbers, 30000000);
+    Ok(last_spoken)
+}
+
+fn game(numbers: &[i32], num_rounds: usize) -> i32 {
+    let mut last_visit: Vec<usize> = vec![0; num_rounds];
+    let mut visited: Vec<bool> = vec![false; num_rounds];
+    let mut last_number = 0;
+    let mut next_number = 0;
+
+    for round in 0..num_rounds {
+        next_number = if round < numbers.len() {
+            numbers[round]
+        } else if !visited[last_number] {
+            0
+        } else {
+            (round - last_visit[last_number]) as i32
+        };
+        if round != 0 {
+            last_visit[last_number] = round;
+            visited[last_number] = true;
+        }
+        last_number = next_number as usize;
+    }
+    next_number
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<i32>> {
+    Ok(parse_error::nom(input, parse_input)?)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list0(tag(","), complete::i32)(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(_input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(INPUT)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let input = "0,3,6";
+        let result = part1(&parse(input)?)?;
+        let expected = 436;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let input = "0,3,6";
+        let result = part2(&parse(input)?)?;
+        let expected = 175594;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
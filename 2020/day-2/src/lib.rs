@@ -0,0 +1,121 @@
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete::{anychar, multispace0, newline, not_line_ending, space1};
+use nom::combinator::eof;
+use nom::multi::separated_list0;
+use nom::sequence::tuple;
+use nom::{character, IResult};
+use std::fmt::Display;
+
+pub fn part1(lines: &[Line]) -> anyhow::Result<u32> {
+    Ok(lines.iter().filter(|line| line.is_valid_part_1()).count() as u32)
+}
+
+pub fn part2(lines: &[Line]) -> anyhow::Result<u32> {
+    Ok(lines.iter().filter(|line| line.is_valid_part_2()).count() as u32)
+}
+
+#[derive(Debug)]
+struct Policy {
+    min_occurs: u32,
+    max_occurs: u32,
+    letter: char,
+}
+
+impl Policy {
+    fn is_valid(&self, password: &str) -> bool {
+        let num_occurs = password.chars().filter(|&c| c == self.letter).count() as u32;
+        self.min_occurs <= num_occurs && self.max_occurs >= num_occurs
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    policy: Policy,
+    password: String,
+}
+
+impl Line {
+    fn is_valid_part_1(&self) -> bool {
+        self.policy.is_valid(&self.password)
+    }
+
+    fn is_valid_part_2(&self) -> bool {
+        let pwbytes = self.password.as_bytes();
+        let pwlen = pwbytes.len();
+        let letter = self.policy.letter as u8;
+        let i1 = (self.policy.min_occurs - 1) as usize;
+        let i2 = (self.policy.max_occurs - 1) as usize;
+        let first_char = i1 < pwlen && pwbytes[i1] == letter;
+        let second_char = i2 < pwlen && pwbytes[i2] == letter;
+        first_char ^ second_char
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Line>> {
+    Ok(parse_error::nom(input, parse_lines)?)
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<Line>> {
+    let (input, lines) = separated_list0(newline, parse_line)(input)?;
+    let (input, _) = tuple((multispace0, eof))(input)?;
+    Ok((input, lines))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    let (input, policy) = parse_policy(input)?;
+    let (input, _) = tag(":")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, password) = not_line_ending(input)?;
+    Ok((
+        input,
+        Line {
+            policy,
+            password: password.to_string(),
+        },
+    ))
+}
+
+fn parse_policy(input: &str) -> IResult<&str, Policy> {
+    let (input, min_occurs) = character::complete::u32(input)?;
+    let (input, _) = tag("-")(input)?;
+    let (input, max_occurs) = character::complete::u32(input)?;
+    let (input, _) = space1(input)?;
+    let (input, letter) = anychar(input)?;
+
+    Ok((
+        input,
+        Policy {
+            min_occurs,
+            max_occurs,
+            letter,
+        },
+    ))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_part1() {}
+}
@@ -0,0 +1,139 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, newline};
+use nom::combinator::{eof, map, map_res};
+use nom::multi::{many1, separated_list1};
+use nom::IResult;
+
+pub fn part1(grid: &Grid<Value>) -> anyhow::Result<u64> {
+    Ok(treecount_for_slope(grid, 3, 1))
+}
+
+pub fn part2(grid: &Grid<Value>) -> anyhow::Result<u64> {
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+    let count = slopes
+        .into_iter()
+        .map(|(dx, dy)| treecount_for_slope(grid, dx, dy))
+        .product();
+    Ok(count)
+}
+
+fn treecount_for_slope(grid: &Grid<Value>, dx: usize, dy: usize) -> u64 {
+    let width = grid.width();
+    let height = grid.height();
+    if width < dx || height < dy {
+        return 0;
+    }
+    let slope = Pos::from((dy, dx));
+    let Some(map) = grid.wrapping() else {
+        return 0;
+    };
+    let mut pos = Pos::ORIGIN;
+    let mut count = 0;
+    while pos.row < (height - dy) as isize {
+        pos += slope;
+        if let Value::Tree = map[pos] {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Value {
+    Empty,
+    Tree,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let ch = match self {
+            Value::Empty => '.',
+            Value::Tree => '#',
+        };
+        write!(f, "{ch}")
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<Value>> {
+    Ok(parse_error::nom(input, parse_grid)?)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Grid<Value>> {
+    let (input, grid) = map_res(parse_lines, Grid::from_rows)(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+    Ok((input, grid))
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<Vec<Value>>> {
+    separated_list1(newline, parse_values)(input)
+}
+
+fn parse_values(input: &str) -> IResult<&str, Vec<Value>> {
+    many1(parse_value)(input)
+}
+
+fn parse_value(input: &str) -> IResult<&str, Value> {
+    let empty = map(tag("."), |_| Value::Empty);
+    let tree = map(tag("#"), |_| Value::Tree);
+    alt((empty, tree))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Grid<Value>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 336);
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Seat {
+    row: u32,
+    column: u32,
+}
+
+impl FromStr for Seat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let row = s
+            .chars()
+            .filter(|&c| c == 'F' || c == 'B')
+            .fold(0, |r, c| (r << 1) + if c == 'B' { 1 } else { 0 });
+        let column = s
+            .chars()
+            .filter(|&c| c == 'L' || c == 'R')
+            .fold(0, |r, c| (r << 1) + if c == 'R' { 1 } else { 0 });
+        Ok(Seat { row, column })
+    }
+}
+
+impl Seat {
+    fn id(&self) -> u32 {
+        self.row * 8 + self.column
+    }
+}
+
+pub fn part1(ids: &[u32]) -> anyhow::Result<u32> {
+    Ok(ids.iter().copied().max().unwrap_or(0))
+}
+
+pub fn part2(ids: &[u32]) -> anyhow::Result<u32> {
+    Ok(ids
+        .windows(2)
+        // w[0] = the first seat with missing successor
+        .filter(|w| w[1] != w[0] + 1)
+        .map(|w| w[0] + 1)
+        .next()
+        .unwrap_or(0))
+}
+
+/// The sorted ids of the seats.
+pub fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+    let ids: anyhow::Result<Vec<u32>> = input
+        .lines()
+        .map(|line| line.parse::<Seat>())
+        .map(|seat| seat.map(|r| r.id()))
+        .collect();
+    let mut ids = ids?;
+    ids.sort();
+    Ok(ids)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL"#;
+
+    #[test]
+    fn seat_from_str_works() -> anyhow::Result<()> {
+        let input = "BFFFBBFRRR";
+        let seat = input.parse::<Seat>()?;
+        let expected = Seat { row: 70, column: 7 };
+        assert_eq!(seat, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn seat_id_works() -> anyhow::Result<()> {
+        let input = Seat { row: 70, column: 7 };
+        let expected = 567;
+        let result = input.id();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+    fn part1_works() -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_core::Solution;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use nom::{
+    character::complete::{alpha1, line_ending},
+    combinator::all_consuming,
+    multi::separated_list0,
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Group {
+    persons: Vec<Person>,
+}
+
+impl Group {
+    fn all_answers(&self) -> BTreeSet<char> {
+        self.persons.iter().fold(BTreeSet::new(), |mut v, p| {
+            v.extend(p.answers.iter().copied());
+            v
+        })
+    }
+
+    fn common_answers(&self) -> BTreeSet<char> {
+        let mut result = BTreeSet::new();
+        for (i, v) in self.persons.iter().enumerate() {
+            if i == 0 {
+                result = v.answers.clone();
+            } else {
+                result = result.intersection(&v.answers).copied().collect();
+            }
+        }
+        result
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Person {
+    answers: BTreeSet<char>,
+}
+
+impl Person {
+    fn new(input: &str) -> Self {
+        Self {
+            answers: BTreeSet::from_iter(input.chars()),
+        }
+    }
+}
+
+pub fn part1(groups: &[Group]) -> anyhow::Result<u32> {
+    Ok(groups
+        .iter()
+        .map(|group| group.all_answers().len() as u32)
+        .sum())
+}
+
+pub fn part2(groups: &[Group]) -> anyhow::Result<u32> {
+    Ok(groups
+        .iter()
+        .map(|group| group.common_answers().len() as u32)
+        .sum())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Group>> {
+    Ok(parse_error::nom(input, all_consuming(groups))?)
+}
+
+fn groups(input: &str) -> IResult<&str, Vec<Group>> {
+    separated_list0(line_ending, group)(input)
+}
+
+fn group(input: &str) -> IResult<&str, Group> {
+    let (input, persons) = separated_list0(line_ending, person)(input)?;
+    Ok((input, Group { persons }))
+}
+
+fn person(input: &str) -> IResult<&str, Person> {
+    let (input, chars) = alpha1(input)?;
+    Ok((input, Person::new(chars)))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Group>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 11;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 6;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
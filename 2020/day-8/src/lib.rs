@@ -0,0 +1,192 @@
+use aoc_core::Solution;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+
+use anyhow::anyhow;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, multispace0, space1};
+use nom::combinator::{eof, map};
+use nom::multi::separated_list0;
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+
+pub fn part1(instructions: &[Instruction]) -> anyhow::Result<i32> {
+    let mut visited: BTreeSet<i32> = BTreeSet::new();
+    let mut acc = 0;
+    let mut pc = 0;
+    loop {
+        if pc < 0 || (pc as usize) > instructions.len() {
+            return Err(anyhow!("instrcution out of range {pc}"));
+        }
+        if !visited.insert(pc) {
+            return Ok(acc);
+        }
+
+        let instr = instructions[pc as usize];
+        match instr {
+            Instruction::Nop(_) => pc += 1,
+            Instruction::Acc(n) => {
+                acc += n;
+                pc += 1;
+            }
+            Instruction::Jmp(n) => {
+                pc += n;
+            }
+        }
+    }
+}
+
+pub fn part2(instructions: &[Instruction]) -> anyhow::Result<i32> {
+    fn try_alternative(
+        visited: &BTreeSet<i32>,
+        instructions: &[Instruction],
+        acc: i32,
+        pc: i32,
+        n: i32,
+    ) -> Option<i32> {
+        if n > 1 {
+            return None;
+        }
+        let mut visited = visited.clone();
+        let mut acc = acc;
+        let mut pc = pc;
+        loop {
+            if pc as usize == instructions.len() {
+                return Some(acc);
+            }
+            if pc < 0 || (pc as usize) > instructions.len() {
+                return None;
+            }
+            if !visited.insert(pc) {
+                return None;
+            }
+            let instruction = instructions[pc as usize];
+            match instruction {
+                Instruction::Nop(delta) => {
+                    // try JMP
+                    if let Some(result) =
+                        try_alternative(&visited, instructions, acc, pc + delta, n + 1)
+                    {
+                        return Some(result);
+                    }
+                    pc += 1;
+                }
+                Instruction::Acc(delta) => {
+                    pc += 1;
+                    acc += delta;
+                }
+                Instruction::Jmp(delta) => {
+                    // try NOP
+                    if let Some(result) =
+                        try_alternative(&visited, instructions, acc, pc + 1, n + 1)
+                    {
+                        return Some(result);
+                    }
+                    pc += delta;
+                }
+            }
+        }
+    }
+    let visited: BTreeSet<i32> = BTreeSet::new();
+    let acc = 0;
+    let pc = 0;
+    let result = try_alternative(&visited, instructions, acc, pc, 0);
+    result.ok_or_else(|| anyhow!("No solution found"))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Nop(n) => write!(f, "nop {n}"),
+            Instruction::Acc(n) => write!(f, "acc {n}"),
+            Instruction::Jmp(n) => write!(f, "jmp {n}"),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+    Ok(parse_error::nom(
+        input,
+        terminated(instructions, tuple((multispace0, eof))),
+    )?)
+}
+
+fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_list0(line_ending, instruction)(input)
+}
+
+fn instruction(input: &str) -> IResult<&str, Instruction> {
+    let nop = map(preceded(tuple((tag("nop"), space1)), complete::i32), |n| {
+        Instruction::Nop(n)
+    });
+    let acc = map(preceded(tuple((tag("acc"), space1)), complete::i32), |n| {
+        Instruction::Acc(n)
+    });
+    let jmp = map(preceded(tuple((tag("jmp"), space1)), complete::i32), |n| {
+        Instruction::Jmp(n)
+    });
+    alt((nop, acc, jmp))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 5;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 8;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
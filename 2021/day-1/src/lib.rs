@@ -0,0 +1,78 @@
+use aoc_core::Solution;
+use nom::character::complete::line_ending;
+use nom::multi::separated_list1;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(values: &[u32]) -> anyhow::Result<usize> {
+    Ok(values.windows(2).filter(|w| w[0] < w[1]).count())
+}
+
+pub fn part2(values: &[u32]) -> anyhow::Result<usize> {
+    let bla = values
+        .windows(3)
+        .map(|w| w.iter().sum())
+        .collect::<Vec<u32>>();
+    Ok(bla.windows(2).filter(|w| w[0] < w[1]).count())
+}
+
+fn values(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(line_ending, nom::character::complete::u32)(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+    Ok(parse_error::nom(input, values)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 7;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 5;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
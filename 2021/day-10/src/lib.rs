@@ -0,0 +1,163 @@
+extern crate core;
+
+use aoc_core::Solution;
+use nom::character::complete::{line_ending, one_of};
+use nom::combinator::recognize;
+use nom::multi::{many1, separated_list1};
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(lines: &[&str]) -> anyhow::Result<i32> {
+    Ok(lines
+        .iter()
+        .filter_map(|&line| first_incorrect(line))
+        .map(|(found, _)| score_for_part1(found))
+        .sum())
+}
+
+pub fn part2(lines: &[&str]) -> anyhow::Result<i64> {
+    let bla: Vec<_> = lines
+        .iter()
+        .filter_map(|line| missing_closing(line))
+        .collect();
+    let mut scores: Vec<_> = bla
+        .iter()
+        .map(|s| s.chars().fold(0, |a, c| a * 5 + score_for_part2(c)))
+        .collect();
+    scores.sort();
+    Ok(scores[scores.len() / 2])
+}
+
+fn score_for_part1(c: char) -> i32 {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        c => unreachable!("{}", c),
+    }
+}
+
+fn score_for_part2(c: char) -> i64 {
+    match c {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        c => unreachable!("{}", c),
+    }
+}
+
+fn first_incorrect(input: &str) -> Option<(char, char)> {
+    let mut stack = Vec::new();
+
+    for c in input.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if let Some(open) = stack.pop() {
+                    let wanted = closing_character(open);
+                    if c != wanted {
+                        return Some((c, wanted));
+                    }
+                }
+            }
+            c => unreachable!("{}", c),
+        }
+    }
+    None
+}
+
+fn missing_closing(input: &str) -> Option<String> {
+    let mut stack = Vec::new();
+
+    for c in input.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if let Some(open) = stack.pop() {
+                    let wanted = closing_character(open);
+                    if wanted != c {
+                        return None;
+                    }
+                }
+            }
+            c => unreachable!("{}", c),
+        }
+    }
+
+    Some(stack.iter().rev().map(|c| closing_character(*c)).collect())
+}
+
+fn closing_character(open: char) -> char {
+    match open {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!("{}", open),
+    }
+}
+
+fn lines(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(line_ending, line)(input)
+}
+
+fn line(input: &str) -> IResult<&str, &str> {
+    recognize(many1(one_of("()[]{}<>")))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+    Ok(parse_error::nom(input, lines)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn hase() {
+        let input = "[({(<(())[]>[[{[]{<()<>>";
+        let result = first_incorrect(input);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 26397;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 288957;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+extern crate core;
+
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use nom::character::complete::{digit1, line_ending};
+use nom::combinator::{map, map_res};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+pub fn part1(grid: &Grid<i32>) -> anyhow::Result<i32> {
+    let mut grid = grid.clone();
+    let num_rounds = 100;
+    let mut result = 0;
+    for _round in 1..=num_rounds {
+        increase_energy_levels(&mut grid);
+        result += flash_octopuses(&mut grid);
+    }
+    Ok(result)
+}
+
+pub fn part2(grid: &Grid<i32>) -> anyhow::Result<i32> {
+    let mut grid = grid.clone();
+    let mut result = 0;
+    loop {
+        result += 1;
+        increase_energy_levels(&mut grid);
+        flash_octopuses(&mut grid);
+        if all_octopuses_flashed(&grid) {
+            break;
+        }
+    }
+    Ok(result)
+}
+
+fn all_octopuses_flashed(grid: &Grid<i32>) -> bool {
+    grid.cells().iter().all(|&level| level == 0)
+}
+
+fn flash_octopuses(grid: &mut Grid<i32>) -> i32 {
+    let flashing: Vec<Pos> = grid
+        .iter()
+        .filter(|&(_, &level)| level > 9)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut q = flashing.clone();
+    let mut visited: HashSet<Pos> = HashSet::from_iter(flashing);
+    let mut result = 0;
+    while let Some(pos) = q.pop() {
+        result += 1;
+
+        for next in pos.neighbours8() {
+            let Some(level) = grid.get_mut(next) else {
+                continue;
+            };
+            *level += 1;
+            if *level > 9 && visited.insert(next) {
+                q.push(next);
+            }
+        }
+    }
+
+    for pos in visited.into_iter() {
+        grid[pos] = 0;
+    }
+
+    result
+}
+
+fn increase_energy_levels(grid: &mut Grid<i32>) {
+    for (_, level) in grid.iter_mut() {
+        *level += 1;
+    }
+}
+
+fn lines(input: &str) -> IResult<&str, Grid<i32>> {
+    map_res(separated_list1(line_ending, line), Grid::from_rows)(input)
+}
+
+fn line(input: &str) -> IResult<&str, Vec<i32>> {
+    map(digit1, |s: &str| {
+        s.chars().map(|c| c.to_digit(10).unwrap() as i32).collect()
+    })(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<i32>> {
+    Ok(parse_error::nom(input, lines)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 1656;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 195;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+extern crate core;
+
+use aoc_core::Solution;
+use graph_export::{Graph, Shape, Style};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, line_ending};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+pub fn part1(edges: &[Edge]) -> anyhow::Result<usize> {
+    let adj: BTreeMap<&str, Vec<&str>> = build_adj(edges);
+    Ok(num_paths(&adj))
+}
+
+pub fn part2(edges: &[Edge]) -> anyhow::Result<usize> {
+    let adj: BTreeMap<&str, Vec<&str>> = build_adj(edges);
+    Ok(num_paths2(&adj))
+}
+
+/// The cave system, with big caves drawn as boxes and small ones as circles.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let edges = parse_error::nom(input, edges)?;
+    let mut graph = Graph::undirected();
+    graph.style("end", Style::new().shape(Shape::Hexagon).fill("#9fd89f"));
+    graph.style("big", Style::new().shape(Shape::Box).fill("#a8c8f0"));
+    graph.style("small", Style::new().shape(Shape::Circle));
+    for edge in edges.iter() {
+        for cave in [edge.from, edge.to] {
+            let kind = match cave {
+                "start" | "end" => "end",
+                _ if first_is_lowercase(cave) => "small",
+                _ => "big",
+            };
+            graph.node(cave).kind(kind);
+        }
+        graph.edge(edge.from, edge.to);
+    }
+    Ok(graph)
+}
+
+fn build_adj<'a>(edges: &[Edge<'a>]) -> BTreeMap<&'a str, Vec<&'a str>> {
+    let mut adj: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for edge in edges.into_iter() {
+        let e = adj.entry(edge.from).or_default();
+
+        if edge.to != "start" {
+            e.push(edge.to);
+        }
+
+        let e = adj.entry(edge.to).or_default();
+        if edge.from != "start" {
+            e.push(edge.from);
+        }
+    }
+    adj
+}
+
+fn num_paths(adj: &BTreeMap<&str, Vec<&str>>) -> usize {
+    let mut stack = vec![("start", vec!["start"])];
+    let mut result = 0;
+
+    while let Some((from, path)) = stack.pop() {
+        if from == "end" {
+            result += 1;
+            continue;
+        }
+        for to in adj.get(from).unwrap().iter() {
+            if to.chars().next().unwrap().is_lowercase() && path.contains(to) {
+                continue;
+            }
+            let mut next_path = path.to_vec();
+            next_path.push(to);
+            stack.push((to, next_path));
+        }
+    }
+
+    result
+}
+
+fn num_paths2(adj: &BTreeMap<&str, Vec<&str>>) -> usize {
+    let mut stack = vec![("start", vec!["start"])];
+    let mut result = 0;
+
+    while let Some((from, path)) = stack.pop() {
+        if from == "end" {
+            result += 1;
+            continue;
+        }
+        for to in adj.get(from).unwrap().iter() {
+            if !check_valid(&path, to) {
+                continue;
+            }
+            let mut next_path = path.to_vec();
+            next_path.push(to);
+            stack.push((to, next_path));
+        }
+    }
+
+    result
+}
+
+fn first_is_lowercase(s: &str) -> bool {
+    s.chars().next().map(|c| c.is_lowercase()).unwrap_or(false)
+}
+
+fn check_valid(path: &[&str], to: &str) -> bool {
+    if !first_is_lowercase(to) {
+        return true;
+    }
+
+    let hist = create_hist(path);
+    let count = hist.get(to).copied().unwrap_or(0);
+
+    if to == "start" || to == "end" && count > 0 {
+        return false;
+    }
+
+    let max_count = hist.values().max().copied().unwrap_or(0);
+
+    if max_count < 2 {
+        return count <= 1;
+    }
+
+    count == 0
+}
+
+fn create_hist<'a>(path: &[&'a str]) -> HashMap<&'a str, i32> {
+    let mut hist = HashMap::new();
+    for node in path.into_iter().filter(|n| first_is_lowercase(*n)) {
+        let entry = hist.entry(*node).or_insert(0);
+        *entry += 1;
+    }
+    hist
+}
+
+#[derive(Debug)]
+pub struct Edge<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+fn edges(input: &str) -> IResult<&str, Vec<Edge>> {
+    separated_list1(line_ending, edge)(input)
+}
+
+fn edge(input: &str) -> IResult<&str, Edge> {
+    let (input, from) = alpha1(input)?;
+    let (input, _) = tag("-")(input)?;
+    let (input, to) = alpha1(input)?;
+
+    Ok((input, Edge { from, to }))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Edge<'_>>> {
+    Ok(parse_error::nom(input, edges)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Edge<'a>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 10;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 36;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph(INPUT)?;
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.edge_count(), 7);
+        assert!(graph.to_dot().contains(r#""start" -- "A";"#));
+        Ok(())
+    }
+}
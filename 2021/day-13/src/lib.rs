@@ -0,0 +1,194 @@
+extern crate core;
+
+use aoc_core::Solution;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, one_of};
+use nom::multi::{many1, separated_list1};
+use nom::IResult;
+
+pub fn part1(bla: &Paper) -> anyhow::Result<usize> {
+    let instruction = bla.instructions.first().unwrap();
+    let next_points: BTreeSet<Point> = match instruction {
+        FoldInstruction::X(x) => bla.points.iter().map(|p| p.folded_along_x(*x)).collect(),
+        FoldInstruction::Y(y) => bla.points.iter().map(|p| p.folded_along_y(*y)).collect(),
+    };
+    let result = next_points.len();
+    Ok(result)
+}
+
+pub fn part2(bla: &Paper) -> anyhow::Result<String> {
+    let final_points = bla
+        .instructions
+        .iter()
+        .fold(bla.points.clone(), |points, instruction| {
+            let next_points: BTreeSet<Point> = match instruction {
+                FoldInstruction::X(x) => points.into_iter().map(|p| p.folded_along_x(*x)).collect(),
+                FoldInstruction::Y(y) => points.into_iter().map(|p| p.folded_along_y(*y)).collect(),
+            };
+            next_points
+        });
+    let paper = Paper {
+        points: final_points,
+        instructions: Vec::new(),
+    };
+    ocr::decode(&paper.to_string())
+}
+
+#[derive(Debug, Copy, Clone)]
+enum FoldInstruction {
+    X(i32),
+    Y(i32),
+}
+
+#[derive(Debug)]
+pub struct Paper {
+    points: BTreeSet<Point>,
+    instructions: Vec<FoldInstruction>,
+}
+
+impl Paper {
+    fn char_at(&self, x: i32, y: i32) -> char {
+        if self.points.contains(&Point { x, y }) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let width = self.points.iter().map(|p| p.x).max().unwrap_or(0);
+        let height = self.points.iter().map(|p| p.y).max().unwrap_or(0);
+
+        for y in 0..=height {
+            for x in 0..=width {
+                write!(f, "{}", self.char_at(x, y))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Copy, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn folded_along_x(&self, x: i32) -> Point {
+        if self.x < x {
+            *self
+        } else {
+            let xdiff = self.x - x;
+            Point {
+                x: x - xdiff,
+                y: self.y,
+            }
+        }
+    }
+
+    fn folded_along_y(&self, y: i32) -> Point {
+        if self.y < y {
+            *self
+        } else {
+            let ydiff = self.y - y;
+            Point {
+                x: self.x,
+                y: y - ydiff,
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Paper> {
+    Ok(parse_error::nom(input, parse_input)?)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Paper> {
+    let (input, points) = points(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, instructions) = instructions(input)?;
+
+    Ok((
+        input,
+        Paper {
+            points: points.into_iter().collect(),
+            instructions,
+        },
+    ))
+}
+
+fn instructions(input: &str) -> IResult<&str, Vec<FoldInstruction>> {
+    separated_list1(line_ending, instruction)(input)
+}
+
+fn instruction(input: &str) -> IResult<&str, FoldInstruction> {
+    let (input, _) = tag("fold along ")(input)?;
+    let (input, xy) = one_of("xy")(input)?;
+    let (input, _) = tag("=")(input)?;
+    let (input, coordinate) = complete::i32(input)?;
+    let instruction = match xy {
+        'x' => FoldInstruction::X(coordinate),
+        'y' => FoldInstruction::Y(coordinate),
+        _ => unreachable!("{}", xy),
+    };
+
+    Ok((input, instruction))
+}
+
+fn points(input: &str) -> IResult<&str, Vec<Point>> {
+    separated_list1(line_ending, point)(input)
+}
+
+fn point(input: &str) -> IResult<&str, Point> {
+    let (input, x) = complete::i32(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = complete::i32(input)?;
+    Ok((input, Point { x, y }))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Paper;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 17;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
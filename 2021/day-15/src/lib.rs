@@ -0,0 +1,121 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos, Wrapping};
+use search::dijkstra;
+use std::fmt::Display;
+
+use nom::character::complete::{digit1, line_ending, multispace0};
+use nom::combinator::{eof, map, map_res};
+use nom::multi::separated_list1;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+
+pub fn part1(grid: &Grid<u32>) -> anyhow::Result<u32> {
+    Ok(find_lowest_total_risk_part1(grid).unwrap())
+}
+
+pub fn part2(grid: &Grid<u32>) -> anyhow::Result<u32> {
+    Ok(find_lowest_total_risk_part2(grid).unwrap())
+}
+
+fn find_lowest_total_risk_part1(grid: &Grid<u32>) -> Option<u32> {
+    let target = Pos::from((grid.height() - 1, grid.width() - 1));
+    dijkstra(
+        &Pos::ORIGIN,
+        |&pos| grid.neighbours4(pos).map(|next| (next, grid[next])),
+        |&pos| pos == target,
+    )
+    .map(|(_path, cost)| cost)
+}
+
+fn find_lowest_total_risk_part2(tile: &Grid<u32>) -> Option<u32> {
+    let tiles = tile.wrapping()?;
+    let width = 5 * tile.width() as isize;
+    let height = 5 * tile.height() as isize;
+    let target = Pos::new(height - 1, width - 1);
+    dijkstra(
+        &Pos::ORIGIN,
+        |&pos| {
+            pos.neighbours4()
+                .into_iter()
+                .filter(|next| (0..height).contains(&next.row) && (0..width).contains(&next.col))
+                .map(move |next| (next, expanded_risk(tile, tiles, pos)))
+        },
+        |&pos| pos == target,
+    )
+    .map(|(_path, cost)| cost)
+}
+
+/// The risk level at `pos` in the full map, which is the tile repeated five times in
+/// each direction with the risk increasing by one per repetition.
+fn expanded_risk(tile: &Grid<u32>, tiles: Wrapping<'_, u32>, pos: Pos) -> u32 {
+    let grid_row = pos.row as u32 / tile.height() as u32;
+    let grid_col = pos.col as u32 / tile.width() as u32;
+    let value = tiles[pos];
+
+    (value + grid_col + grid_row - 1) % 9 + 1
+}
+
+fn grid(input: &str) -> IResult<&str, Grid<u32>> {
+    map_res(
+        separated_list1(
+            line_ending,
+            map(digit1, |s: &str| {
+                s.chars()
+                    .map(|c| c.to_digit(10).unwrap())
+                    .collect::<Vec<_>>()
+            }),
+        ),
+        Grid::from_rows,
+    )(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+    Ok(parse_error::nom(
+        input,
+        terminated(grid, tuple((multispace0, eof))),
+    )?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 40;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 307;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
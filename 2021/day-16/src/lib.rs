@@ -1,3 +1,6 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use std::fmt::Display;
 use std::fs::File;
 use std::io::Read;
 
@@ -7,6 +10,14 @@ use nom::multi::many1;
 use nom::IResult;
 use nom::{error::ErrorKind, Err};
 
+pub fn part1(packet: &Packet) -> anyhow::Result<usize> {
+    Ok(packet.sum_of_packet_versions())
+}
+
+pub fn part2(packet: &Packet) -> anyhow::Result<u64> {
+    Ok(packet.value())
+}
+
 #[derive(Debug)]
 pub struct Header {
     pub version: u8,
@@ -215,6 +226,13 @@ fn num_packets(input: (&[u8], usize)) -> IResult<(&[u8], usize), Operator> {
     Ok((i, Operator::NumPackets(num_packets, result)))
 }
 
+pub fn parse(input: &str) -> anyhow::Result<Packet> {
+    let input_data = parse_error::nom(input, input_data)?;
+    let input = (input_data.as_ref(), 0usize);
+    let (_, packet) = packet(input).map_err(|e| anyhow!("invalid packet: {e}"))?;
+    Ok(packet)
+}
+
 type InputData = Vec<u8>;
 
 pub fn input_data(input: &str) -> IResult<&str, InputData> {
@@ -239,3 +257,45 @@ pub fn read_file(name: &str) -> anyhow::Result<String> {
     f.read_to_string(&mut result)?;
     Ok(result)
 }
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Packet;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse("A0016C880162017C3686B18A3D4780")?)?;
+        let expected = 31;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse("9C0141080250320F1802104A08")?)?;
+        let expected = 1;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
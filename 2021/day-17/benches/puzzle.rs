@@ -0,0 +1 @@
+aoc_bench::main!(y2021_day_17::Puzzle);
@@ -0,0 +1,125 @@
+use aoc_core::Solution;
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(bounds: &Bounds) -> anyhow::Result<i32> {
+    Ok((1..=bounds.xmax)
+        .cartesian_product(1..bounds.ymin.abs())
+        .filter_map(|(dx, dy)| simulate(dx, dy, bounds))
+        .max()
+        .unwrap())
+}
+
+pub fn part2(bounds: &Bounds) -> anyhow::Result<usize> {
+    Ok((1..=bounds.xmax)
+        .cartesian_product(bounds.ymin..bounds.ymin.abs())
+        .filter_map(|(dx, dy)| simulate(dx, dy, bounds))
+        .count())
+}
+
+fn simulate(initial_dx: i32, initial_dy: i32, bounds: &Bounds) -> Option<i32> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut dx = initial_dx;
+    let mut dy = initial_dy;
+    let mut max_y = i32::MIN;
+
+    while check_not_out_of_bounds(x, y, bounds) {
+        x += dx;
+        y += dy;
+        max_y = y.max(max_y);
+        if check_in_bounds(x, y, bounds) {
+            return Some(max_y);
+        }
+        // dx > 0 => dx -= 1
+        dx -= dx.signum();
+        dy -= 1;
+    }
+
+    None
+}
+
+fn check_not_out_of_bounds(x: i32, y: i32, bounds: &Bounds) -> bool {
+    x <= bounds.xmax && y >= bounds.ymin
+}
+
+fn check_in_bounds(x: i32, y: i32, bounds: &Bounds) -> bool {
+    (bounds.xmin..=bounds.xmax).contains(&x) && (bounds.ymin..=bounds.ymax).contains(&y)
+}
+
+#[derive(Debug)]
+pub struct Bounds {
+    xmin: i32,
+    xmax: i32,
+    ymin: i32,
+    ymax: i32,
+}
+
+fn input_data(input: &str) -> IResult<&str, Bounds> {
+    let (input, _) = tag("target area: x=")(input)?;
+    let (input, (xmin, xmax)) = separated_pair(complete::i32, tag(".."), complete::i32)(input)?;
+    let (input, _) = tag(", y=")(input)?;
+    let (input, (ymin, ymax)) = separated_pair(complete::i32, tag(".."), complete::i32)(input)?;
+
+    Ok((
+        input,
+        Bounds {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+        },
+    ))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Bounds> {
+    Ok(parse_error::nom(input, input_data)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Bounds;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 45;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 112;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_core::Solution;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(commands: &[Command]) -> anyhow::Result<u32> {
+    let (x, y) = commands.iter().fold((0, 0), |(x, y), c| match c {
+        Command::Forward(n) => (x + n, y),
+        Command::Up(n) => (x, y - n),
+        Command::Down(n) => (x, y + n),
+    });
+    Ok(x * y)
+}
+
+pub fn part2(commands: &[Command]) -> anyhow::Result<u32> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        match command {
+            Command::Forward(n) => {
+                x += n;
+                y += aim * n;
+            }
+            Command::Up(n) => {
+                aim -= n;
+            }
+            Command::Down(n) => {
+                aim += n;
+            }
+        };
+    }
+
+    Ok(x * y)
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+fn commands(input: &str) -> IResult<&str, Vec<Command>> {
+    separated_list1(line_ending, command)(input)
+}
+
+fn command(input: &str) -> IResult<&str, Command> {
+    use nom::character::complete::u32 as u32_parser;
+    let forward = map(preceded(tag("forward "), u32_parser), |n| {
+        Command::Forward(n)
+    });
+    let up = map(preceded(tag("up "), u32_parser), |n| Command::Up(n));
+    let down = map(preceded(tag("down "), u32_parser), |n| Command::Down(n));
+    alt((forward, up, down))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Command>> {
+    Ok(parse_error::nom(input, commands)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 150;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 900;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_core::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, space1};
+use nom::multi::separated_list1;
+use nom::sequence::delimited;
+use nom::IResult;
+
+pub fn part1(lines: &[Line]) -> anyhow::Result<usize> {
+    let mut points: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for line in lines
+        .iter()
+        .filter(|l| l.p1.0 == l.p2.0 || l.p1.1 == l.p2.1)
+    {
+        let x1 = line.p1.0.min(line.p2.0);
+        let x2 = line.p1.0.max(line.p2.0);
+        let y1 = line.p1.1.min(line.p2.1);
+        let y2 = line.p1.1.max(line.p2.1);
+
+        for x in x1..=x2 {
+            for y in y1..=y2 {
+                *points.entry((x, y)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    Ok(points.values().filter(|&&c| c > 1).count())
+}
+
+pub fn part2(lines: &[Line]) -> anyhow::Result<usize> {
+    let mut points: HashMap<(i32, i32), i32> = HashMap::new();
+
+    for line in lines.iter() {
+        let (x1, y1) = line.p1;
+        let (x2, y2) = line.p2;
+        let dx = (x2 - x1).signum();
+        let dy = (y2 - y1).signum();
+
+        let mut x = x1;
+        let mut y = y1;
+        *points.entry((x, y)).or_insert(0) += 1;
+
+        while x != x2 || y != y2 {
+            x += dx;
+            y += dy;
+            *points.entry((x, y)).or_insert(0) += 1;
+        }
+    }
+
+    Ok(points.values().filter(|&&c| c > 1).count())
+}
+
+#[derive(Debug)]
+pub struct Line {
+    p1: (i32, i32),
+    p2: (i32, i32),
+}
+
+fn lines(input: &str) -> IResult<&str, Vec<Line>> {
+    separated_list1(line_ending, line)(input)
+}
+
+fn line(input: &str) -> IResult<&str, Line> {
+    
+    let (input, p1) = point(input)?;
+    let (input, _) = delimited(space1, tag("->"), space1)(input)?;
+    let (input, p2) = point(input)?;
+
+    Ok((input, Line { p1, p2 }))
+}
+
+fn point(input: &str) -> IResult<&str, (i32, i32)> {
+    use nom::character::complete::i32 as i32_parser;
+    let (input, x) = i32_parser(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = i32_parser(input)?;
+
+    Ok((input, (x, y)))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Line>> {
+    Ok(parse_error::nom(input, lines)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 5;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 12;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
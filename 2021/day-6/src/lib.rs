@@ -0,0 +1,104 @@
+use aoc_core::Solution;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::multi::separated_list1;
+use nom::IResult;
+
+pub fn part1(fishes: &[i32]) -> anyhow::Result<usize> {
+    let mut fishes = fishes.to_vec();
+    let num_days = 80;
+
+    for _ in 0..num_days {
+        let count = fishes.iter().filter(|&&v| v == 0).count();
+        for v in fishes.iter_mut() {
+            if *v == 0 {
+                *v = 7;
+            }
+        }
+        for v in fishes.iter_mut() {
+            *v -= 1;
+        }
+        for _ in 0..count {
+            fishes.push(8);
+        }
+    }
+    Ok(fishes.len())
+}
+
+pub fn part2(fishes: &[i32]) -> anyhow::Result<u64> {
+    let mut fish_map: BTreeMap<i32, u64> = BTreeMap::new();
+
+    for &fish in fishes.iter() {
+        *fish_map.entry(fish).or_insert(0) += 1;
+    }
+    let num_days = 256;
+
+    for _ in 0..num_days {
+        let count = *fish_map.entry(0).or_insert(0);
+        *fish_map.entry(7).or_insert(0) += count;
+
+        for i in 1..=8 {
+            let next_entry = fish_map.get(&i).copied().unwrap_or_default();
+            let entry = fish_map.entry(i - 1).or_insert(0);
+            *entry = next_entry;
+        }
+
+        *fish_map.entry(8).or_insert(0) = count;
+    }
+
+    Ok(fish_map.values().copied().sum())
+}
+
+fn fishes(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(tag(","), nom::character::complete::i32)(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<i32>> {
+    Ok(parse_error::nom(input, fishes)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 5934;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 26984457539u64;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::multi::separated_list1;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(positions: &[i32]) -> anyhow::Result<i32> {
+    let min_position = positions.iter().min().copied().unwrap();
+    let max_position = positions.iter().max().copied().unwrap();
+    Ok((min_position..=max_position)
+        .map(|p| fuel_consumtion(positions, p))
+        .min()
+        .unwrap())
+}
+
+fn fuel_consumtion(positions: &[i32], position: i32) -> i32 {
+    positions.iter().map(|&p| (p - position).abs()).sum()
+}
+
+pub fn part2(positions: &[i32]) -> anyhow::Result<i32> {
+    let min_position = positions.iter().min().copied().unwrap();
+    let max_position = positions.iter().max().copied().unwrap();
+    Ok((min_position..=max_position)
+        .map(|p| crab_fuel_consumtion(positions, p))
+        .min()
+        .unwrap())
+}
+
+fn crab_fuel_consumtion(positions: &[i32], position: i32) -> i32 {
+    positions
+        .iter()
+        .map(|&p| {
+            let n = (p - position).abs();
+            n * (n + 1) / 2
+        })
+        .sum()
+}
+
+fn positions(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(tag(","), nom::character::complete::i32)(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<i32>> {
+    Ok(parse_error::nom(input, positions)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 37;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 168;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
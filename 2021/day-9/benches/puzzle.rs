@@ -0,0 +1 @@
+aoc_bench::main!(y2021_day_9::Puzzle);
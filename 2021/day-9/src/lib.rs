@@ -0,0 +1,107 @@
+extern crate core;
+
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Display;
+
+use nom::character::complete::{digit1, line_ending};
+use nom::combinator::{map, map_res};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+pub fn part1(grid: &Grid<i32>) -> anyhow::Result<i32> {
+    let result = low_points(grid);
+    Ok(result.into_iter().map(|pos| grid[pos] + 1).sum())
+}
+
+pub fn part2(grid: &Grid<i32>) -> anyhow::Result<usize> {
+    let points = low_points(grid);
+    let basins: BTreeSet<_> = points
+        .into_iter()
+        .map(|pos| basin_size(grid, pos))
+        .collect();
+    let mut sizes: Vec<usize> = basins.into_iter().map(|basin| basin.len()).collect();
+    sizes.sort_by(|a, b| b.cmp(a));
+    Ok(sizes.iter().take(3).product())
+}
+
+fn basin_size(grid: &Grid<i32>, pos: Pos) -> BTreeSet<Pos> {
+    let mut q = VecDeque::from([pos]);
+    let mut visited = BTreeSet::from([pos]);
+
+    while let Some(pos) = q.pop_front() {
+        for next in grid.neighbours4(pos) {
+            if grid[next] != 9 && visited.insert(next) {
+                q.push_back(next);
+            }
+        }
+    }
+
+    visited
+}
+
+fn low_points(grid: &Grid<i32>) -> Vec<Pos> {
+    grid.iter()
+        .filter(|&(pos, &value)| grid.neighbours4(pos).all(|next| value < grid[next]))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn grid(input: &str) -> IResult<&str, Grid<i32>> {
+    map_res(separated_list1(line_ending, line), Grid::from_rows)(input)
+}
+
+fn line(input: &str) -> IResult<&str, Vec<i32>> {
+    map(digit1, |s: &str| {
+        s.chars().map(|c| (c as i32 - '0' as i32)).collect()
+    })(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<i32>> {
+    Ok(parse_error::nom(input, grid)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Grid<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 15;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 1134;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
+pub fn part1(sums: &[i32]) -> anyhow::Result<i32> {
+    Ok(sums.first().copied().unwrap_or(0))
+}
+
+pub fn part2(sums: &[i32]) -> anyhow::Result<i32> {
+    Ok(sums.iter().take(3).sum())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<i32>> {
+    let mut current_sum = 0;
+    let mut sums = Vec::new();
+    for line in input.lines() {
+        if !line.is_empty() {
+            let value = line.parse::<i32>()?;
+            current_sum += value;
+        } else {
+            sums.push(current_sum);
+            current_sum = 0;
+        }
+    }
+
+    sums.sort_by(|a, b| b.cmp(a));
+    Ok(sums)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
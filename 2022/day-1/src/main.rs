@@ -1,32 +1,20 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use std::fs;
+
+use anyhow::Context;
+
+use y2022_day_1::{parse, part1, part2};
 
 fn main() -> anyhow::Result<()> {
-    let lines = read_lines("./input.txt")?;
-    let mut current_sum = 0;
-    let mut sums = Vec::new();
-    for line in lines {
-        let line = line?;
-        if !line.is_empty() {
-            let value = line.parse::<i32>()?;
-            current_sum += value;
-        } else {
-            sums.push(current_sum);
-            current_sum = 0;
-        }
-    }
+    let filename = "./input.txt";
+    let content =
+        fs::read_to_string(filename).with_context(|| format!("cannot read {filename}"))?;
 
-    sums.sort_by(|a, b| b.cmp(a));
-    let total: i32 = sums.iter().take(3).sum();
-    println!("{}", total);
-    Ok(())
-}
+    let sums = parse(&content)?;
+    let result = part1(&sums)?;
+    println!("{}", result);
+
+    let result = part2(&sums)?;
+    println!("{}", result);
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    Ok(())
 }
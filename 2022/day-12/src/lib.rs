@@ -0,0 +1,126 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+
+use nom::character::complete::{alpha1, line_ending};
+use nom::combinator::{map, map_res};
+use nom::multi::separated_list1;
+use nom::IResult;
+use search::{bfs, dijkstra_all};
+
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<usize> {
+    shortest_path(grid).ok_or_else(|| anyhow!("no path found"))
+}
+
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<usize> {
+    shortest_hike(grid).ok_or_else(|| anyhow!("no path found"))
+}
+
+fn shortest_path(grid: &Grid<char>) -> Option<usize> {
+    let start = grid.find(|&c| c == 'S')?;
+    let end = grid.find(|&c| c == 'E')?;
+    let path = bfs(&start, |&from| possible_moves(grid, from), |&p| p == end)?;
+
+    Some(path.len() - 1)
+}
+
+fn shortest_hike(grid: &Grid<char>) -> Option<usize> {
+    let end = grid.find(|&c| c == 'E')?;
+
+    // walk downhill from the end, i.e. follow the moves backwards
+    let parents = dijkstra_all(&end, |&from| reverse_moves(grid, from).map(|to| (to, 1)));
+
+    parents
+        .iter()
+        .filter_map(|(&pos, &(_, distance))| {
+            if grid[pos] == 'a' || grid[pos] == 'S' {
+                Some(distance)
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+/// The positions from which `to` can be reached in a single step.
+fn reverse_moves(grid: &Grid<char>, to: Pos) -> impl Iterator<Item = Pos> + '_ {
+    grid.neighbours4(to)
+        .filter(move |&from| is_valid(grid[from], grid[to]))
+}
+
+fn possible_moves(grid: &Grid<char>, from: Pos) -> impl Iterator<Item = Pos> + '_ {
+    grid.neighbours4(from)
+        .filter(move |&to| is_valid(grid[from], grid[to]))
+}
+
+fn is_valid(from: char, to: char) -> bool {
+    let from_val = to_code(from);
+    let to_val = to_code(to);
+    to_val - from_val <= 1
+}
+
+fn to_code(c: char) -> i32 {
+    match c {
+        'S' => 'a' as i32,
+        'E' => 'z' as i32,
+        _ => c as i32,
+    }
+}
+
+fn grid(input: &str) -> IResult<&str, Grid<char>> {
+    map_res(
+        separated_list1(line_ending, map(alpha1, |s: &str| s.chars().collect())),
+        Grid::from_rows,
+    )(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    Ok(parse_error::nom(input, grid)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1_works() {
+        let result = part1(&parse(INPUT).unwrap()).unwrap();
+        let expected = 31;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_works() {
+        let result = part2(&parse(INPUT).unwrap()).unwrap();
+        let expected = 29;
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,163 @@
+extern crate core;
+
+use aoc_core::Solution;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::{many1, separated_list0, separated_list1};
+use nom::sequence::delimited;
+use nom::IResult;
+
+pub fn part1(sequence_pairs: &[(Item, Item)]) -> anyhow::Result<usize> {
+    Ok(sequence_pairs
+        .iter()
+        .enumerate()
+        .map(|(i, (a, b))| if a < b { i + 1 } else { 0 })
+        .sum())
+}
+
+pub fn part2(sequence_pairs: &[(Item, Item)]) -> anyhow::Result<usize> {
+    let mut packets = Vec::new();
+
+    for (a, b) in sequence_pairs {
+        packets.push(a);
+        packets.push(b);
+    }
+
+    let first = Item::List(vec![Item::List(vec![Item::Constant(2)])]);
+    let second = Item::List(vec![Item::List(vec![Item::Constant(6)])]);
+
+    packets.push(&first);
+    packets.push(&second);
+
+    packets.sort();
+
+    let first_idx = 1 + packets.iter().position(|&x| x == &first).unwrap();
+    let second_idx = 1 + packets.iter().position(|&x| x == &second).unwrap();
+
+    Ok(first_idx * second_idx)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    List(Vec<Item>),
+    Constant(i32),
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Item::*;
+
+        match (self, other) {
+            (Constant(a), Constant(b)) => a.cmp(b),
+            (List(a), List(b)) => a.cmp(b),
+            (Constant(a), List(b)) => vec![Constant(*a)].cmp(b),
+            (List(a), Constant(b)) => {
+                let vec1 = vec![Constant(*b)];
+                a.cmp(&vec1)
+            }
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn sequence_pairs(input: &str) -> IResult<&str, Vec<(Item, Item)>> {
+    separated_list1(many1(line_ending), sequence_pair)(input)
+}
+
+fn sequence_pair(input: &str) -> IResult<&str, (Item, Item)> {
+    let (input, first) = sequence(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, second) = sequence(input)?;
+    Ok((input, (Item::List(first), Item::List(second))))
+}
+
+fn sequence(input: &str) -> IResult<&str, Vec<Item>> {
+    delimited(tag("["), separated_list0(tag(","), item), tag("]"))(input)
+}
+
+fn item(input: &str) -> IResult<&str, Item> {
+    alt((
+        map(sequence, Item::List),
+        map(nom::character::complete::i32, Item::Constant),
+    ))(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<(Item, Item)>> {
+    Ok(parse_error::nom(input, sequence_pairs)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<(Item, Item)>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 13;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 140;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
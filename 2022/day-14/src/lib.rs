@@ -0,0 +1,396 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, space1};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, tuple};
+use nom::IResult;
+use visualize::{Frame, Recorder, Simulation};
+
+pub fn part1(line_segments: &[LineSegment]) -> anyhow::Result<usize> {
+    let mut grid = Cave::new(line_segments);
+
+    let mut count = 0;
+    while grid.drop_sand() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+pub fn part2(line_segments: &[LineSegment]) -> anyhow::Result<usize> {
+    let mut grid = Cave::new(line_segments);
+
+    let mut count = 0;
+    while grid.drop_sand2() {
+        count += 1;
+    }
+    Ok(count + 1)
+}
+
+/// Animates part 2, one frame per 50 units of sand.
+pub fn animate(input: &str) -> anyhow::Result<Recorder> {
+    let mut simulation = simulate(input)?;
+    Ok(Recorder::new()
+        .scale(3)
+        .every(50)
+        .delay(4)
+        .record(&mut *simulation))
+}
+
+/// Part 2 one unit of sand at a time, following the unit that came to rest last.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let line_segments = parse_error::nom(input, line_segments)?;
+    Ok(Box::new(Sand {
+        cave: Cave::new(&line_segments),
+        last: (500, 0),
+        blocked: false,
+    }))
+}
+
+struct Sand {
+    cave: Cave,
+    last: (i32, i32),
+    blocked: bool,
+}
+
+impl Simulation for Sand {
+    fn step(&mut self) -> bool {
+        if self.blocked {
+            return false;
+        }
+        let (x, y) = self.cave.landing2();
+        self.cave.insert(x, y, Material::Sand);
+        self.last = (x, y);
+        self.blocked = (x, y) == (500, 0);
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let pos = self.cave.pos(self.last.0, self.last.1);
+        self.cave
+            .frame()
+            .with_focus(pos.col as usize, pos.row as usize)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Material {
+    Air,
+    Sand,
+    Rock,
+    Source,
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use Material::*;
+
+        let c = match self {
+            Air => '.',
+            Sand => 'o',
+            Rock => '#',
+            Source => '+',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+impl Material {
+    fn is_solid(&self) -> bool {
+        !matches!(self, Material::Air)
+    }
+
+    fn colour(&self) -> visualize::Rgb {
+        match self {
+            Material::Air => [20, 20, 40],
+            Material::Sand => [230, 190, 90],
+            Material::Rock => [120, 110, 100],
+            Material::Source => [220, 60, 60],
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material::Air
+    }
+}
+
+/// The slice of the cave the sand can reach. Column 0 of `cells` is at `x_offset`.
+#[derive(Debug)]
+struct Cave {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    x_offset: i32,
+    cells: Grid<Material>,
+}
+
+impl Cave {
+    fn new(line_segments: &[LineSegment]) -> Self {
+        let min_points: Vec<_> = line_segments.iter().map(|p| p.min()).collect();
+        let max_points: Vec<_> = line_segments.iter().map(|p| p.max()).collect();
+
+        let x1 = min_points.iter().map(|(x, _)| x).min().copied().unwrap();
+        let y1 = min_points
+            .iter()
+            .map(|(_, y)| y)
+            .min()
+            .copied()
+            .unwrap()
+            .min(0);
+        let x2 = max_points.iter().map(|(x, _)| x).max().copied().unwrap();
+        let y2 = max_points.iter().map(|(_, y)| y).max().copied().unwrap();
+
+        // Sand piles up in a triangle below the source which is never wider than the floor is
+        // deep, so this is all the space that is ever needed.
+        let depth = y2 + 2;
+        let x_offset = x1.min(500 - depth) - 1;
+        let width = x2.max(500 + depth) + 2 - x_offset;
+        let mut cells = Grid::new(width as usize, depth as usize + 1, Material::Air);
+        let mut insert = |x: i32, y: i32, material| {
+            cells[Pos::new(y as isize, (x - x_offset) as isize)] = material;
+        };
+        for line_segment in line_segments.iter() {
+            for w in line_segment.points.windows(2) {
+                let (x1, y1) = w[0];
+                let (x2, y2) = w[1];
+                if x1 == x2 {
+                    for y in y1.min(y2)..=y1.max(y2) {
+                        insert(x1, y, Material::Rock);
+                    }
+                } else {
+                    for x in x1.min(x2)..=x1.max(x2) {
+                        insert(x, y1, Material::Rock);
+                    }
+                }
+            }
+        }
+        insert(500, 0, Material::Source);
+        Cave {
+            x1,
+            y1,
+            x2,
+            y2,
+            x_offset,
+            cells,
+        }
+    }
+
+    /// The whole slice of the cave, including the sand beyond the rocks in part 2.
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.cells, Material::colour)
+    }
+
+    fn pos(&self, x: i32, y: i32) -> Pos {
+        Pos::new(y as isize, (x - self.x_offset) as isize)
+    }
+
+    fn get(&self, x: i32, y: i32) -> Material {
+        self.cells.get(self.pos(x, y)).copied().unwrap_or_default()
+    }
+
+    fn insert(&mut self, x: i32, y: i32, material: Material) {
+        let pos = self.pos(x, y);
+        self.cells[pos] = material;
+    }
+
+    fn get2(&self, x: i32, y: i32) -> Material {
+        if y == self.y2 + 2 {
+            return Material::Rock;
+        }
+        self.get(x, y)
+    }
+
+    fn drop_sand(&mut self) -> bool {
+        let mut x = 500;
+        let mut y = 0;
+
+        while let Some((next_x, next_y)) = self.next_pos(x, y) {
+            x = next_x;
+            y = next_y;
+        }
+
+        self.insert(x, y, Material::Sand);
+        y < self.y2
+    }
+
+    fn drop_sand2(&mut self) -> bool {
+        let (x, y) = self.landing2();
+        self.insert(x, y, Material::Sand);
+
+        !(y == 0 && x == 500)
+    }
+
+    /// Where the next unit of sand comes to rest in part 2.
+    fn landing2(&self) -> (i32, i32) {
+        let mut x = 500;
+        let mut y = 0;
+
+        while let Some((next_x, next_y)) = self.next_pos2(x, y) {
+            x = next_x;
+            y = next_y;
+        }
+
+        (x, y)
+    }
+
+    fn next_pos(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        if y >= self.y2 {
+            return None;
+        }
+
+        if !self.get(x, y + 1).is_solid() {
+            return Some((x, y + 1));
+        }
+
+        if !self.get(x - 1, y + 1).is_solid() {
+            return Some((x - 1, y + 1));
+        }
+
+        if !self.get(x + 1, y + 1).is_solid() {
+            return Some((x + 1, y + 1));
+        }
+
+        None
+    }
+
+    fn next_pos2(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        if !self.get2(x, y + 1).is_solid() {
+            return Some((x, y + 1));
+        }
+
+        if !self.get2(x - 1, y + 1).is_solid() {
+            return Some((x - 1, y + 1));
+        }
+
+        if !self.get2(x + 1, y + 1).is_solid() {
+            return Some((x + 1, y + 1));
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let x1 = self.x1;
+        let y1 = self.y1;
+        let x2 = self.x2;
+        let y2 = self.y2;
+
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                write!(f, "{}", self.get(x, y))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct LineSegment {
+    points: Vec<(i32, i32)>,
+}
+
+impl LineSegment {
+    fn min(&self) -> (i32, i32) {
+        let x = self.points.iter().map(|p| p.0).min().unwrap();
+        let y = self.points.iter().map(|p| p.1).min().unwrap();
+        (x, y)
+    }
+    fn max(&self) -> (i32, i32) {
+        let x = self.points.iter().map(|p| p.0).max().unwrap();
+        let y = self.points.iter().map(|p| p.1).max().unwrap();
+        (x, y)
+    }
+}
+
+fn line_segments(input: &str) -> IResult<&str, Vec<LineSegment>> {
+    separated_list1(line_ending, line_segment)(input)
+}
+
+fn line_segment(input: &str) -> IResult<&str, LineSegment> {
+    map(
+        separated_list1(tuple((space1, tag("->"), space1)), point),
+        |points| LineSegment { points },
+    )(input)
+}
+
+fn point(input: &str) -> IResult<&str, (i32, i32)> {
+    use nom::character::complete::i32 as i32_parser;
+    separated_pair(i32_parser, tag(","), i32_parser)(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<LineSegment>> {
+    Ok(parse_error::nom(input, line_segments)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<LineSegment>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 24;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 93;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn animate_works() -> anyhow::Result<()> {
+        let recorder = animate(INPUT)?;
+        // the empty cave, the 50th of 93 units and the final state
+        assert_eq!(recorder.len(), 3);
+        let last = recorder.frames().last().unwrap();
+        let sand = Material::Sand.colour();
+        let count = (0..last.height())
+            .flat_map(|y| (0..last.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| last.get(x, y) == sand)
+            .count();
+        assert_eq!(count, 93);
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use interval_set::{Interval, IntervalSet};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, space0};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, separated_pair, tuple};
+use nom::IResult;
+
+pub fn part1(entries: &[Entry], ypos: i32) -> anyhow::Result<usize> {
+    let beacons: HashSet<_> = entries
+        .iter()
+        .map(|e| e.beacon_pos)
+        .filter(|p| p.1 == ypos)
+        .collect();
+    let beacon_count = beacons.len();
+    let sensors_and_distances: Vec<_> = entries
+        .iter()
+        .map(|e| {
+            let d = manhattan_distance(
+                e.sensor_pos.0,
+                e.sensor_pos.1,
+                e.beacon_pos.0,
+                e.beacon_pos.1,
+            );
+            (e.sensor_pos.0, e.sensor_pos.1, d)
+        })
+        .collect();
+    let covered = x_ranges(&sensors_and_distances, ypos);
+    Ok(covered.coverage() as usize - beacon_count)
+}
+
+fn manhattan_distance(x1: i32, y1: i32, x2: i32, y2: i32) -> i32 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+pub fn part2(entries: &[Entry], limit: i32) -> anyhow::Result<usize> {
+    let sensors_and_distances: Vec<_> = entries
+        .iter()
+        .map(|e| {
+            let d = manhattan_distance(
+                e.sensor_pos.0,
+                e.sensor_pos.1,
+                e.beacon_pos.0,
+                e.beacon_pos.1,
+            );
+            (e.sensor_pos.0, e.sensor_pos.1, d)
+        })
+        .collect();
+
+    let area = IntervalSet::from(Interval::inclusive(0, limit));
+    let p = (0..=limit)
+        .find_map(|y| {
+            let uncovered = area.difference(&x_ranges(&sensors_and_distances, y));
+            uncovered.min().map(|x| (x, y))
+        })
+        .ok_or_else(|| anyhow!("no position left for the distress beacon"))?;
+    let x = p.0 as usize;
+    let y = p.1 as usize;
+
+    Ok(x * 4_000_000 + y)
+}
+
+/// The x positions on `ypos` that are at least as close to a sensor as its beacon.
+fn x_ranges(sensors_and_distances: &[(i32, i32, i32)], ypos: i32) -> IntervalSet<i32> {
+    sensors_and_distances
+        .iter()
+        .filter_map(|(xs, ys, d)| {
+            let dy = (ys - ypos).abs();
+            let dx = d - dy;
+            if dx >= 0 {
+                Some(Interval::inclusive(xs - dx, xs + dx))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    sensor_pos: (i32, i32),
+    beacon_pos: (i32, i32),
+}
+
+fn lines(input: &str) -> IResult<&str, Vec<Entry>> {
+    separated_list1(line_ending, line)(input)
+}
+
+fn line(input: &str) -> IResult<&str, Entry> {
+    map(
+        separated_pair(
+            preceded(tag("Sensor at "), point),
+            tuple((tag(":"), space0)),
+            preceded(tag("closest beacon is at "), point),
+        ),
+        |(p1, p2)| Entry {
+            sensor_pos: p1,
+            beacon_pos: p2,
+        },
+    )(input)
+}
+
+fn point(input: &str) -> IResult<&str, (i32, i32)> {
+    use nom::character::complete::i32 as i32_parser;
+    separated_pair(
+        preceded(tag("x="), i32_parser),
+        tuple((tag(","), space0)),
+        preceded(tag("y="), i32_parser),
+    )(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Entry>> {
+    Ok(parse_error::nom(input, lines)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Entry>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input, 2_000_000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input, 4_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?, 10)?;
+        let expected = 26;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?, 20)?;
+        let expected = 56000011;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn x_ranges_works() {
+        // 3..=7 and 8..=10 touch and get merged, 13 is too far from the row
+        let sensors = [(5, 10, 2), (9, 11, 2), (13, 0, 9), (20, 10, 1)];
+        let expected = vec![Interval::inclusive(3, 10), Interval::inclusive(19, 21)];
+        let result: Vec<_> = x_ranges(&sensors, 10).iter().collect();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1 @@
+aoc_bench::main!(y2022_day_16::Puzzle);
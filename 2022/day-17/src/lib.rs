@@ -0,0 +1,363 @@
+use anyhow::bail;
+use aoc_core::Solution;
+use cycle::Detector;
+use grid::{Grid, Pos};
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use visualize::{Frame, Simulation};
+
+pub fn part1(commands: &[char]) -> i32 {
+    let rocks = create_rocks();
+    let mut command_idx = 0;
+    let mut grid = Chamber::new();
+
+    for round in 0..2022 {
+        let rock = &rocks[round % rocks.len()];
+
+        // println!("round {}", round + 1);
+        // println!("========================================\n");
+        // println!("{}\n", &grid);
+
+        drop_rock(&mut grid, rock, commands, &mut command_idx);
+    }
+
+    grid.height()
+}
+
+pub fn part2(commands: &[char]) -> i64 {
+    let rocks = create_rocks();
+    let mut command_idx = 0;
+    let mut grid = Chamber::new();
+    let mut detector = Detector::new();
+    let mut heights = vec![0i64];
+    let max_rounds = 1_000_000_000_000;
+
+    // the next rock, the next jet and the top of the chamber determine everything to come
+    let mut round = 0;
+    let cycle = loop {
+        if let Some(cycle) =
+            detector.push((round % rocks.len(), command_idx, grid.get_hash_string()))
+        {
+            break cycle;
+        }
+        drop_rock(
+            &mut grid,
+            &rocks[round % rocks.len()],
+            commands,
+            &mut command_idx,
+        );
+        heights.push(grid.height() as i64);
+        round += 1;
+    };
+
+    cycle.extrapolate(&heights, max_rounds)
+}
+
+/// Lets the rock fall until it comes to rest, pushed around by the jets starting with
+/// `commands[*command_idx]`.
+fn drop_rock(grid: &mut Chamber, rock: &Rock, commands: &[char], command_idx: &mut usize) {
+    let mut pos = (grid.height() + 3, 2);
+    while let Some(next) = fall(grid, rock, pos, commands, command_idx) {
+        pos = next;
+    }
+}
+
+/// Pushes the rock at `(row, col)` by the next jet and lets it fall by one unit. Returns its
+/// new position, or `None` once it has come to rest.
+fn fall(
+    grid: &mut Chamber,
+    rock: &Rock,
+    (row, mut col): (i32, i32),
+    commands: &[char],
+    command_idx: &mut usize,
+) -> Option<(i32, i32)> {
+    let command = commands[*command_idx];
+    *command_idx = (*command_idx + 1) % commands.len();
+    let next_col = if command == '>' { col + 1 } else { col - 1 };
+    if grid.can_draw(row, next_col, rock) {
+        col = next_col;
+    }
+
+    if grid.can_draw(row - 1, col, rock) {
+        Some((row - 1, col))
+    } else {
+        grid.draw(row, col, rock);
+        None
+    }
+}
+
+/// The 2022 rocks of part 1, one jet at a time.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let commands = parse(input)?;
+    Ok(Box::new(Tower {
+        chamber: Chamber::new(),
+        rocks: create_rocks(),
+        commands,
+        command_idx: 0,
+        round: 0,
+        falling: None,
+    }))
+}
+
+struct Tower {
+    chamber: Chamber,
+    rocks: Vec<Rock>,
+    commands: Vec<char>,
+    command_idx: usize,
+    round: usize,
+    /// The position of the rock of the current round, if it is still falling.
+    falling: Option<(i32, i32)>,
+}
+
+impl Simulation for Tower {
+    fn step(&mut self) -> bool {
+        let rock = &self.rocks[self.round % self.rocks.len()];
+        match self.falling {
+            None if self.round == 2022 => return false,
+            None => self.falling = Some((self.chamber.height() + 3, 2)),
+            Some(pos) => {
+                self.falling = fall(
+                    &mut self.chamber,
+                    rock,
+                    pos,
+                    &self.commands,
+                    &mut self.command_idx,
+                );
+                if self.falling.is_none() {
+                    self.round += 1;
+                }
+            }
+        }
+        true
+    }
+
+    /// The chamber with its walls and floor, up to where the next rock appears.
+    fn frame(&self) -> Frame {
+        let rock = &self.rocks[self.round % self.rocks.len()];
+        let top = self.chamber.height() + 3 + rock.rows.len() as i32;
+        let is_falling = |r: i32, c: i32| {
+            self.falling.is_some_and(|(row, col)| {
+                usize::try_from(r - row)
+                    .ok()
+                    .zip(usize::try_from(c - col).ok())
+                    .and_then(|(i, j)| rock.rows.get(i)?.get(j))
+                    .is_some_and(|&c| c == '#')
+            })
+        };
+        let frame = Frame::from_fn(
+            self.chamber.width() as usize + 2,
+            top as usize + 1,
+            |x, y| {
+                let (r, c) = (top - 1 - y as i32, x as i32 - 1);
+                if is_falling(r, c) {
+                    return [240, 160, 40];
+                }
+                match self.chamber.get_char(r, c) {
+                    '#' => [150, 110, 80],
+                    '.' => [20, 20, 30],
+                    _ => [110, 110, 120],
+                }
+            },
+        );
+        let (row, col) = self.falling.unwrap_or((self.chamber.height(), 0));
+        frame.with_focus(col as usize + 1, (top - 1 - row) as usize)
+    }
+}
+
+/// The chamber from the floor upwards, row 0 being the lowest row above the floor.
+#[derive(Debug)]
+struct Chamber {
+    cells: Grid<bool>,
+    height: i32,
+}
+
+impl Chamber {
+    fn new() -> Self {
+        Self {
+            cells: Grid::default(),
+            height: 0,
+        }
+    }
+
+    /// the top 20 lines of the grid (including air and border etc.)
+    /// used for cycle detection
+    fn get_hash_string(&self) -> String {
+        let h = self.height();
+        let w = self.width();
+        let mut result = String::new();
+
+        for r in (h - 20)..h {
+            for c in 0..w {
+                result.push(self.get_char(r, c));
+            }
+        }
+
+        result
+    }
+
+    fn get_char(&self, r: i32, c: i32) -> char {
+        if r < 0 && !(0..7).contains(&c) {
+            return '+';
+        }
+
+        if !(0..7).contains(&c) {
+            return '|';
+        }
+
+        if r < 0 {
+            return '-';
+        }
+
+        if self.cells.get(Pos::new(r as isize, c as isize)) == Some(&true) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn can_draw(&mut self, row: i32, col: i32, rock: &Rock) -> bool {
+        for (i, r) in rock.rows.iter().enumerate() {
+            for (j, &c) in r.iter().enumerate() {
+                if c != '.' && self.get_char(row + i as i32, col + j as i32) != '.' {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn draw(&mut self, row: i32, col: i32, rock: &Rock) {
+        let top = row + rock.rows.len() as i32;
+        while self.cells.height() < top as usize {
+            self.cells
+                .push_row([false; 7])
+                .expect("the chamber is 7 cells wide");
+        }
+        for (i, r) in rock.rows.iter().enumerate() {
+            for (j, &c) in r.iter().enumerate() {
+                if c != '.' {
+                    let pos = Pos::new((row + i as i32) as isize, (col + j as i32) as isize);
+                    self.cells[pos] = true;
+                }
+            }
+        }
+        self.height = self.height.max(top);
+    }
+
+    fn width(&self) -> i32 {
+        7
+    }
+
+    fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let height = self.height();
+        let width = self.width();
+
+        for r in (-1..height).rev() {
+            for c in -1..=width {
+                let c = self.get_char(r, c);
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Rock {
+    rows: Vec<Vec<char>>,
+}
+
+fn create_rocks() -> Vec<Rock> {
+    let defs = vec![
+        vec!["####"],
+        vec![".#.", "###", ".#."],
+        vec!["..#", "..#", "###"],
+        vec!["#", "#", "#", "#"],
+        vec!["##", "##"],
+    ];
+
+    defs.into_iter()
+        .map(|stone| {
+            stone
+                .into_iter()
+                .rev()
+                .map(|line| line.chars().collect())
+                .collect()
+        })
+        .map(|v| Rock { rows: v })
+        .collect()
+}
+
+/// The directions of the jets.
+pub fn parse(input: &str) -> anyhow::Result<Vec<char>> {
+    let commands: Vec<char> = input.chars().filter(|&c| c == '<' || c == '>').collect();
+    if commands.is_empty() {
+        bail!("no jets in the input");
+    }
+    Ok(commands)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn part1_works() {
+        let result = part1(&parse(INPUT).unwrap());
+        let expected = 3068;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_works() {
+        let result = part2(&parse(INPUT).unwrap());
+        let expected = 1514285714288i64;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn simulate_works() -> anyhow::Result<()> {
+        let mut tower = simulate(INPUT)?;
+        // the first rock appears and is pushed right, then falls three units on its own
+        tower.step();
+        assert_eq!(tower.frame().focus(), Some((3, 0)));
+        tower.step();
+        assert_eq!(tower.frame().focus(), Some((4, 1)));
+        while tower.step() {}
+        let frame = tower.frame();
+        // the tower on the floor, with room for the next rock, which is three rows high
+        assert_eq!(frame.height(), 1 + 3068 + 3 + 3);
+        Ok(())
+    }
+}
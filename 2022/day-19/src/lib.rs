@@ -1,4 +1,6 @@
+use aoc_core::Solution;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
@@ -307,3 +309,69 @@ fn cost(input: &str) -> IResult<&str, (Material, i32)> {
 
     Ok((input, (name, cost)))
 }
+
+pub fn part1(blueprints: &[BluePrint]) -> anyhow::Result<i32> {
+    let max_steps = 24;
+    Ok(blueprints
+        .iter()
+        .map(|bp| bp.id * max_geodes(bp, max_steps))
+        .sum())
+}
+
+pub fn part2(blueprints: &[BluePrint]) -> anyhow::Result<i32> {
+    let max_steps = 32;
+    Ok(blueprints
+        .iter()
+        .take(3)
+        .map(|bp| max_geodes(bp, max_steps))
+        .product())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<BluePrint>> {
+    Ok(parse_error::nom(input, blueprints)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 19;
+
+    type Input<'a> = Vec<BluePrint>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 33;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 12160;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
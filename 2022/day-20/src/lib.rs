@@ -0,0 +1,108 @@
+use aoc_core::Solution;
+use nom::character::complete;
+use nom::character::complete::line_ending;
+use nom::multi::separated_list1;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(numbers: &[i64]) -> anyhow::Result<i64> {
+    let mut pairs: Vec<_> = numbers.iter().copied().enumerate().collect();
+    let size = numbers.len();
+
+    mix(numbers, &mut pairs);
+
+    let zero_idx = pairs.iter().position(|(_, x)| *x == 0).unwrap();
+    let (_, x1) = pairs[(zero_idx + 1_000) % size];
+    let (_, x2) = pairs[(zero_idx + 2_000) % size];
+    let (_, x3) = pairs[(zero_idx + 3_000) % size];
+
+    Ok(x1 + x2 + x3)
+}
+
+fn mix(numbers: &[i64], pairs: &mut Vec<(usize, i64)>) {
+    for (idx, &value) in numbers.iter().enumerate() {
+        if value == 0 {
+            continue;
+        }
+        let current_idx = pairs.iter().position(|(i, _)| *i == idx).unwrap();
+        let tmp = pairs.remove(current_idx);
+        let new_idx = (current_idx as i64 + value).rem_euclid(pairs.len() as i64);
+        pairs.insert(new_idx as usize, tmp);
+    }
+}
+
+pub fn part2(arr: &[i64]) -> anyhow::Result<i64> {
+    let num_rounds = 10;
+    let key = 811_589_153;
+    let size = arr.len();
+    let numbers: Vec<_> = arr.iter().map(|v| v * key).collect();
+    let mut pairs: Vec<_> = numbers.iter().copied().enumerate().collect();
+
+    for _ in 0..num_rounds {
+        mix(&numbers, &mut pairs);
+    }
+
+    let zero_idx = pairs.iter().position(|(_, x)| *x == 0).unwrap();
+    let (_, x1) = pairs[(zero_idx + 1_000) % size];
+    let (_, x2) = pairs[(zero_idx + 2_000) % size];
+    let (_, x3) = pairs[(zero_idx + 3_000) % size];
+    Ok(x1 + x2 + x3)
+}
+
+fn numbers(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list1(line_ending, complete::i64)(input)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<i64>> {
+    Ok(parse_error::nom(input, numbers)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1
+2
+-3
+3
+-2
+0
+4";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 3;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 1623178306i64;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
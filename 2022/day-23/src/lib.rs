@@ -0,0 +1,350 @@
+use aoc_core::Solution;
+use itertools::Itertools;
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending};
+use nom::multi::{many1, separated_list1};
+use nom::IResult;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::RangeInclusive;
+use visualize::{Frame, Recorder, Simulation};
+
+pub fn part1(board: &Board) -> anyhow::Result<usize> {
+    let mut board = board.clone();
+
+    for _step in 0..10 {
+        board.step();
+    }
+
+    Ok(board.num_empty_fields())
+}
+
+pub fn part2(board: &Board) -> anyhow::Result<i64> {
+    let mut board = board.clone();
+    let mut result = 0;
+
+    loop {
+        result += 1;
+        if board.step() == 0 {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Animates part 2.
+pub fn animate(input: &str) -> anyhow::Result<Recorder> {
+    let mut simulation = simulate(input)?;
+    Ok(Recorder::new().scale(4).record(&mut *simulation))
+}
+
+/// Part 2 one round at a time. All rounds are drawn inside the area the elves spread over
+/// until they stop, which takes running the rounds once in advance.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let board = parse_error::nom(input, board)?;
+
+    let mut end = board.clone();
+    let (mut xs, mut ys) = (end.xrange(), end.yrange());
+    while end.step() > 0 {
+        xs = *xs.start().min(&end.xmin())..=*xs.end().max(&end.xmax());
+        ys = *ys.start().min(&end.ymin())..=*ys.end().max(&end.ymax());
+    }
+
+    Ok(Box::new(Spreading { board, xs, ys }))
+}
+
+struct Spreading {
+    board: Board,
+    xs: RangeInclusive<i64>,
+    ys: RangeInclusive<i64>,
+}
+
+impl Simulation for Spreading {
+    fn step(&mut self) -> bool {
+        self.board.step() > 0
+    }
+
+    fn frame(&self) -> Frame {
+        self.board.frame(&self.xs, &self.ys)
+    }
+}
+
+type Point = (i64, i64);
+#[derive(Clone)]
+pub struct Board {
+    elves: HashSet<Point>,
+    directions: VecDeque<MoveDirection>,
+}
+
+const DIRECTIONS: [(i64, i64); 8] = [
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+];
+
+#[derive(Debug, Copy, Clone)]
+enum MoveDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Board {
+    fn step(&mut self) -> usize {
+        let initial: HashSet<Point> = self
+            .elves
+            .iter()
+            .filter(|(x, y)| {
+                !DIRECTIONS
+                    .iter()
+                    .all(|(dx, dy)| !self.elves.contains(&(x + dx, y + dy)))
+            })
+            .copied()
+            .collect();
+
+        let mut moves = HashMap::new();
+
+        for direction in self.directions.iter() {
+            match direction {
+                MoveDirection::North => {
+                    for &(x, y) in initial.iter() {
+                        if !self.elves.contains(&(x - 1, y + 1))
+                            && !self.elves.contains(&(x, y + 1))
+                            && !self.elves.contains(&(x + 1, y + 1))
+                        {
+                            moves.entry((x, y)).or_insert((x, y + 1));
+                        }
+                    }
+                }
+                MoveDirection::South => {
+                    for &(x, y) in initial.iter() {
+                        if !self.elves.contains(&(x - 1, y - 1))
+                            && !self.elves.contains(&(x, y - 1))
+                            && !self.elves.contains(&(x + 1, y - 1))
+                        {
+                            moves.entry((x, y)).or_insert((x, y - 1));
+                        }
+                    }
+                }
+                MoveDirection::West => {
+                    for &(x, y) in initial.iter() {
+                        if !self.elves.contains(&(x - 1, y - 1))
+                            && !self.elves.contains(&(x - 1, y))
+                            && !self.elves.contains(&(x - 1, y + 1))
+                        {
+                            moves.entry((x, y)).or_insert((x - 1, y));
+                        }
+                    }
+                }
+                MoveDirection::East => {
+                    for &(x, y) in initial.iter() {
+                        if !self.elves.contains(&(x + 1, y - 1))
+                            && !self.elves.contains(&(x + 1, y))
+                            && !self.elves.contains(&(x + 1, y + 1))
+                        {
+                            moves.entry((x, y)).or_insert((x + 1, y));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.directions.rotate_left(1);
+
+        let mut hist = HashMap::new();
+        for p in moves.values() {
+            let entry = hist.entry(*p).or_insert(0);
+            *entry += 1;
+        }
+
+        let mut n = 0;
+        for (p1, p2) in moves.iter() {
+            let count = hist.get(p2).copied().unwrap();
+            if count == 1 {
+                n += 1;
+                self.elves.remove(p1);
+                self.elves.insert(*p2);
+            }
+        }
+
+        n
+    }
+
+    /// The elves in the given area, with north at the top.
+    fn frame(&self, xs: &RangeInclusive<i64>, ys: &RangeInclusive<i64>) -> Frame {
+        let width = (xs.end() - xs.start() + 1) as usize;
+        let height = (ys.end() - ys.start() + 1) as usize;
+        Frame::from_fn(width, height, |x, y| {
+            let p = (xs.start() + x as i64, ys.end() - y as i64);
+            if self.elves.contains(&p) {
+                [46, 139, 87]
+            } else {
+                [240, 240, 240]
+            }
+        })
+    }
+
+    fn num_empty_fields(&self) -> usize {
+        self.xrange()
+            .cartesian_product(self.yrange())
+            .filter(|p| !self.elves.contains(p))
+            .count()
+    }
+
+    fn xrange(&self) -> RangeInclusive<i64> {
+        let xmin = self.xmin();
+        let xmax = self.xmax();
+
+        xmin..=xmax
+    }
+
+    fn yrange(&self) -> RangeInclusive<i64> {
+        let ymin = self.ymin();
+        let ymax = self.ymax();
+
+        ymin..=ymax
+    }
+
+    fn xmin(&self) -> i64 {
+        self.elves.iter().map(|(x, _)| x).min().copied().unwrap()
+    }
+
+    fn xmax(&self) -> i64 {
+        self.elves.iter().map(|(x, _)| x).max().copied().unwrap()
+    }
+
+    fn ymin(&self) -> i64 {
+        self.elves.iter().map(|(_, y)| y).min().copied().unwrap()
+    }
+
+    fn ymax(&self) -> i64 {
+        self.elves.iter().map(|(_, y)| y).max().copied().unwrap()
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let xmin = self.xmin() - 2;
+        let xmax = self.xmax() + 2;
+        let yr = (self.ymin() - 2)..=(self.ymax() + 2);
+
+        for y in yr.rev() {
+            for x in xmin..=xmax {
+                write!(
+                    f,
+                    "{}",
+                    if self.elves.contains(&(x, y)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn board(input: &str) -> IResult<&str, Board> {
+    let (input, lines) = separated_list1(line_ending, many1(alt((char('.'), char('#')))))(input)?;
+    let mut elves = HashSet::new();
+
+    for (y, line) in lines.iter().rev().enumerate() {
+        for (x, &c) in line.iter().enumerate() {
+            if c == '#' {
+                elves.insert((x as i64, y as i64));
+            }
+        }
+    }
+
+    Ok((
+        input,
+        Board {
+            elves,
+            directions: VecDeque::from([
+                MoveDirection::North,
+                MoveDirection::South,
+                MoveDirection::West,
+                MoveDirection::East,
+            ]),
+        },
+    ))
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Board> {
+    Ok(parse_error::nom(input, board)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Board;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 110;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 20;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn animate_works() -> anyhow::Result<()> {
+        let recorder = animate(INPUT)?;
+        assert_eq!(recorder.len(), 20);
+        let sizes: HashSet<_> = recorder
+            .frames()
+            .iter()
+            .map(|f| (f.width(), f.height()))
+            .collect();
+        assert_eq!(sizes.len(), 1);
+        Ok(())
+    }
+}
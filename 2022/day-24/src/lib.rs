@@ -1,9 +1,13 @@
+use aoc_core::Solution;
+use std::collections::HashMap;
 use std::fmt;
+use std::fmt::Display;
 use std::fmt::Formatter;
 
 use nom::character::complete::{line_ending, one_of};
 use nom::multi::{many1, separated_list1};
 use nom::IResult;
+use search::bfs;
 
 #[derive(Debug)]
 pub struct Board {
@@ -203,3 +207,112 @@ pub fn board(input: &str) -> IResult<&str, Board> {
         },
     ))
 }
+
+const DIRECTIONS: [(i32, i32); 5] = [(-1, 0), (1, 0), (0, -1), (0, 1), (0, 0)];
+
+pub fn part1(board: &Board) -> anyhow::Result<i32> {
+    let start_pos = Position::new(0, 1);
+    let end_pos = Position::new(board.height - 1, board.width - 2);
+
+    Ok(find_path(board, start_pos, end_pos).unwrap())
+}
+
+pub fn part2(board: &Board) -> anyhow::Result<i32> {
+    let start_pos = Position::new(0, 1);
+    let end_pos = Position::new(board.height - 1, board.width - 2);
+
+    let first = find_path(board, start_pos, end_pos).unwrap();
+    let second_board = board.at_minute(first);
+    let second = find_path(&second_board, end_pos, start_pos).unwrap();
+    let third_board = board.at_minute(first + second);
+    let third = find_path(&third_board, start_pos, end_pos).unwrap();
+
+    Ok(first + second + third)
+}
+
+fn find_path(board: &Board, start_pos: Position, end_pos: Position) -> Option<i32> {
+    let board_cache: HashMap<i32, Board> = (0..1000)
+        .map(|minute| (minute, board.at_minute(minute)))
+        .collect();
+
+    let path = bfs(
+        &(0, start_pos),
+        |&(minute, position)| {
+            let next_minute = minute + 1;
+            let next_board = board_cache.get(&next_minute).unwrap();
+            DIRECTIONS
+                .iter()
+                .map(move |&d| position.plus(d))
+                .filter(|next_position| {
+                    (0..next_board.width).contains(&next_position.col)
+                        && (0..next_board.height).contains(&next_position.row)
+                        && next_board
+                            .get(next_position.row, next_position.col)
+                            .is_none()
+                })
+                .map(move |next_position| (next_minute, next_position))
+        },
+        |&(_, position)| position == end_pos,
+    )?;
+    path.last().map(|&(minute, _)| minute)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Board> {
+    Ok(parse_error::nom(input, board)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 24;
+
+    type Input<'a> = Board;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+    //     const INPUT: &str = "#.#####
+    // #.....#
+    // #>....#
+    // #.....#
+    // #...v.#
+    // #.....#
+    // #####.#";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 18;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 54;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_core::Solution;
+use std::collections::hash_set::HashSet;
+use std::fmt::Display;
+
+pub fn part1(content: &str) -> usize {
+    let lines = content.lines();
+    let mut sum = 0;
+
+    for line in lines {
+        let (s1, s2) = compartments(line);
+        let m1: HashSet<_> = s1.chars().collect();
+        let m2: HashSet<_> = s2.chars().collect();
+        let dups: String = m1.intersection(&m2).copied().collect();
+        let priority = dups.chars().next().map(priority).unwrap_or(0);
+        sum += priority;
+    }
+
+    sum
+}
+
+pub fn part2(content: &str) -> usize {
+    let lines: Vec<_> = content.lines().collect();
+    let chunks = lines.chunks_exact(3);
+    let mut sum = 0;
+
+    for chunk in chunks {
+        let m1: HashSet<char> = chunk[0].chars().collect();
+        let m2: HashSet<char> = chunk[1].chars().collect();
+        let m3: HashSet<char> = chunk[2].chars().collect();
+        let dups: String = m1
+            .intersection(&m2)
+            .copied()
+            .collect::<HashSet<_>>()
+            .intersection(&m3)
+            .collect();
+        let priority = dups.chars().next().map(priority).unwrap_or(0);
+        sum += priority;
+    }
+
+    sum
+}
+
+fn priority(c: char) -> usize {
+    match c {
+        'a'..='z' => c as usize - 'a' as usize + 1,
+        'A'..='Z' => c as usize - 'A' as usize + 27,
+        _ => 0,
+    }
+}
+
+fn compartments(s: &str) -> (&str, &str) {
+    let l = s.len();
+    (&s[0..(l / 2)], &s[(l / 2)..])
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part2(input))
+    }
+}
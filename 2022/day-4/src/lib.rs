@@ -0,0 +1,114 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+
+use anyhow::Context;
+use interval_set::Interval;
+
+pub fn part1(range_lists: &[Vec<Interval<i32>>]) -> anyhow::Result<i32> {
+    let mut count = 0;
+    for range_list in range_lists.iter() {
+        let (a, b) = (range_list[0], range_list[1]);
+        if a.covers(&b) || b.covers(&a) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn part2(range_lists: &[Vec<Interval<i32>>]) -> anyhow::Result<i32> {
+    let mut count = 0;
+    for range_list in range_lists.iter() {
+        if range_list[0].overlaps(&range_list[1]) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<Interval<i32>>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_no, line)| ranges_from_line(line).context(line_no))
+        .collect()
+}
+
+fn ranges_from_line(line: &str) -> Result<Vec<Interval<i32>>, anyhow::Error> {
+    let range_parts = line.split(',');
+    let ranges = range_parts.map(|range_part| {
+        let mut num_parts = range_part.split('-');
+        let start = num_parts
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .parse::<i32>()
+            .context("start")?;
+        let end = num_parts
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .parse::<i32>()
+            .context("end")?;
+        Ok(Interval::inclusive(start, end))
+    });
+    ranges.collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Vec<Interval<i32>>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges_from_line() {
+        let input = "2-4,6-8\n";
+        let result = ranges_from_line(input);
+        assert_eq!(result.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = r#"2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8"#;
+
+        let result = part1(&parse(input).unwrap());
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = r#"2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8"#;
+
+        let result = part2(&parse(input).unwrap());
+        assert_eq!(result.unwrap(), 4);
+    }
+}
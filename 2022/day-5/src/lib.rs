@@ -0,0 +1,209 @@
+use aoc_core::Solution;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, satisfy, space0, space1},
+    multi::separated_list1,
+    sequence::delimited,
+    IResult,
+};
+use std::fmt::Display;
+
+pub fn part1(document: &Document) -> anyhow::Result<String> {
+    let mut document = document.clone();
+    let moves = document.moves.clone();
+    for m in moves.iter() {
+        document.perform_move(m);
+    }
+
+    let result = document
+        .stacks
+        .iter()
+        .map(|s| s.last().unwrap())
+        .collect::<String>();
+    Ok(result)
+}
+
+pub fn part2(document: &Document) -> anyhow::Result<String> {
+    let mut document = document.clone();
+    let moves = document.moves.clone();
+    for m in moves.iter() {
+        document.perform_move_9001(m);
+    }
+
+    let result = document
+        .stacks
+        .iter()
+        .map(|s| s.last().unwrap())
+        .collect::<String>();
+    Ok(result)
+}
+
+fn crate_item(input: &str) -> IResult<&str, char> {
+    use nom::character::complete::char as char_parser;
+    let first = delimited(tag("["), satisfy(|c| c.is_alphabetic()), tag("]"));
+    let second = delimited(char_parser(' '), char_parser(' '), char_parser(' '));
+    let (input, c) = alt((first, second))(input)?;
+    Ok((input, c))
+}
+
+fn crate_item_line(input: &str) -> IResult<&str, Vec<char>> {
+    use nom::character::complete::char as char_parser;
+    let (input, result) = separated_list1(char_parser(' '), crate_item)(input)?;
+    Ok((input, result))
+}
+
+fn crate_item_lines(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, result) = separated_list1(line_ending, crate_item_line)(input)?;
+    Ok((input, result))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Move {
+    quantity: u32,
+    from: u32,
+    to: u32,
+}
+
+fn move_command(input: &str) -> IResult<&str, Move> {
+    use nom::character::complete::u32 as u32_parser;
+    let (input, _move) = tag("move ")(input)?;
+    let (input, quantity) = u32_parser(input)?;
+    let (input, _from) = tag(" from ")(input)?;
+    let (input, from) = u32_parser(input)?;
+    let (input, _to) = tag(" to ")(input)?;
+    let (input, to) = u32_parser(input)?;
+    let result = Move { quantity, from, to };
+    Ok((input, result))
+}
+
+#[derive(Debug, Clone)]
+pub struct Document {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+fn document(input: &str) -> IResult<&str, Document> {
+    let (input, lines) = crate_item_lines(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _columns) = delimited(space0, separated_list1(space1, digit1), space0)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, moves) = separated_list1(line_ending, move_command)(input)?;
+
+    Ok((
+        input,
+        Document {
+            stacks: build_stacks(&lines),
+            moves,
+        },
+    ))
+}
+
+fn build_stacks(lines: &[Vec<char>]) -> Vec<Vec<char>> {
+    let lines: Vec<Vec<char>> = lines.iter().cloned().rev().collect();
+
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result: Vec<Vec<char>> = vec![Vec::new(); lines[0].len()];
+
+    for line in lines {
+        for (i, &c) in line.iter().enumerate() {
+            if !c.is_whitespace() {
+                result[i].push(c);
+            }
+        }
+    }
+
+    result
+}
+
+impl Document {
+    fn perform_move(&mut self, move_command: &Move) {
+        let from = (move_command.from - 1) as usize;
+        let to = (move_command.to - 1) as usize;
+        let mut items = Vec::new();
+
+        for _ in 0..move_command.quantity {
+            items.push(self.stacks[from].pop().unwrap());
+        }
+
+        for c in items.into_iter() {
+            self.stacks[to].push(c);
+        }
+    }
+
+    fn perform_move_9001(&mut self, move_command: &Move) {
+        let from = (move_command.from - 1) as usize;
+        let to = (move_command.to - 1) as usize;
+        let mut items = Vec::new();
+
+        for _ in 0..move_command.quantity {
+            items.push(self.stacks[from].pop().unwrap());
+        }
+
+        for c in items.into_iter().rev() {
+            self.stacks[to].push(c);
+        }
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Document> {
+    Ok(parse_error::nom(input, document)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Document;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = test_input();
+        let result = part1(&parse(input).unwrap()).unwrap();
+        let expected = "CMZ".to_owned();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = test_input();
+        let result = part2(&parse(input).unwrap()).unwrap();
+        let expected = "MCD".to_owned();
+        assert_eq!(result, expected);
+    }
+
+    fn test_input() -> &'static str {
+        "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+pub fn part1(input: &str) -> Option<usize> {
+    let a: Vec<char> = input.chars().collect();
+    let result = a.windows(4).enumerate().find(|(_, w)| {
+        w[0] != w[1] && w[0] != w[2] && w[0] != w[3] && w[1] != w[2] && w[1] != w[3] && w[2] != w[3]
+    });
+
+    result.map(|(idx, _)| idx + 4)
+}
+
+pub fn part2(input: &str) -> Option<usize> {
+    let a: Vec<char> = input.chars().collect();
+    let result = a.windows(14).enumerate().find(|(_, w)| is_all_distinct(w));
+
+    result.map(|(idx, _)| idx + 14)
+}
+
+fn is_all_distinct(input: &[char]) -> bool {
+    let mut m = HashSet::with_capacity(1024);
+
+    for c in input.iter() {
+        if m.contains(c) {
+            return false;
+        }
+        m.insert(*c);
+    }
+
+    true
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input).ok_or_else(|| anyhow!("no marker found"))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input).ok_or_else(|| anyhow!("no marker found"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result = part1(&input).unwrap();
+        let expected = 7;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn part2_works_2() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result = part2(&input).unwrap();
+        let expected = 19;
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,237 @@
+use aoc_core::Solution;
+use nom::character::complete::{line_ending, one_of, space1};
+use nom::multi::separated_list1;
+use nom::IResult;
+use std::collections::HashSet;
+use std::fmt::Display;
+use visualize::{Frame, Simulation};
+
+pub fn part1(directions: &[Direction]) -> anyhow::Result<usize> {
+    let mut head = (0, 0);
+    let mut tail = (0, 0);
+    let mut positions = HashSet::new();
+
+    positions.insert(tail);
+
+    for &d in directions.iter() {
+        head = next_head(&head, d);
+        tail = next_tail(&head, &tail);
+        positions.insert(tail);
+    }
+    Ok(positions.len())
+}
+
+pub fn part2(directions: &[Direction]) -> anyhow::Result<usize> {
+    let mut tails = vec![(0, 0); 10];
+    let mut positions = HashSet::new();
+
+    positions.insert((0, 0));
+
+    for &d in directions.iter() {
+        pull(&mut tails, d);
+        positions.insert(tails[9]);
+    }
+    Ok(positions.len())
+}
+
+/// The rope of part 2 one move of the head at a time, following the head.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let directions = parse_error::nom(input, directions)?;
+
+    // a first run finds the area the rope moves in
+    let mut knots = [(0, 0); 10];
+    let (mut min, mut max) = ((0, 0), (0, 0));
+    for &d in directions.iter() {
+        pull(&mut knots, d);
+        for &(x, y) in knots.iter() {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+    }
+
+    Ok(Box::new(Rope {
+        knots: [(0, 0); 10],
+        visited: HashSet::from([(0, 0)]),
+        directions,
+        next: 0,
+        min,
+        max,
+    }))
+}
+
+struct Rope {
+    knots: [(i32, i32); 10],
+    /// The positions of the tail so far.
+    visited: HashSet<(i32, i32)>,
+    directions: Vec<Direction>,
+    next: usize,
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl Rope {
+    /// The column and row of a position, with the y axis pointing up.
+    fn pixel(&self, (x, y): (i32, i32)) -> (usize, usize) {
+        ((x - self.min.0) as usize, (self.max.1 - y) as usize)
+    }
+}
+
+impl Simulation for Rope {
+    fn step(&mut self) -> bool {
+        let Some(&d) = self.directions.get(self.next) else {
+            return false;
+        };
+        pull(&mut self.knots, d);
+        self.visited.insert(self.knots[9]);
+        self.next += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let width = (self.max.0 - self.min.0 + 1) as usize;
+        let height = (self.max.1 - self.min.1 + 1) as usize;
+        let mut frame = Frame::new(width, height, [20, 20, 30]);
+        for &p in self.visited.iter() {
+            let (x, y) = self.pixel(p);
+            frame.set(x, y, [70, 90, 140]);
+        }
+        // the head last, so that it is on top of knots at the same position
+        for (i, &p) in self.knots.iter().enumerate().rev() {
+            let (x, y) = self.pixel(p);
+            let shade = 255 - 15 * i as u8;
+            frame.set(x, y, [shade, shade / 2, 40]);
+        }
+        let (x, y) = self.pixel(self.knots[0]);
+        frame.with_focus(x, y)
+    }
+}
+
+/// Moves the head of the rope in `direction` and lets the other knots follow.
+fn pull(knots: &mut [(i32, i32)], direction: Direction) {
+    knots[0] = next_head(&knots[0], direction);
+    for i in 1..knots.len() {
+        knots[i] = next_tail(&knots[i - 1], &knots[i]);
+    }
+}
+
+fn next_head(head: &(i32, i32), direction: Direction) -> (i32, i32) {
+    let (x, y) = *head;
+    match direction {
+        Direction::Up => (x, y + 1),
+        Direction::Down => (x, y - 1),
+        Direction::Left => (x - 1, y),
+        Direction::Right => (x + 1, y),
+    }
+}
+
+fn next_tail(head: &(i32, i32), tail: &(i32, i32)) -> (i32, i32) {
+    if is_connected(head, tail) {
+        return *tail;
+    }
+
+    let dx = (head.0 - tail.0).signum();
+    let dy = (head.1 - tail.1).signum();
+
+    (tail.0 + dx, tail.1 + dy)
+}
+
+fn is_connected(head: &(i32, i32), tail: &(i32, i32)) -> bool {
+    (head.0 - 1) <= tail.0
+        && (head.0 + 1) >= tail.0
+        && (head.1 - 1) <= tail.1
+        && (head.1 + 1) >= tail.1
+}
+
+fn directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, directions): (&str, Vec<Vec<Direction>>) =
+        separated_list1(line_ending, moves)(input)?;
+    Ok((input, directions.into_iter().flatten().collect()))
+}
+
+fn moves(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, c) = one_of("LRUD")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, distance) = nom::character::complete::u32(input)?;
+
+    let direction = match c {
+        'U' => Direction::Up,
+        'D' => Direction::Down,
+        'L' => Direction::Left,
+        _ => Direction::Right,
+    };
+
+    let result: Vec<Direction> = vec![direction; distance as usize];
+    Ok((input, result))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Direction>> {
+    Ok(parse_error::nom(input, directions)?)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 13;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn simulate_works() -> anyhow::Result<()> {
+        let mut rope = simulate(INPUT)?;
+        let frame = rope.frame();
+        // the rope spans x 0..=5 and y 0..=4 and starts at the bottom left
+        assert_eq!((frame.width(), frame.height()), (6, 5));
+        assert_eq!(frame.focus(), Some((0, 4)));
+        let mut steps = 0;
+        while rope.step() {
+            steps += 1;
+        }
+        assert_eq!(steps, 24);
+        // the head ends at (2, 2)
+        assert_eq!(rope.frame().focus(), Some((2, 2)));
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+use itertools::Itertools;
+
+pub fn part1(image: &Image) -> anyhow::Result<usize> {
+    let expansion_factor = 1;
+    let result = image.sum_of_distances(expansion_factor);
+    Ok(result)
+}
+
+pub fn part2(image: &Image) -> anyhow::Result<usize> {
+    let expansion_factor = 1_000_000 - 1; // each empty row/col is **replaced** by 1_000_000 rows / cols. That's a factor of 999_999
+    let result = image.sum_of_distances(expansion_factor);
+    Ok(result)
+}
+
+/// The galaxies of the image together with the rows and columns that contain none.
+#[derive(Debug)]
+pub struct Image {
+    galaxies: Vec<Pos>,
+    empty_rows: Vec<isize>,
+    empty_cols: Vec<isize>,
+}
+
+impl Image {
+    fn sum_of_distances(&self, expansion_factor: usize) -> usize {
+        let expanded: Vec<Pos> = self
+            .galaxies
+            .iter()
+            .map(|&pos| self.expand(pos, expansion_factor))
+            .collect();
+        expanded
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.manhattan(*b))
+            .sum()
+    }
+
+    /// Translates `pos` by the number of empty rows and columns before it.
+    fn expand(&self, pos: Pos, expansion_factor: usize) -> Pos {
+        let rows = self.empty_rows.iter().filter(|&&r| r < pos.row).count();
+        let cols = self.empty_cols.iter().filter(|&&c| c < pos.col).count();
+        Pos::new(
+            pos.row + (rows * expansion_factor) as isize,
+            pos.col + (cols * expansion_factor) as isize,
+        )
+    }
+}
+
+impl From<&Grid<char>> for Image {
+    fn from(grid: &Grid<char>) -> Self {
+        let empty_rows = (0..grid.height())
+            .filter(|&row| grid.row(row).iter().all(|&c| c == '.'))
+            .map(|row| row as isize)
+            .collect();
+        let empty_cols = (0..grid.width())
+            .filter(|&col| grid.column(col).all(|&c| c == '.'))
+            .map(|col| col as isize)
+            .collect();
+        let galaxies = grid
+            .iter()
+            .filter(|&(_, &c)| c == '#')
+            .map(|(pos, _)| pos)
+            .collect();
+        Self {
+            galaxies,
+            empty_rows,
+            empty_cols,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Image> {
+    let grid: Grid<char> = input.parse()?;
+    Ok(Image::from(&grid))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Image;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 374;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sum_of_distances_with_an_expansion() -> anyhow::Result<()> {
+        let image = parse(INPUT)?;
+        let result = image.sum_of_distances(10 - 1);
+        let expected = 1030;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+
+use grid::{Grid, Pos};
+use nom::branch::alt;
+use nom::character::complete::{char, line_ending};
+use nom::combinator::map_res;
+use nom::multi::{many1, separated_list0, separated_list1};
+use nom::IResult;
+use nom::Parser;
+
+pub fn part1(grids: &[Pattern]) -> anyhow::Result<usize> {
+    let folds: Vec<_> = grids.iter().map(|g| g.detect_fold()).collect();
+    let result = folds
+        .iter()
+        .map(|fold| match fold {
+            Fold::Vertical(n) => *n,
+            Fold::Horizontal(n) => 100 * *n,
+        })
+        .sum();
+    Ok(result)
+}
+
+pub fn part2(grids: &[Pattern]) -> anyhow::Result<usize> {
+    let folds: Vec<_> = grids.iter().map(|g| g.detect_smudge_fold()).collect();
+    let result = folds
+        .iter()
+        .map(|fold| match fold {
+            Fold::Vertical(n) => *n,
+            Fold::Horizontal(n) => 100 * *n,
+        })
+        .sum();
+    Ok(result)
+}
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    cells: Grid<CellType>,
+}
+
+impl Pattern {
+    fn detect_fold(&self) -> Fold {
+        self.rotated()
+            .find_horizontal_fold()
+            .map(Fold::Vertical)
+            .or_else(|| self.find_horizontal_fold().map(Fold::Horizontal))
+            .unwrap()
+    }
+
+    fn detect_fold_opt(&self) -> Vec<Fold> {
+        self.rotated()
+            .find_horizontal_folds()
+            .into_iter()
+            .map(Fold::Vertical)
+            .chain(
+                self.find_horizontal_folds()
+                    .into_iter()
+                    .map(Fold::Horizontal),
+            )
+            .collect()
+    }
+
+    fn detect_smudge_fold(&self) -> Fold {
+        let mut g = self.clone();
+        let old = self.detect_fold();
+        for row in 0..self.cells.height() {
+            for col in 0..self.cells.width() {
+                g.flip(row, col);
+                let maybes: Vec<Fold> = g
+                    .detect_fold_opt()
+                    .into_iter()
+                    .filter(|n| *n != old)
+                    .collect();
+                g.flip(row, col);
+                if !maybes.is_empty() {
+                    return maybes[0];
+                }
+            }
+        }
+        unreachable!();
+    }
+
+    fn flip(&mut self, row: usize, col: usize) {
+        let cell = &mut self.cells[Pos::from((row, col))];
+        *cell = cell.flipped();
+    }
+
+    fn find_horizontal_fold(&self) -> Option<usize> {
+        (0..self.cells.height() - 1).find_map(|row| self.find_horizontal_fold_at_row(row))
+    }
+
+    fn find_horizontal_folds(&self) -> Vec<usize> {
+        (0..self.cells.height() - 1)
+            .filter_map(|row| self.find_horizontal_fold_at_row(row))
+            .collect()
+    }
+
+    fn find_horizontal_fold_at_row(&self, row: usize) -> Option<usize> {
+        (0..=row)
+            .rev()
+            .zip((row + 1)..self.cells.height())
+            .all(|(i, j)| self.cells.row(i) == self.cells.row(j))
+            .then_some(row + 1)
+    }
+
+    fn rotated(&self) -> Pattern {
+        Pattern {
+            cells: self.cells.transposed(),
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum CellType {
+    Ash,
+    Rock,
+}
+
+impl CellType {
+    fn flipped(&self) -> Self {
+        match self {
+            CellType::Ash => CellType::Rock,
+            CellType::Rock => CellType::Ash,
+        }
+    }
+}
+
+impl fmt::Display for CellType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            CellType::Ash => '.',
+            CellType::Rock => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Fold {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Pattern>> {
+    let grids = parse_error::nom(input, parse_grids)?;
+    Ok(grids)
+}
+
+fn parse_grids(input: &str) -> IResult<&str, Vec<Pattern>> {
+    separated_list0(many1(line_ending), parse_grid)(input)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Pattern> {
+    let row = many1(parse_cell_type);
+    let (input, cells) = map_res(separated_list1(line_ending, row), Grid::from_rows)(input)?;
+    Ok((input, Pattern { cells }))
+}
+
+fn parse_cell_type(input: &str) -> IResult<&str, CellType> {
+    let ash = char('.').map(|_| CellType::Ash);
+    let rock = char('#').map(|_| CellType::Rock);
+    alt((ash, rock))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+
+    static INPUT: &str = r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#;
+
+    #[test]
+    fn test1() -> anyhow::Result<()> {
+        let grids = parse(INPUT)?;
+        println!("{}", grids[0].rotated());
+        let result = grids[0].detect_fold();
+
+        let expected = Fold::Vertical(5);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test2() -> anyhow::Result<()> {
+        let grids = parse(INPUT)?;
+        let result = grids[0].detect_smudge_fold();
+
+        let expected = Fold::Horizontal(3);
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 405;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 400;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    /// Every mirror line, vertical ones first, found by comparing each cell with its image.
+    fn reflections(pattern: &Pattern) -> Vec<Fold> {
+        let (height, width) = (pattern.cells.height(), pattern.cells.width());
+        let cell = |row: usize, col: usize| pattern.cells[Pos::from((row, col))];
+        let vertical = (1..width).filter(|&line| {
+            (0..height).all(|row| {
+                (0..line).all(|col| {
+                    let image = 2 * line - 1 - col;
+                    image >= width || cell(row, col) == cell(row, image)
+                })
+            })
+        });
+        let horizontal = (1..height).filter(|&line| {
+            (0..line).all(|row| {
+                let image = 2 * line - 1 - row;
+                image >= height || (0..width).all(|col| cell(row, col) == cell(image, col))
+            })
+        });
+        vertical
+            .map(Fold::Vertical)
+            .chain(horizontal.map(Fold::Horizontal))
+            .collect()
+    }
+
+    /// The first new mirror line when fixing each cell in turn.
+    fn smudge_reflection(pattern: &Pattern) -> Option<Fold> {
+        let old = *reflections(pattern).first()?;
+        let mut pattern = pattern.clone();
+        for row in 0..pattern.cells.height() {
+            for col in 0..pattern.cells.width() {
+                pattern.flip(row, col);
+                let new = reflections(&pattern).into_iter().find(|&fold| fold != old);
+                pattern.flip(row, col);
+                if new.is_some() {
+                    return new;
+                }
+            }
+        }
+        None
+    }
+
+    /// Patterns of up to 9x9 cells. Most are mirrored along a random line and some of those
+    /// get a smudge.
+    fn pattern() -> impl Strategy<Value = String> {
+        (1..10usize, 1..10usize)
+            .prop_flat_map(|(height, width)| {
+                (
+                    Just((height, width)),
+                    prop::collection::vec(any::<bool>(), height * width),
+                    prop::option::weighted(0.8, (any::<bool>(), any::<Index>())),
+                    prop::option::of(any::<Index>()),
+                )
+            })
+            .prop_map(|((height, width), mut rocks, mirror, smudge)| {
+                let at = |row: usize, col: usize| row * width + col;
+                match mirror {
+                    Some((true, line)) if width > 1 => {
+                        let line = 1 + line.index(width - 1);
+                        for row in 0..height {
+                            for col in line..width.min(2 * line) {
+                                rocks[at(row, col)] = rocks[at(row, 2 * line - 1 - col)];
+                            }
+                        }
+                    }
+                    Some((false, line)) if height > 1 => {
+                        let line = 1 + line.index(height - 1);
+                        for row in line..height.min(2 * line) {
+                            for col in 0..width {
+                                rocks[at(row, col)] = rocks[at(2 * line - 1 - row, col)];
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                if let Some(smudge) = smudge {
+                    let i = smudge.index(rocks.len());
+                    rocks[i] = !rocks[i];
+                }
+                let rows: Vec<String> = rocks
+                    .chunks(width)
+                    .map(|row| {
+                        row.iter()
+                            .map(|&rock| if rock { '#' } else { '.' })
+                            .collect()
+                    })
+                    .collect();
+                rows.join("\n")
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn detect_fold_opt_matches_reflections(text in pattern()) {
+            let pattern = parse_error::nom(&text, parse_grid).unwrap();
+            prop_assert_eq!(pattern.detect_fold_opt(), reflections(&pattern));
+        }
+
+        #[test]
+        fn detect_fold_matches_reflections(text in pattern()) {
+            let pattern = parse_error::nom(&text, parse_grid).unwrap();
+            if let Some(&expected) = reflections(&pattern).first() {
+                prop_assert_eq!(pattern.detect_fold(), expected);
+            }
+        }
+
+        #[test]
+        fn detect_smudge_fold_matches_reflections(text in pattern()) {
+            let pattern = parse_error::nom(&text, parse_grid).unwrap();
+            if let Some(expected) = smudge_reflection(&pattern) {
+                prop_assert_eq!(pattern.detect_smudge_fold(), expected);
+            }
+        }
+    }
+}
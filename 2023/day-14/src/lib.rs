@@ -0,0 +1,178 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use cycle::find_cycle;
+use grid::{Grid, Pos};
+
+pub fn part1(grid: &Platform) -> anyhow::Result<usize> {
+    let mut grid = grid.clone();
+    grid.move_dishes_north();
+    let result = grid.total_load();
+    Ok(result)
+}
+
+pub fn part2(grid: &Platform) -> anyhow::Result<usize> {
+    let num_steps = 1_000_000_000;
+    let (cycle, platforms) = find_cycle(grid.clone(), Platform::spin_cycle, Platform::clone);
+    let result = cycle.nth(&platforms, num_steps).total_load();
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Platform {
+    cells: Grid<char>,
+}
+
+impl Platform {
+    /// Tilts the platform north, west, south and east.
+    fn spin_cycle(&self) -> Platform {
+        let mut next = self.clone();
+        next.move_dishes_north();
+        next.move_dishes_west();
+        next.move_dishes_south();
+        next.move_dishes_east();
+        next
+    }
+
+    fn move_dishes_north(&mut self) {
+        for row in 1..self.cells.height() {
+            for col in 0..self.cells.width() {
+                if self.cells[Pos::from((row, col))] == 'O' {
+                    let mut r = row;
+                    while r > 0 && self.cells[Pos::from((r - 1, col))] == '.' {
+                        self.cells[Pos::from((r - 1, col))] = 'O';
+                        self.cells[Pos::from((r, col))] = '.';
+                        r -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn move_dishes_south(&mut self) {
+        for row in (0..self.cells.height() - 1).rev() {
+            for col in 0..self.cells.width() {
+                if self.cells[Pos::from((row, col))] == 'O' {
+                    let mut r = row;
+                    while r + 1 < self.cells.height() && self.cells[Pos::from((r + 1, col))] == '.'
+                    {
+                        self.cells[Pos::from((r + 1, col))] = 'O';
+                        self.cells[Pos::from((r, col))] = '.';
+                        r += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn move_dishes_west(&mut self) {
+        for col in 1..self.cells.width() {
+            for row in 0..self.cells.height() {
+                if self.cells[Pos::from((row, col))] == 'O' {
+                    let mut c = col;
+                    while c > 0 && self.cells[Pos::from((row, c - 1))] == '.' {
+                        self.cells[Pos::from((row, c - 1))] = 'O';
+                        self.cells[Pos::from((row, c))] = '.';
+                        c -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn move_dishes_east(&mut self) {
+        for col in (0..self.cells.width() - 1).rev() {
+            for row in 0..self.cells.height() {
+                if self.cells[Pos::from((row, col))] == 'O' {
+                    let mut c = col;
+                    while c + 1 < self.cells.width() && self.cells[Pos::from((row, c + 1))] == '.' {
+                        self.cells[Pos::from((row, c + 1))] = 'O';
+                        self.cells[Pos::from((row, c))] = '.';
+                        c += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn total_load(&self) -> usize {
+        let mut result = 0;
+
+        for row in 0..self.cells.height() {
+            for col in 0..self.cells.width() {
+                if self.cells[Pos::from((row, col))] == 'O' {
+                    result += self.cells.height() - row;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Platform {
+            cells: input.parse()?,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Platform> {
+    input.parse()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Platform;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 136;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 64;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
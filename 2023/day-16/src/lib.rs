@@ -1,39 +1,41 @@
+use aoc_core::Solution;
 use std::collections::{BTreeSet, VecDeque};
-use std::str::FromStr;
+use std::fmt::Display;
 
+use grid::{Grid, Pos};
 use rayon::prelude::*;
 
-pub fn part1(input: &str) -> anyhow::Result<usize> {
-    let grid: Grid = input.parse()?;
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<usize> {
     let initial = Position {
         row: 0,
         col: 0,
         direction: Direction::Right,
     };
-    let result = number_of_energized_positions(&grid, &initial);
+    let result = number_of_energized_positions(grid, &initial);
     Ok(result)
 }
 
-pub fn part2(input: &str) -> anyhow::Result<usize> {
-    let grid: Grid = input.parse()?;
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<usize> {
+    let height = grid.height() as isize;
+    let width = grid.width() as isize;
 
-    let rights = (0..grid.height).map(|row| Position {
+    let rights = (0..height).map(|row| Position {
         row: row,
         col: 0,
         direction: Direction::Right,
     });
-    let lefts = (0..grid.height).map(|row| Position {
+    let lefts = (0..height).map(|row| Position {
         row: row,
-        col: grid.width - 1,
+        col: width - 1,
         direction: Direction::Left,
     });
-    let downs = (0..grid.width).map(|col| Position {
+    let downs = (0..width).map(|col| Position {
         row: 0,
         col,
         direction: Direction::Down,
     });
-    let ups = (0..grid.width).map(|col| Position {
-        row: grid.height - 1,
+    let ups = (0..width).map(|col| Position {
+        row: height - 1,
         col,
         direction: Direction::Up,
     });
@@ -43,21 +45,21 @@ pub fn part2(input: &str) -> anyhow::Result<usize> {
         .chain(downs)
         .chain(ups)
         .par_bridge()
-        .map(|start| number_of_energized_positions(&grid, &start))
+        .map(|start| number_of_energized_positions(grid, &start))
         .max()
         .unwrap_or_default();
 
     Ok(result)
 }
 
-fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
+fn number_of_energized_positions(grid: &Grid<char>, start: &Position) -> usize {
     let mut q = VecDeque::from([*start]);
-    let mut positions: BTreeSet<(i32, i32)> = BTreeSet::new();
+    let mut positions: BTreeSet<(isize, isize)> = BTreeSet::new();
     let mut visited: BTreeSet<Position> = BTreeSet::new();
 
     while let Some(pos) = q.pop_front() {
         positions.insert((pos.row, pos.col));
-        let cell = grid.get(pos.row, pos.col);
+        let cell = grid[pos.pos()];
         match cell {
             '.' => {
                 let (dr, dc) = pos.direction.delta();
@@ -66,7 +68,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                     col: pos.col + dc,
                     direction: pos.direction,
                 };
-                if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                     q.push_back(next_pos);
                 }
             }
@@ -83,7 +85,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                     col: pos.col + dc,
                     direction: next_direction,
                 };
-                if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                     q.push_back(next_pos);
                 }
             }
@@ -100,7 +102,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                     col: pos.col + dc,
                     direction: next_direction,
                 };
-                if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                     q.push_back(next_pos);
                 }
             }
@@ -112,7 +114,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: pos.direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
                 } else {
@@ -123,7 +125,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: next_direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
 
@@ -134,7 +136,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: next_direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
                 }
@@ -147,7 +149,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: pos.direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
                 } else {
@@ -158,7 +160,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: next_direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
 
@@ -169,7 +171,7 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
                         col: pos.col + dc,
                         direction: next_direction,
                     };
-                    if grid.is_valid_position(&next_pos) && visited.insert(next_pos) {
+                    if grid.contains(next_pos.pos()) && visited.insert(next_pos) {
                         q.push_back(next_pos);
                     }
                 }
@@ -183,46 +185,17 @@ fn number_of_energized_positions(grid: &Grid, start: &Position) -> usize {
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct Position {
-    row: i32,
-    col: i32,
+    row: isize,
+    col: isize,
     direction: Direction,
 }
 
-#[derive(Debug)]
-struct Grid {
-    cells: Vec<Vec<char>>,
-    width: i32,
-    height: i32,
-}
-
-impl Grid {
-    fn is_valid_position(&self, pos: &Position) -> bool {
-        pos.row >= 0 && pos.row < self.height && pos.col >= 0 && pos.col < self.width
-    }
-
-    fn get(&self, row: i32, col: i32) -> char {
-        self.cells[row as usize][col as usize]
+impl Position {
+    fn pos(&self) -> Pos {
+        Pos::new(self.row, self.col)
     }
 }
 
-impl FromStr for Grid {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let cells = s
-            .lines()
-            .filter(|s| !s.is_empty())
-            .map(|s| s.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-        let height = cells.len() as i32;
-        let width = cells.iter().next().map(|s| s.len()).unwrap_or_default() as i32;
-        Ok(Grid {
-            cells,
-            width,
-            height,
-        })
-    }
-}
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 enum Direction {
     Up,
@@ -232,7 +205,7 @@ enum Direction {
 }
 
 impl Direction {
-    fn delta(&self) -> (i32, i32) {
+    fn delta(&self) -> (isize, isize) {
         match self {
             Direction::Up => (-1, 0),
             Direction::Right => (0, 1),
@@ -242,6 +215,31 @@ impl Direction {
     }
 }
 
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    input.parse()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,7 +257,7 @@ mod tests {
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?)?;
         let expected = 46;
         assert_eq!(result, expected);
         Ok(())
@@ -267,7 +265,7 @@ mod tests {
 
     #[test]
     fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
+        let result = part2(&parse(INPUT)?)?;
         let expected = 51;
         assert_eq!(result, expected);
         Ok(())
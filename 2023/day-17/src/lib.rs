@@ -1,28 +1,28 @@
 use anyhow::anyhow;
-use std::str::FromStr;
+use aoc_core::Solution;
+use std::fmt::Display;
 
-use pathfinding::prelude::dijkstra;
+use grid::{Grid, Pos};
+use search::dijkstra;
 
-pub fn part1(input: &str) -> anyhow::Result<usize> {
-    let grid: Grid = input.parse()?;
+pub fn part1(grid: &Grid<usize>) -> anyhow::Result<usize> {
     let start = (0, 0);
-    let goal = (grid.height as i32 - 1, grid.width as i32 - 1);
+    let goal = (grid.height() as i32 - 1, grid.width() as i32 - 1);
     let min = 1;
     let max = 3;
-    find_solution(&grid, start, goal, min, max).ok_or_else(|| anyhow!("no solution found"))
+    find_solution(grid, start, goal, min, max).ok_or_else(|| anyhow!("no solution found"))
 }
 
-pub fn part2(input: &str) -> anyhow::Result<usize> {
-    let grid: Grid = input.parse()?;
+pub fn part2(grid: &Grid<usize>) -> anyhow::Result<usize> {
     let start = (0, 0);
-    let goal = (grid.height as i32 - 1, grid.width as i32 - 1);
+    let goal = (grid.height() as i32 - 1, grid.width() as i32 - 1);
     let min = 4;
     let max = 10;
-    find_solution(&grid, start, goal, min, max).ok_or_else(|| anyhow!("no solution found"))
+    find_solution(grid, start, goal, min, max).ok_or_else(|| anyhow!("no solution found"))
 }
 
 fn find_solution(
-    grid: &Grid,
+    grid: &Grid<usize>,
     start: (i32, i32),
     goal: (i32, i32),
     min: u32,
@@ -41,7 +41,7 @@ fn find_solution(
     .map(|(_path, distance)| distance)
 }
 
-fn successors(grid: &Grid, edge: &Edge, min: u32, max: u32) -> Vec<(Edge, usize)> {
+fn successors(grid: &Grid<usize>, edge: &Edge, min: u32, max: u32) -> Vec<(Edge, usize)> {
     static DIRECTIONS: [Direction; 4] = [
         Direction::Up,
         Direction::Down,
@@ -55,7 +55,7 @@ fn successors(grid: &Grid, edge: &Edge, min: u32, max: u32) -> Vec<(Edge, usize)
 }
 
 fn edge_in_direction(
-    grid: &Grid,
+    grid: &Grid<usize>,
     edge: &Edge,
     direction: Direction,
     min: u32,
@@ -76,10 +76,7 @@ fn edge_in_direction(
         while direction_count < min {
             row += dr;
             col += dc;
-            if row < 0 || row >= grid.height as i32 || col < 0 || col >= grid.width as i32 {
-                return None;
-            }
-            distance += grid.cells[row as usize][col as usize];
+            distance += grid.get(Pos::new(row as isize, col as isize))?;
             direction_count += 1;
         }
         return Some((
@@ -93,10 +90,7 @@ fn edge_in_direction(
     }
     row += dr;
     col += dc;
-    if row < 0 || row >= grid.height as i32 || col < 0 || col >= grid.width as i32 {
-        return None;
-    }
-    distance += grid.cells[row as usize][col as usize];
+    distance += grid.get(Pos::new(row as isize, col as isize))?;
     direction_count += 1;
     if direction_count > max {
         return None;
@@ -118,13 +112,6 @@ struct Edge {
     direction_count: u32,
 }
 
-#[derive(Debug)]
-struct Grid {
-    cells: Vec<Vec<usize>>,
-    width: usize,
-    height: usize,
-}
-
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum Direction {
     Up,
@@ -153,25 +140,32 @@ impl Direction {
     }
 }
 
-impl FromStr for Grid {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let cells: Vec<Vec<usize>> = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).unwrap() as usize)
-                    .collect()
-            })
-            .collect();
-        let height = cells.len();
-        let width = cells.first().map(|l| l.len()).unwrap_or_default();
-        Ok(Grid {
-            cells,
-            width,
-            height,
-        })
+pub fn parse(input: &str) -> anyhow::Result<Grid<usize>> {
+    Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .map(|digit| digit as usize)
+            .ok_or_else(|| anyhow!("not a digit"))
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Grid<usize>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
     }
 }
 
@@ -195,7 +189,7 @@ mod tests {
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?)?;
         let expected = 102;
         assert_eq!(result, expected);
         Ok(())
@@ -203,7 +197,7 @@ mod tests {
 
     #[test]
     fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
+        let result = part2(&parse(INPUT)?)?;
         let expected = 94;
         assert_eq!(result, expected);
         Ok(())
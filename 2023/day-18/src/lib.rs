@@ -1,4 +1,4 @@
-use anyhow::anyhow;
+use aoc_core::Solution;
 use nom::bytes::complete::{tag, take_while_m_n};
 use nom::character::complete;
 use nom::character::complete::{line_ending, one_of, space1};
@@ -6,9 +6,9 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::delimited;
 use nom::IResult;
+use std::fmt::Display;
 
-pub fn part1(input: &str) -> anyhow::Result<i64> {
-    let commands = parse_input(input)?;
+pub fn part1(commands: &[Command]) -> anyhow::Result<i64> {
     let mut start = Position::new(0, 0);
     let mut internal_area = 0;
     for command in commands.iter() {
@@ -26,8 +26,7 @@ pub fn part1(input: &str) -> anyhow::Result<i64> {
     Ok(internal_points + num_edge_points)
 }
 
-pub fn part2(input: &str) -> anyhow::Result<i64> {
-    let commands = parse_input(input)?;
+pub fn part2(commands: &[Command]) -> anyhow::Result<i64> {
     let mut start = Position::new(0, 0);
     let mut internal_area = 0;
 
@@ -67,7 +66,7 @@ pub fn part2(input: &str) -> anyhow::Result<i64> {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Command {
+pub struct Command {
     direction: Direction,
     distance: i64,
     color: i64,
@@ -104,8 +103,8 @@ impl Position {
     }
 }
 
-fn parse_input(input: &str) -> anyhow::Result<Vec<Command>> {
-    let (_, commands) = parse_commands(input).map_err(|e| anyhow!(e.to_string()))?;
+pub fn parse(input: &str) -> anyhow::Result<Vec<Command>> {
+    let commands = parse_error::nom(input, parse_commands)?;
     Ok(commands)
 }
 
@@ -160,6 +159,27 @@ fn parse_direction(input: &str) -> IResult<&str, Direction> {
     Ok((input, dir))
 }
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,7 +201,7 @@ U 2 (#7a21e3)"#;
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?)?;
         let expected = 62;
         assert_eq!(result, expected);
         Ok(())
@@ -189,7 +209,7 @@ U 2 (#7a21e3)"#;
 
     #[test]
     fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
+        let result = part2(&parse(INPUT)?)?;
         let expected: i64 = 952408144115;
         assert_eq!(result, expected);
         Ok(())
@@ -206,7 +226,7 @@ U 4 (#000000)"#;
 
     #[test]
     fn test1() -> anyhow::Result<()> {
-        let result = part1(INPUT2)?;
+        let result = part1(&parse(INPUT2)?)?;
         let expected = 52;
         assert_eq!(result, expected);
         Ok(())
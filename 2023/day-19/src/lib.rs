@@ -1,6 +1,8 @@
+use aoc_core::Solution;
+use interval_set::Interval;
 use std::collections::HashMap;
+use std::fmt::Display;
 
-use anyhow::anyhow;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete;
@@ -9,8 +11,7 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::{delimited, preceded};
 use nom::{character, IResult};
 
-pub fn part1(input: &str) -> anyhow::Result<i64> {
-    let (_, state) = parse_state(input).map_err(|e| anyhow!(e.to_string()))?;
+pub fn part1(state: &State) -> anyhow::Result<i64> {
     let result: i64 = state
         .parts
         .iter()
@@ -20,13 +21,13 @@ pub fn part1(input: &str) -> anyhow::Result<i64> {
     Ok(result)
 }
 
-pub fn part2(input: &str) -> anyhow::Result<i64> {
-    let (_, state) = parse_state(input).map_err(|e| anyhow!(e.to_string()))?;
+pub fn part2(state: &State) -> anyhow::Result<i64> {
+    let all = Interval::inclusive(1, 4000);
     let start_ranges = Ranges {
-        x: (1, 4000),
-        m: (1, 4000),
-        a: (1, 4000),
-        s: (1, 4000),
+        x: all,
+        m: all,
+        a: all,
+        s: all,
     };
     let result = count_solutions(&state.workflows, &start_ranges, "in");
     Ok(result)
@@ -50,23 +51,26 @@ fn count_solutions(workflows: &HashMap<&str, Workflow>, ranges: &Ranges, name: &
                     value,
                     next,
                 } => {
-                    let (a, b) = ranges.get(*name);
-                    let matching_range = match op {
-                        '<' => (a, b.min(*value - 1)),
-                        '>' => (a.max(*value + 1), b),
+                    let range = ranges.get(*name);
+                    let (matching, not_matching) = match op {
+                        '<' => (
+                            Interval::new(i64::MIN, *value),
+                            Interval::new(*value, i64::MAX),
+                        ),
+                        '>' => (
+                            Interval::new(*value + 1, i64::MAX),
+                            Interval::new(i64::MIN, *value + 1),
+                        ),
                         _ => unreachable!("{}", op),
                     };
-                    let not_matching_range = match op {
-                        '<' => (*value, b),
-                        '>' => (a, *value),
-                        _ => unreachable!("{}", op),
-                    };
-                    if matching_range.0 <= matching_range.1 {
+                    let matching_range = range.intersection(&matching);
+                    let not_matching_range = range.intersection(&not_matching);
+                    if !matching_range.is_empty() {
                         let mut copy = ranges;
                         copy.set(*name, matching_range);
                         result += count_solutions(workflows, &copy, next);
                     }
-                    if not_matching_range.0 <= not_matching_range.1 {
+                    if !not_matching_range.is_empty() {
                         ranges.set(*name, not_matching_range);
                     } else {
                         break;
@@ -117,7 +121,7 @@ fn state_from_start<'a>(workflows: &'a HashMap<&str, Workflow>, part: &Part) ->
 }
 
 #[derive(Debug)]
-struct State<'a> {
+pub struct State<'a> {
     workflows: HashMap<&'a str, Workflow<'a>>,
     parts: Vec<Part>,
 }
@@ -150,25 +154,18 @@ impl Part {
 
 #[derive(Debug, Copy, Clone)]
 struct Ranges {
-    x: (i64, i64),
-    m: (i64, i64),
-    a: (i64, i64),
-    s: (i64, i64),
+    x: Interval<i64>,
+    m: Interval<i64>,
+    a: Interval<i64>,
+    s: Interval<i64>,
 }
 
 impl Ranges {
     fn num_choices(&self) -> i64 {
-        fn range_choices(r: (i64, i64)) -> i64 {
-            r.1 - r.0 + 1
-        }
-
-        range_choices(self.x)
-            * range_choices(self.m)
-            * range_choices(self.a)
-            * range_choices(self.s)
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
     }
 
-    fn get(&self, key: char) -> (i64, i64) {
+    fn get(&self, key: char) -> Interval<i64> {
         match key {
             'x' => self.x,
             'm' => self.m,
@@ -178,7 +175,7 @@ impl Ranges {
         }
     }
 
-    fn set(&mut self, key: char, value: (i64, i64)) {
+    fn set(&mut self, key: char, value: Interval<i64>) {
         match key {
             'x' => self.x = value,
             'm' => self.m = value,
@@ -202,6 +199,10 @@ enum Rule<'a> {
     },
 }
 
+pub fn parse(input: &str) -> anyhow::Result<State<'_>> {
+    Ok(parse_error::nom(input, parse_state)?)
+}
+
 fn parse_state(input: &str) -> IResult<&str, State> {
     let (input, workflows) = separated_list1(line_ending, parse_workflow)(input)?;
     let (input, _) = many1(line_ending)(input)?;
@@ -263,6 +264,27 @@ fn parse_default_rule(input: &str) -> IResult<&str, Rule> {
     let (input, next) = alpha1(input)?;
     Ok((input, Rule::Default { next }))
 }
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input<'a> = State<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -287,7 +309,7 @@ hdj{m>838:A,pv}
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?)?;
         let expected = 19114;
         assert_eq!(result, expected);
         Ok(())
@@ -295,7 +317,7 @@ hdj{m>838:A,pv}
 
     #[test]
     fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
+        let result = part2(&parse(INPUT)?)?;
         let expected = 167409079868000;
         assert_eq!(result, expected);
         Ok(())
@@ -2,17 +2,17 @@ use std::fs;
 
 use anyhow::Context;
 
-use day_19::{part1, part2};
+use y2023_day_19::{parse, part1, part2};
 
 fn main() -> anyhow::Result<()> {
     let filename = "day-19.txt";
     let input = fs::read_to_string(filename).with_context(|| format!("cannot load {filename}"))?;
-    let input = input.trim();
+    let state = parse(input.trim())?;
 
-    let result = part1(input)?;
+    let result = part1(&state)?;
     println!("{result}");
 
-    let result = part2(input)?;
+    let result = part2(&state)?;
     println!("{result}");
 
     Ok(())
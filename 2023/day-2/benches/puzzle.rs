@@ -0,0 +1 @@
+aoc_bench::main!(y2023_day_2::Puzzle);
@@ -1,4 +1,6 @@
 use anyhow::anyhow;
+use aoc_core::Solution;
+use graph_export::{Graph, Shape, Style};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alpha1, line_ending, one_of, space1};
@@ -8,10 +10,10 @@ use nom::IResult;
 use nom::Parser;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::fmt::Display;
 use std::fmt::Formatter;
 
-pub fn part1(input: &str) -> anyhow::Result<i64> {
-    let (_, state) = parse_state(input).map_err(|e| anyhow!(e.to_string()))?;
+pub fn part1(state: &State) -> anyhow::Result<i64> {
     let mut q: VecDeque<Signal> = VecDeque::new();
     let mut flip_flops: HashMap<&str, bool> = HashMap::new();
     let mut conjunctions: HashMap<&str, HashMap<&str, Pulse>> = HashMap::new();
@@ -124,8 +126,7 @@ fn find_relevant_modules<'a>(modules: &'a HashMap<&str, Module>) -> Vec<&'a str>
     find_incoming_modules(bla, modules)
 }
 
-pub fn part2(input: &str) -> anyhow::Result<i64> {
-    let (_, state) = parse_state(input).map_err(|e| anyhow!(e.to_string()))?;
+pub fn part2(state: &State) -> anyhow::Result<i64> {
     let mut q: VecDeque<Signal> = VecDeque::new();
     let mut flip_flops: HashMap<&str, bool> = HashMap::new();
     let mut conjunctions: HashMap<&str, HashMap<&str, Pulse>> = HashMap::new();
@@ -141,7 +142,6 @@ pub fn part2(input: &str) -> anyhow::Result<i64> {
         }
     }
     let relevant_nodes: HashSet<&str> = HashSet::from_iter(find_relevant_modules(&state.modules));
-    println!("{:?}", relevant_nodes);
     let mut periods: HashMap<&str, i64> = HashMap::new();
 
     let mut round = 0;
@@ -187,10 +187,10 @@ pub fn part2(input: &str) -> anyhow::Result<i64> {
                             {
                                 periods.insert(module.name, round);
                                 if periods.len() == relevant_nodes.len() {
-                                    println!("periods: {:?}", periods);
                                     let values: Vec<i64> = periods.values().copied().collect();
-                                    let result = lcm_seq(&values);
-                                    return Ok(result);
+                                    return number_theory::lcm_all(values).ok_or_else(|| {
+                                        anyhow!("the number of presses doesn't fit an i64")
+                                    });
                                 }
                             }
                             Pulse::High
@@ -215,8 +215,45 @@ pub fn part2(input: &str) -> anyhow::Result<i64> {
     }
 }
 
+/// The module configuration, with the modules drawn by type. Modules without outputs of their
+/// own, like `rx`, are drawn as circles.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let state = parse_error::nom(input.trim(), parse_state)?;
+    let mut graph = Graph::directed();
+    graph.style(
+        "broadcaster",
+        Style::new().shape(Shape::Hexagon).fill("#9fd89f"),
+    );
+    graph.style(
+        "flip-flop",
+        Style::new().shape(Shape::Rounded).fill("#a8c8f0"),
+    );
+    graph.style(
+        "conjunction",
+        Style::new().shape(Shape::Diamond).fill("#ffd27f"),
+    );
+    graph.style("output", Style::new().shape(Shape::Circle).fill("#f4a6a6"));
+    let mut modules: Vec<&Module> = state.modules.values().collect();
+    modules.sort_by_key(|module| module.name);
+    for module in modules {
+        let node = graph.node(module.name);
+        match module.module_type {
+            ModuleType::FlipFlop => node.label(format!("%{}", module.name)).kind("flip-flop"),
+            ModuleType::Conjunction => node.label(format!("&{}", module.name)).kind("conjunction"),
+            ModuleType::Other => node.kind("broadcaster"),
+        };
+        for edge in module.edges.iter() {
+            if !state.modules.contains_key(edge) {
+                graph.node(edge).kind("output");
+            }
+            graph.edge(module.name, edge);
+        }
+    }
+    Ok(graph)
+}
+
 #[derive(Debug)]
-struct State<'a> {
+pub struct State<'a> {
     modules: HashMap<&'a str, Module<'a>>,
 }
 
@@ -256,6 +293,10 @@ impl fmt::Display for Pulse {
     }
 }
 
+pub fn parse(input: &str) -> anyhow::Result<State<'_>> {
+    Ok(parse_error::nom(input, parse_state)?)
+}
+
 fn parse_state(input: &str) -> IResult<&str, State> {
     let (input, modules) = separated_list1(line_ending, parse_module)(input)?;
     let modules: HashMap<&str, Module> =
@@ -296,33 +337,30 @@ fn parse_module_name(input: &str) -> IResult<&str, (ModuleType, &str)> {
     Ok((input, (ModuleType::Other, name)))
 }
 
-fn lcm_seq(numbers: &[i64]) -> i64 {
-    let mut result = 1;
-    for n in numbers.iter().copied() {
-        result = lcm(result, n)
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input<'a> = State<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input.trim())
     }
-    result
-}
 
-fn lcm(a: i64, b: i64) -> i64 {
-    a * (b / gcd(a, b))
-}
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
 
-fn gcd(a: i64, b: i64) -> i64 {
-    let mut num1 = a;
-    let mut num2 = b;
-    while num2 != 0 {
-        let tmp = num2;
-        num2 = num1 % num2;
-        num1 = tmp;
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
     }
-    num1
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
 
     static INPUT: &str = r#"broadcaster -> a
 %a -> inv, con
@@ -332,41 +370,34 @@ mod tests {
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?)?;
         let expected = 11687500;
         assert_eq!(result, expected);
         Ok(())
     }
 
     #[test]
-    fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
-        let expected = 167409079868000;
-        assert_eq!(result, expected);
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph(INPUT)?;
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.edge_count(), 6);
+        let dot = graph.to_dot();
+        assert!(dot.contains(
+            r##""inv" [label="&inv", shape=diamond, fillcolor="#ffd27f", style="filled"];"##
+        ));
+        assert!(dot.contains(r#""con" -> "output";"#));
         Ok(())
     }
 
     #[test]
     fn test_mermaid() -> anyhow::Result<()> {
-        let input = include_str!("../day-20.txt");
-        let (_, state) = parse_state(input).map_err(|e| anyhow!(e.to_string()))?;
-        let mut q: VecDeque<&Module> = VecDeque::from([state.modules.get("broadcaster").unwrap()]);
-        let mut visited = HashSet::from(["broadcaster"]);
-        while let Some(module) = q.pop_front() {
-            let module_name = match module.module_type {
-                ModuleType::Conjunction => format!("{}[&{}]", module.name, module.name),
-                ModuleType::FlipFlop => format!("{}[%{}]", module.name, module.name),
-                ModuleType::Other => format!("{}", module.name),
-            };
-            for edge in module.edges.iter() {
-                println!("{} --> {}", module_name, edge);
-                if let Some(next) = state.modules.get(edge) {
-                    if visited.insert(edge) {
-                        q.push_back(next);
-                    }
-                }
-            }
-        }
+        let mermaid = graph(INPUT)?.to_mermaid();
+        println!("{mermaid}");
+        assert!(mermaid.starts_with("flowchart LR"));
+        assert!(mermaid.contains(r#"{{"broadcaster"}}"#));
+        assert!(mermaid.contains(r#"{"&inv"}"#));
+        assert!(mermaid.contains(r#"(("output"))"#));
+        assert_eq!(mermaid.matches(" --> ").count(), 6);
         Ok(())
     }
 }
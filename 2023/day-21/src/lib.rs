@@ -1,101 +1,182 @@
-use std::fmt;
-use std::fmt::Formatter;
-use std::str::FromStr;
+use anyhow::{anyhow, bail};
+use aoc_core::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
 
-pub fn part1(input: &str) -> anyhow::Result<i64> {
-    let grid: Grid = input.parse()?;
-    let result = num_tiles(&grid, 64);
+use grid::{Grid, Pos, Wrapping};
+
+pub fn part1(grid: &Grid<char>, steps: i32) -> anyhow::Result<i64> {
+    let result = num_tiles(grid, steps);
     Ok(result)
 }
 
-pub fn part2(input: &str) -> anyhow::Result<i64> {
-    Ok(0)
+/// Counts the plots reachable in exactly `steps` steps in the garden repeated infinitely in
+/// every direction. Once the walk has spread over a few copies of the garden, the second
+/// differences of the counts after `steps`, `steps - width`, `steps - 2 * width`, ... repeat,
+/// so the walk is only simulated until they do and the rest is extrapolated.
+pub fn part2(grid: &Grid<char>, steps: i64) -> anyhow::Result<i64> {
+    if grid.width() != grid.height() {
+        bail!("the garden is not square");
+    }
+    let period = grid.width() as i64;
+    let mut walk = Walk::new(grid)?;
+    let mut counts = Vec::new();
+    loop {
+        if walk.steps == steps {
+            return Ok(walk.count());
+        }
+        if walk.steps % period == steps % period {
+            counts.push(walk.count());
+            let second: Vec<i64> = counts.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+            if let Some(cycle) = repeating(&second) {
+                let remaining = (steps - walk.steps) / period;
+                let mut count = counts[counts.len() - 1];
+                let mut first = count - counts[counts.len() - 2];
+                for i in 0..remaining as usize {
+                    first += second[second.len() - cycle + i % cycle];
+                    count += first;
+                }
+                return Ok(count);
+            }
+        }
+        walk.step();
+    }
 }
 
-#[derive(Debug, Clone)]
-struct Grid {
-    cells: Vec<Vec<char>>,
-    height: usize,
-    width: usize,
+/// The shortest cycle the end of `values` has repeated in at least `REPEATS` times.
+fn repeating(values: &[i64]) -> Option<usize> {
+    const REPEATS: usize = 4;
+    (1..=values.len() / (REPEATS + 1)).find(|&cycle| {
+        let tail = &values[values.len() - (REPEATS + 1) * cycle..];
+        tail.iter().zip(&tail[cycle..]).all(|(a, b)| a == b)
+    })
 }
 
-impl Grid {
-    fn start_pos(&self) -> Option<(usize, usize)> {
-        self.cells.iter()
-            .enumerate()
-            .find_map(|(row, line)|
-                line
-                    .iter()
-                    .enumerate()
-                    .find(|(_, ch)| **ch == 'S').map(|(col, _)| (row, col)))
-    }
+/// A walk through the infinitely repeated garden, one step at a time.
+struct Walk<'a> {
+    tiles: Wrapping<'a, char>,
+    steps: i64,
+    /// The plots first reached on the previous and on the last step. Those of the next step
+    /// can only be next to the latter.
+    previous: HashSet<Pos>,
+    frontier: HashSet<Pos>,
+    /// Plots first reached after an even and an odd number of steps.
+    reached: [i64; 2],
 }
 
-impl FromStr for Grid {
-    type Err = anyhow::Error;
+impl<'a> Walk<'a> {
+    fn new(grid: &'a Grid<char>) -> anyhow::Result<Self> {
+        let start = grid
+            .find(|c| *c == 'S')
+            .ok_or_else(|| anyhow!("no starting position"))?;
+        let tiles = grid
+            .wrapping()
+            .ok_or_else(|| anyhow!("the garden is empty"))?;
+        Ok(Self {
+            tiles,
+            steps: 0,
+            previous: HashSet::new(),
+            frontier: HashSet::from([start]),
+            reached: [1, 0],
+        })
+    }
+
+    /// The plots the walk can end on after the steps taken so far.
+    fn count(&self) -> i64 {
+        self.reached[(self.steps % 2) as usize]
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let cells: Vec<Vec<char>> = s.lines().filter(|s| !s.is_empty()).map(|line| line.chars().collect()).collect();
-        let height = cells.len();
-        let width = cells.first().map(|line| line.len()).unwrap_or_default();
-        Ok(Grid { cells, width, height })
+    fn step(&mut self) {
+        let next: HashSet<Pos> = self
+            .frontier
+            .iter()
+            .flat_map(|pos| pos.neighbours4())
+            .filter(|next| {
+                self.tiles[*next] != '#'
+                    && !self.previous.contains(next)
+                    && !self.frontier.contains(next)
+            })
+            .collect();
+        self.steps += 1;
+        if self.steps == 1 && next.is_empty() {
+            // the start can't be returned to without a plot next to it
+            self.reached[0] = 0;
+        }
+        self.reached[(self.steps % 2) as usize] += next.len() as i64;
+        self.previous = std::mem::replace(&mut self.frontier, next);
     }
 }
 
-fn num_tiles(grid: &Grid, max_steps: i32) -> i64 {
-    let (start_row, start_col) = grid.start_pos().unwrap();
-    let mut points = vec![(start_row, start_col)];
+/// Counts the plots reachable in exactly `max_steps` steps. A plot first reached after `n`
+/// steps can also be reached after `n + 2`, `n + 4`, ... steps by stepping back and forth, so
+/// these are the plots whose distance is at most `max_steps` and has the same parity. Only a
+/// start without any plot next to it cannot be returned to.
+pub fn num_tiles(grid: &Grid<char>, max_steps: i32) -> i64 {
+    let start = grid.find(|c| *c == 'S').unwrap();
+    let mut points = vec![start];
     let mut grid = grid.clone();
-    grid.cells[start_row][start_col] = '.';
+    grid[start] = 'O';
+    let can_move = start
+        .neighbours4()
+        .into_iter()
+        .any(|next| grid.get(next) == Some(&'.'));
+    let mut result = if max_steps == 0 || (can_move && max_steps % 2 == 0) {
+        1
+    } else {
+        0
+    };
 
-    for round in 0..max_steps {
-        let mut next_points: Vec<(usize, usize)> = Vec::new();
+    for round in 1..=max_steps {
+        let mut next_points: Vec<Pos> = Vec::new();
 
-        for &(row, col) in points.iter() {
-            if row > 0 && grid.cells[row - 1][col] == '.' {
-                grid.cells[row - 1][col] = 'O';
-                next_points.push((row - 1, col));
+        for &pos in points.iter() {
+            for next in pos.neighbours4() {
+                if grid.get(next) == Some(&'.') {
+                    grid[next] = 'O';
+                    next_points.push(next);
+                }
             }
+        }
 
-            if row + 1 < grid.height && grid.cells[row + 1][col] == '.' {
-                grid.cells[row + 1][col] = 'O';
-                next_points.push((row + 1, col));
-            }
+        points = next_points;
+        if round % 2 == max_steps % 2 {
+            result += points.len() as i64;
+        }
+    }
 
-            if col > 0 && grid.cells[row][col - 1] == '.' {
-                grid.cells[row][col - 1] = 'O';
-                next_points.push((row, col - 1));
-            }
+    result
+}
 
-            if col + 1 < grid.width && grid.cells[row][col + 1] == '.' {
-                grid.cells[row][col + 1] = 'O';
-                next_points.push((row, col + 1));
-            }
-        }
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    input.parse()
+}
 
-        points = next_points;
-        println!("{} {}", round, points.len());
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Input<'a> = Grid<char>;
 
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
     }
 
-    points.len() as i64
-}
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input, 64)
+    }
 
-impl fmt::Display for Grid {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for line in self.cells.iter() {
-            for c in line {
-                write!(f, "{}", c)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input, 26501365)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::Index;
 
     static INPUT: &str = r#"...........
 .....###.#.
@@ -111,7 +192,7 @@ mod tests {
 
     #[test]
     fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
+        let result = part1(&parse(INPUT)?, 6)?;
         let expected = 16;
         assert_eq!(result, expected);
         Ok(())
@@ -119,18 +200,101 @@ mod tests {
 
     #[test]
     fn test1() -> anyhow::Result<()> {
-        let grid: Grid = INPUT.parse()?;
+        let grid: Grid<char> = INPUT.parse()?;
         let result = num_tiles(&grid, 6);
         let expected = 16;
         assert_eq!(result, expected);
         Ok(())
     }
 
+    #[test]
+    fn num_tiles_counts_plots_by_parity() -> anyhow::Result<()> {
+        // two steps can end on the start again or on either plot two steps away
+        let grid: Grid<char> = "..S..".parse()?;
+        assert_eq!(num_tiles(&grid, 2), 3);
+        // but not if there is no plot to step to and back from
+        let grid: Grid<char> = "S#.".parse()?;
+        assert_eq!(num_tiles(&grid, 2), 0);
+        Ok(())
+    }
+
     #[test]
     fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
-        let expected = 167409079868000;
+        let result = part2(&parse(INPUT)?, 5000)?;
+        let expected = 16733044;
         assert_eq!(result, expected);
         Ok(())
     }
+
+    #[test]
+    fn test2() -> anyhow::Result<()> {
+        let grid = parse(INPUT)?;
+        for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(part2(&grid, steps)?, expected);
+        }
+        Ok(())
+    }
+
+    /// Walks every path of `steps` steps at once, in the garden repeated infinitely or in the
+    /// garden alone.
+    fn brute_force(grid: &Grid<char>, steps: i64, infinite: bool) -> i64 {
+        let start = grid.find(|c| *c == 'S').unwrap();
+        let mut points = HashSet::from([start]);
+        for _ in 0..steps {
+            points = points
+                .iter()
+                .flat_map(|pos| pos.neighbours4())
+                .filter(|&next| {
+                    if infinite {
+                        grid.wrapping().unwrap()[next] != '#'
+                    } else {
+                        grid.get(next).is_some_and(|c| *c != '#')
+                    }
+                })
+                .collect();
+        }
+        points.len() as i64
+    }
+
+    /// Gardens of up to `max_size` x `max_size` tiles with some rocks and the start on a random
+    /// plot.
+    fn garden(max_size: usize, square: bool) -> impl Strategy<Value = String> {
+        (1..=max_size, 1..=max_size)
+            .prop_flat_map(move |(height, width)| {
+                let height = if square { width } else { height };
+                (
+                    Just(width),
+                    prop::collection::vec(prop::bool::weighted(0.3), height * width),
+                    any::<Index>(),
+                )
+            })
+            .prop_map(|(width, rocks, start)| {
+                let start = start.index(rocks.len());
+                let tiles: Vec<char> = rocks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &rock)| match (i == start, rock) {
+                        (true, _) => 'S',
+                        (false, true) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect();
+                let rows: Vec<String> = tiles.chunks(width).map(String::from_iter).collect();
+                rows.join("\n")
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn num_tiles_matches_brute_force(input in garden(9, false), steps in 0..20i64) {
+            let grid = parse(&input).unwrap();
+            prop_assert_eq!(num_tiles(&grid, steps as i32), brute_force(&grid, steps, false));
+        }
+
+        #[test]
+        fn part2_matches_brute_force(input in garden(5, true), steps in 0..60i64) {
+            let grid = parse(&input).unwrap();
+            prop_assert_eq!(part2(&grid, steps).unwrap(), brute_force(&grid, steps, true));
+        }
+    }
 }
@@ -0,0 +1,261 @@
+use aoc_core::Solution;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+use std::iter;
+
+use anyhow::anyhow;
+use graph_export::{Graph, Shape, Style};
+use grid::{Grid, Pos};
+
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<i64> {
+    let start = find_start(grid).ok_or_else(|| anyhow!("no start"))?;
+    let mut q = VecDeque::from([(start, Vec::new())]);
+
+    let mut result = 0;
+    while let Some(((row, col), path)) = q.pop_front() {
+        if row + 1 == grid.height() as isize {
+            result = result.max(path.len());
+        }
+        let directions = next_directions(grid, row, col);
+        for &(dr, dc) in directions.iter() {
+            let (next_row, next_col) = (row + dr, col + dc);
+            if is_open(grid, next_row, next_col) && !path.contains(&(next_row, next_col)) {
+                let next_path = path
+                    .iter()
+                    .copied()
+                    .chain(iter::once((next_row, next_col)))
+                    .collect();
+                q.push_back(((next_row, next_col), next_path));
+            }
+        }
+        //
+    }
+    Ok(result as i64)
+}
+
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<i64> {
+    let start = find_start(grid).ok_or_else(|| anyhow!("no start position"))?;
+    let end = find_end(grid).ok_or_else(|| anyhow!("no end position"))?;
+    // longest path of a graph is NP-complete (see: https://en.wikipedia.org/wiki/Longest_path_problem)
+    // this graph has many nodes without branches. You can get a feasible runtime by compressing all such consecutive edges
+    // into a single one.
+    let graph = build_compressed_graph(grid, &start, &end);
+    let mut visited = BTreeSet::new();
+    let result = dfs(&graph, &start, &end, &mut visited);
+    Ok(result)
+}
+
+fn build_compressed_graph(
+    grid: &Grid<char>,
+    start: &Point,
+    end: &Point,
+) -> BTreeMap<Point, Vec<(Point, i64)>> {
+    let mut graph: BTreeMap<Point, Vec<(Point, i64)>> = BTreeMap::new();
+    // these are all nodes within the grid with more than two branches (where i come from + more than a single choice to move on)
+    let points = find_points_with_branches(grid, start, end);
+
+    //
+    // for each "branching point" find edges to the directly adjacent "branching points"
+    // the resulting graph is compressed, but contains silly cycles which are filtered out in the DFS step later on...
+    // see the attached mermaid diagram for the test input in the project
+    //
+    for &(start_row, start_col) in points.iter() {
+        let mut q: VecDeque<(Point, i64)> = VecDeque::from([((start_row, start_col), 0)]);
+        let mut visited = BTreeSet::from([(start_row, start_col)]);
+
+        while let Some(((row, col), distance)) = q.pop_front() {
+            // distance != 0 means "not start node"
+            if distance != 0 && points.contains(&(row, col)) {
+                graph
+                    .entry((start_row, start_col))
+                    .or_default()
+                    .push(((row, col), distance));
+            } else {
+                for (dr, dc) in DIRECTIONS.iter() {
+                    let (next_row, next_col) = (row + dr, col + dc);
+                    if is_open(grid, next_row, next_col) && visited.insert((next_row, next_col)) {
+                        q.push_back(((next_row, next_col), distance + 1));
+                    }
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+fn find_points_with_branches(grid: &Grid<char>, start: &Point, end: &Point) -> Vec<Point> {
+    let mut points = vec![*start, *end];
+
+    for (pos, _) in grid.iter().filter(|(_, c)| **c != '#') {
+        let (row, col) = (pos.row, pos.col);
+        let direction_count = DIRECTIONS
+            .iter()
+            .filter(|(dr, dc)| is_open(grid, row + dr, col + dc))
+            .count();
+        if direction_count > 2 {
+            points.push((row, col))
+        }
+    }
+    points
+}
+
+// must do it recurively since i do not know when to insert/remove the nodes from
+// visited otherwise...
+fn dfs(
+    graph: &BTreeMap<Point, Vec<(Point, i64)>>,
+    pos: &Point,
+    end: &Point,
+    visited: &mut BTreeSet<Point>,
+) -> i64 {
+    if pos == end {
+        0
+    } else {
+        visited.insert(*pos);
+        let edges = graph.get(pos).unwrap();
+        let mut m = i64::MIN;
+        for (p, d) in edges.iter() {
+            if !visited.contains(p) {
+                m = m.max(dfs(graph, p, end, visited) + *d);
+            }
+        }
+        visited.remove(pos);
+        m
+    }
+}
+
+/// The compressed graph of part 2, from every branching point to the next ones, labelled with
+/// the length of the trail between them.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let grid: Grid<char> = input.parse()?;
+    let start = find_start(&grid).ok_or_else(|| anyhow!("no start position"))?;
+    let end = find_end(&grid).ok_or_else(|| anyhow!("no end position"))?;
+    let compressed = build_compressed_graph(&grid, &start, &end);
+
+    let mut graph = Graph::directed();
+    graph.style("end", Style::new().shape(Shape::Circle).fill("#9fd89f"));
+    for (r, c) in [start, end] {
+        graph.node(format!("{r},{c}")).kind("end");
+    }
+    for ((sr, sc), edges) in compressed.iter() {
+        for ((dr, dc), distance) in edges.iter() {
+            graph
+                .edge(format!("{sr},{sc}"), format!("{dr},{dc}"))
+                .label(distance);
+        }
+    }
+    Ok(graph)
+}
+
+static DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+type Point = (isize, isize);
+type State = (Point, Point, i64);
+fn is_open(grid: &Grid<char>, row: isize, col: isize) -> bool {
+    grid.get(Pos::new(row, col)).is_some_and(|c| *c != '#')
+}
+
+fn find_start(grid: &Grid<char>) -> Option<(isize, isize)> {
+    grid.rows().next().and_then(|line| {
+        line.iter()
+            .position(|c| *c == '.')
+            .map(|col| (0isize, col as isize))
+    })
+}
+
+fn find_end(grid: &Grid<char>) -> Option<(isize, isize)> {
+    grid.rows().last().and_then(|line| {
+        line.iter()
+            .position(|c| *c == '.')
+            .map(|col| (grid.height() as isize - 1, col as isize))
+    })
+}
+
+fn next_directions(grid: &Grid<char>, row: isize, col: isize) -> &'static [(isize, isize)] {
+    match grid[Pos::new(row, col)] {
+        '^' => [(-1, 0)].as_slice(),
+        'v' => [(1, 0)].as_slice(),
+        '<' => [(0, -1)].as_slice(),
+        '>' => [(0, 1)].as_slice(),
+        _ => [(-1, 0), (1, 0), (0, -1), (0, 1)].as_slice(),
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    input.parse()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 94;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 154;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph(INPUT)?;
+        assert_eq!(graph.node_count(), 9);
+        assert_eq!(graph.edge_count(), 24);
+        assert!(graph.to_mermaid().contains(r#"n0 -->|"15"| n"#));
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use aoc_core::Solution;
+use graph_export::Graph;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, line_ending, space0, space1};
+use nom::multi::separated_list0;
+use nom::sequence::terminated;
+use nom::IResult;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use union_find::UnionFind;
+
+pub fn part1(state: &State) -> anyhow::Result<usize> {
+    let mut labels = BTreeSet::new();
+    for (start, edges) in state.adj.iter() {
+        labels.insert(start);
+        for v in edges.iter() {
+            labels.insert(v);
+        }
+    }
+    let ids: BTreeMap<&str, usize> =
+        BTreeMap::from_iter(labels.iter().enumerate().map(|(i, v)| (**v, i)));
+
+    let mut edges = Vec::new();
+    for (start, dests) in state.adj.iter() {
+        let u = ids.get(start).copied().unwrap();
+        for e in dests.iter() {
+            let v = ids.get(e).copied().unwrap();
+            edges.push(Edge { u: u, v: v });
+        }
+    }
+
+    // karger_min_cut finds multiple solutions.
+    // use the first, that removes 3 edges...
+    let (n1, n2): (usize, usize) = (0..)
+        .map(|_| karger_min_cut(&edges, ids.len()))
+        .find(|(_n1, _n2, count)| *count == 3)
+        .map(|(n1, n2, _count)| (n1, n2))
+        .unwrap();
+
+    Ok(n1 * n2)
+}
+
+#[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Clone, Copy)]
+struct Edge {
+    u: usize,
+    v: usize,
+}
+
+fn karger_min_cut(edges: &[Edge], num_vertices: usize) -> (usize, usize, usize) {
+    let mut subsets = UnionFind::new(num_vertices);
+    let mut rng = thread_rng();
+
+    while subsets.count() > 2 {
+        let edge = edges.choose(&mut rng).unwrap();
+        subsets.union(edge.u, edge.v);
+    }
+
+    // calculate the sizes of the two groups
+    let values: Vec<usize> = subsets.sizes().collect();
+
+    // calculate the number of edges to be removed
+    let count = edges.iter().filter(|e| !subsets.same(e.u, e.v)).count();
+
+    (values[0], values[1], count)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<State<'_>> {
+    Ok(parse_error::nom(input, parse_state)?)
+}
+
+fn parse_state(input: &str) -> IResult<&str, State> {
+    let (input, edge_list) = separated_list0(line_ending, parse_edge)(input)?;
+    let adj = BTreeMap::from_iter(edge_list);
+    Ok((input, State { adj }))
+}
+
+fn parse_edge(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    let (input, start) = alpha1(input)?;
+    let (input, _) = terminated(tag(":"), space0)(input)?;
+    let (input, edges) = separated_list0(space1, alpha1)(input)?;
+
+    Ok((input, (start, edges)))
+}
+
+/// The wiring diagram, with every connection drawn once.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let state = parse_error::nom(input, parse_state)?;
+    let mut graph = Graph::undirected();
+    for (v, edges) in state.adj.iter() {
+        for e in edges.iter() {
+            graph.edge(v, e);
+        }
+    }
+    Ok(graph)
+}
+
+#[derive(Debug)]
+pub struct State<'a> {
+    adj: BTreeMap<&'a str, Vec<&'a str>>,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+    const HAS_PART2: bool = false;
+
+    type Input<'a> = State<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 54;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph(INPUT)?;
+        assert_eq!(graph.node_count(), 15);
+        assert_eq!(graph.edge_count(), 33);
+        assert!(graph.to_mermaid().contains(" --- "));
+        Ok(())
+    }
+}
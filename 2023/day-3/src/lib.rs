@@ -0,0 +1,230 @@
+use aoc_core::Solution;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use grid::{Grid, Pos};
+use itertools::Itertools;
+
+pub fn part1(schematic: &Schematic) -> anyhow::Result<i32> {
+    let numbers = schematic.scan_numbers();
+    let result = numbers.iter().sum();
+    Ok(result)
+}
+
+pub fn part2(schematic: &Schematic) -> anyhow::Result<i32> {
+    let numbers = schematic.scan_numbers_and_positions();
+    let mut stars_to_numbers: BTreeMap<Point, Vec<i32>> = BTreeMap::new();
+    for np in numbers.into_iter() {
+        for pos in np.positions.into_iter() {
+            let e = stars_to_numbers.entry(pos).or_default();
+            e.push(np.number);
+        }
+    }
+    let result = stars_to_numbers
+        .into_iter()
+        .filter(|(_, numbers)| numbers.len() == 2)
+        .map(|(_, numbers)| numbers.into_iter().product::<i32>())
+        .sum();
+    Ok(result)
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+}
+
+impl Schematic {
+    fn new(input: &str) -> anyhow::Result<Schematic> {
+        Ok(Schematic {
+            grid: input.parse()?,
+        })
+    }
+
+    fn scan_numbers(&self) -> Vec<i32> {
+        (0..self.grid.width())
+            .cartesian_product(0..self.grid.height())
+            .filter_map(|(row, col)| self.scan_number(row as i32, col as i32))
+            .collect()
+    }
+
+    fn scan_numbers_and_positions(&self) -> Vec<NumberAndPositions> {
+        (0..self.grid.width())
+            .cartesian_product(0..self.grid.height())
+            .filter_map(|(row, col)| self.scan_number_and_positions(row as i32, col as i32))
+            .collect()
+    }
+
+    fn scan_number(&self, row: i32, col: i32) -> Option<i32> {
+        if !self.is_start_of_number(row, col) {
+            return None;
+        }
+
+        let mut result = 0;
+        let mut symbol_found = false;
+        let mut c = col;
+
+        while self.is_digit(row, c) {
+            result *= 10;
+            result += (self.get(row, c) as i32) - '0' as i32;
+            symbol_found |= self.has_adjacent_symbol(row, c);
+            c += 1;
+        }
+
+        if symbol_found {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    fn scan_number_and_positions(&self, row: i32, col: i32) -> Option<NumberAndPositions> {
+        if !self.is_start_of_number(row, col) {
+            return None;
+        }
+
+        let mut result = 0;
+        let mut positions = BTreeSet::new();
+        let mut c = col;
+
+        while self.is_digit(row, c) {
+            result *= 10;
+            result += (self.get(row, c) as i32) - '0' as i32;
+            positions = positions
+                .union(&self.adjacent_stars(row, c))
+                .copied()
+                .collect();
+            c += 1;
+        }
+
+        if positions.is_empty() {
+            None
+        } else {
+            Some(NumberAndPositions {
+                number: result,
+                positions,
+            })
+        }
+    }
+
+    fn get(&self, row: i32, col: i32) -> char {
+        let pos = Pos::new(row as isize, col as isize);
+        self.grid.get(pos).copied().unwrap_or('.')
+    }
+
+    fn has_adjacent_symbol(&self, row: i32, col: i32) -> bool {
+        DELTAS
+            .iter()
+            .any(|(dr, dc)| self.is_symbol(row + dr, col + dc))
+    }
+
+    fn adjacent_stars(&self, row: i32, col: i32) -> BTreeSet<Point> {
+        let mut result = BTreeSet::new();
+        DELTAS.iter().for_each(|(dr, dc)| {
+            if self.is_star(row + dr, col + dc) {
+                result.insert(Point {
+                    row: row + *dr,
+                    col: col + *dc,
+                });
+            }
+        });
+        result
+    }
+
+    fn is_digit(&self, row: i32, col: i32) -> bool {
+        self.get(row, col).is_ascii_digit()
+    }
+
+    fn is_symbol(&self, row: i32, col: i32) -> bool {
+        let c = self.get(row, col);
+        !(c == '.' || c.is_ascii_digit())
+    }
+
+    fn is_star(&self, row: i32, col: i32) -> bool {
+        let c = self.get(row, col);
+        c == '*'
+    }
+
+    fn is_start_of_number(&self, row: i32, col: i32) -> bool {
+        self.is_digit(row, col) && !self.is_digit(row, col - 1)
+    }
+}
+
+static DELTAS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug)]
+struct NumberAndPositions {
+    number: i32,
+    positions: BTreeSet<Point>,
+}
+
+#[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Copy, Clone)]
+struct Point {
+    row: i32,
+    col: i32,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Schematic> {
+    Schematic::new(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 4361;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 467835;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
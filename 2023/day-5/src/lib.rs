@@ -0,0 +1,209 @@
+use aoc_core::Solution;
+use interval_set::{Interval, IntervalSet, RangeMap};
+use std::fmt::Display;
+
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{alpha1, line_ending, space1};
+use nom::multi::{many1, separated_list0};
+use nom::sequence::{delimited, preceded};
+use nom::IResult;
+
+pub fn part1(category_mappings: &Almanach) -> anyhow::Result<i64> {
+    let result = category_mappings
+        .seeds
+        .iter()
+        .map(|value| category_mappings.location_value(*value))
+        .min()
+        .unwrap_or_default();
+    Ok(result)
+}
+
+pub fn part2(category_mappings: &Almanach) -> anyhow::Result<i64> {
+    let seed_ranges: IntervalSet<i64> = category_mappings
+        .seeds
+        .chunks(2)
+        .map(|chunk| Interval::new(chunk[0], chunk[0] + chunk[1]))
+        .collect();
+    let locations = category_mappings
+        .category_mappings
+        .iter()
+        .fold(seed_ranges, |ranges, mapping| {
+            mapping.mappings.map_set(&ranges)
+        });
+    Ok(locations.min().unwrap_or_default())
+}
+
+#[derive(Debug)]
+pub struct Almanach {
+    seeds: Vec<i64>,
+    category_mappings: Vec<CategoryMapping>,
+}
+
+impl Almanach {
+    fn location_value(&self, value: i64) -> i64 {
+        let result = self
+            .category_mappings
+            .iter()
+            .fold(value, |v, m| m.range_value(v));
+        result
+    }
+}
+
+#[derive(Debug)]
+struct CategoryMapping {
+    source: String,
+    destination: String,
+    mappings: RangeMap<i64>,
+}
+
+impl CategoryMapping {
+    fn range_value(&self, value: i64) -> i64 {
+        self.mappings.get(value)
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Almanach> {
+    let category_mappings = parse_error::nom(input, seed_category_mappings)?;
+    Ok(category_mappings)
+}
+
+fn seed_category_mappings(input: &str) -> IResult<&str, Almanach> {
+    let (input, seeds) = delimited(
+        preceded(tag("seeds:"), space1),
+        separated_list0(space1, complete::i64),
+        many1(line_ending),
+    )(input)?;
+    let (input, category_mappings) = separated_list0(many1(line_ending), category_mapping)(input)?;
+    Ok((
+        input,
+        Almanach {
+            seeds,
+            category_mappings,
+        },
+    ))
+}
+
+fn category_mapping(input: &str) -> IResult<&str, CategoryMapping> {
+    let (input, source) = alpha1(input)?;
+    let (input, _) = tag("-to-")(input)?;
+    let (input, destination) = alpha1(input)?;
+    let (input, _) = preceded(space1, tag("map:"))(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, mappings) = separated_list0(line_ending, mapping)(input)?;
+
+    Ok((
+        input,
+        CategoryMapping {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            mappings: mappings.into_iter().collect(),
+        },
+    ))
+}
+
+/// A source interval and the start of the destination it maps to.
+fn mapping(input: &str) -> IResult<&str, (Interval<i64>, i64)> {
+    let (input, destination_range_start) = complete::i64(input)?;
+    let (input, _) = space1(input)?;
+    let (input, source_range_start) = complete::i64(input)?;
+    let (input, _) = space1(input)?;
+    let (input, range_length) = complete::i64(input)?;
+
+    Ok((
+        input,
+        (
+            Interval::new(source_range_start, source_range_start + range_length),
+            destination_range_start,
+        ),
+    ))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanach;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        let expected = 35;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        let expected = 46;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn range_value_works() {
+        let m = CategoryMapping {
+            source: "seed".to_string(),
+            destination: "soil".to_string(),
+            mappings: [(Interval::new(98, 100), 50)].into_iter().collect(),
+        };
+        assert_eq!(m.range_value(98), 50);
+        assert_eq!(m.range_value(99), 51);
+        assert_eq!(m.range_value(100), 100);
+        assert_eq!(m.range_value(97), 97);
+    }
+}
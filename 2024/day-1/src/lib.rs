@@ -0,0 +1,101 @@
+use aoc_core::Solution;
+use nom::character::complete;
+use nom::character::complete::{multispace0, newline, space1};
+use nom::multi::separated_list0;
+use nom::sequence::{separated_pair, terminated};
+use nom::IResult;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+pub fn part1(lists: &Lists) -> anyhow::Result<i64> {
+    let (mut list1, mut list2) = lists.clone();
+    list1.sort();
+    list2.sort();
+    Ok(list1
+        .iter()
+        .zip(list2.iter())
+        .map(|(i1, i2)| (i2 - i1).abs())
+        .sum())
+}
+
+pub fn part2(lists: &Lists) -> anyhow::Result<i64> {
+    let (list1, list2) = lists;
+    let hist2: BTreeMap<i64, i64> = list2.iter().fold(BTreeMap::new(), |mut m, n| {
+        let e = m.entry(*n).or_insert(0);
+        *e += 1;
+        m
+    });
+    let sum1 = list1
+        .iter()
+        .map(|n| {
+            let count = hist2.get(n).unwrap_or(&0);
+            *n * count
+        })
+        .sum();
+
+    Ok(sum1)
+}
+
+pub type Lists = (Vec<i64>, Vec<i64>);
+
+pub fn parse(input: &str) -> anyhow::Result<Lists> {
+    let input = parse_error::nom(input, terminated(parse_lists, multispace0))?;
+    Ok(input)
+}
+
+fn parse_lists(input: &str) -> IResult<&str, Lists> {
+    let (rest, pairs) = separated_list0(newline, parse_pair)(input)?;
+    let mut list1 = Vec::with_capacity(pairs.len());
+    let mut list2 = Vec::with_capacity(pairs.len());
+
+    for (s1, s2) in pairs.into_iter() {
+        list1.push(s1);
+        list2.push(s2);
+    }
+
+    Ok((rest, (list1, list2)))
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (i64, i64)> {
+    separated_pair(complete::i64, space1, complete::i64)(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Lists;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let input = r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#;
+        let result = part2(&parse(input)?)?;
+        assert_eq!(31, result);
+
+        Ok(())
+    }
+}
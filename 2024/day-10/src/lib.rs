@@ -0,0 +1,151 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+
+pub fn part1(grid: &Grid<i32>) -> Result<usize, anyhow::Error> {
+    let result = walk_grid(grid);
+    Ok(result)
+}
+
+pub fn part2(grid: &Grid<i32>) -> Result<usize, anyhow::Error> {
+    let result = walk_grid2(grid);
+    Ok(result)
+}
+
+fn walk_grid(grid: &Grid<i32>) -> usize {
+    grid.positions()
+        .filter(|&pos| grid[pos] == 0)
+        .map(|start| walk_grid_from_start(grid, start))
+        .sum()
+}
+
+fn walk_grid2(grid: &Grid<i32>) -> usize {
+    grid.positions()
+        .filter(|&pos| grid[pos] == 0)
+        .map(|start| walk_grid_from_start2(grid, start))
+        .sum()
+}
+
+fn walk_grid_from_start(grid: &Grid<i32>, start: Pos) -> usize {
+    let mut q = VecDeque::from([(start, 0)]);
+    let mut visited: BTreeSet<Pos> = BTreeSet::new();
+    let mut result = 0;
+    while let Some((pos, height)) = q.pop_front() {
+        if !visited.insert(pos) {
+            continue;
+        }
+        if height == 9 {
+            result += 1;
+        }
+        for next in grid.neighbours4(pos) {
+            if grid[next] == height + 1 {
+                q.push_back((next, height + 1));
+            }
+        }
+    }
+    result
+}
+
+///
+/// Solution as suggested by hyperneutrino here:
+///
+/// - https://www.youtube.com/watch?v=layyhtQQuM0
+/// - https:///github.com/hyperneutrino/advent-of-code/blob/main/2024/day10p2.py
+///
+/// should be much more efficient (`O(|V|+|E|)`?) than my original solution, since the number
+/// of paths grows exponentially. Does not really make a difference for the current problem.
+///
+fn walk_grid_from_start2(grid: &Grid<i32>, start: Pos) -> usize {
+    let mut q = VecDeque::from([(start, 0)]);
+    let mut seen: BTreeMap<Pos, usize> = BTreeMap::from([(start, 1)]);
+    let mut result = 0;
+
+    while let Some((pos, height)) = q.pop_front() {
+        let count = *seen.get(&pos).unwrap();
+        if height == 9 {
+            result += count;
+            continue;
+        }
+        for next in grid.neighbours4(pos) {
+            let next_height = grid[next];
+            if height + 1 != next_height {
+                continue;
+            }
+            let entry = seen.entry(next).or_insert(0);
+            if *entry > 0 {
+                //
+                // next position has already been visited
+                // that means, we can add the number of paths to the current position
+                // position
+                //
+                *entry += count;
+                continue;
+            }
+            // not been visited, yet => the number of paths to the next position
+            // is the number of paths to the current position
+            //
+            *entry = count;
+            q.push_back((next, next_height));
+        }
+    }
+    result
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<i32>> {
+    Grid::parse_with(input, |c| match c {
+        '.' => Ok(-1),
+        _ => c
+            .to_digit(10)
+            .map(|height| height as i32)
+            .ok_or_else(|| anyhow!("expected a height")),
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Grid<i32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT2: &str = r#"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"#;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(INPUT2).unwrap()).unwrap();
+        assert_eq!(result, 36);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(INPUT2).unwrap()).unwrap();
+        assert_eq!(result, 81);
+    }
+}
@@ -0,0 +1,225 @@
+use aoc_core::Solution;
+use grid::{Dir, Grid, Pos};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Display;
+
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<usize> {
+    let mut visited = BTreeSet::new();
+    let mut total_price = 0;
+    for pos in grid.positions() {
+        if !visited.contains(&pos) {
+            let (area, perimeter) = area_and_perimiter(grid, pos, &mut visited);
+            // println!(
+            //     "type={}, area={}, perimeter={}, price={}",
+            //     grid[pos],
+            //     area,
+            //     perimeter,
+            //     area * perimeter
+            // );
+            total_price += area * perimeter;
+        }
+    }
+    Ok(total_price)
+}
+
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<usize> {
+    let mut visited = BTreeSet::new();
+    let mut total_price = 0;
+    for pos in grid.positions() {
+        if !visited.contains(&pos) {
+            let (area, perimeter) = area_and_perimiter2(grid, pos, &mut visited);
+            // println!(
+            //     "type={}, area={}, perimeter={}, price={}",
+            //     grid[pos],
+            //     area,
+            //     perimeter,
+            //     area * perimeter
+            // );
+            total_price += area * perimeter;
+        }
+    }
+    Ok(total_price)
+}
+
+/// A cell and the side of it which lies on the region's border.
+type PositionAndSide = (Pos, Dir);
+
+fn plant(grid: &Grid<char>, pos: Pos) -> char {
+    grid.get(pos).copied().unwrap_or('.')
+}
+
+fn area_and_perimiter(
+    grid: &Grid<char>,
+    start: Pos,
+    visited: &mut BTreeSet<Pos>,
+) -> (usize, usize) {
+    let plant_type = plant(grid, start);
+    let mut q = VecDeque::from([start]);
+    let mut area = 0;
+    let mut perimeter = 0;
+    while let Some(pos) = q.pop_front() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        visited.insert(pos);
+        area += 1;
+        for next in pos.neighbours4() {
+            if plant(grid, next) == plant_type {
+                q.push_back(next);
+            } else {
+                perimeter += 1;
+            }
+        }
+    }
+    (area, perimeter)
+}
+
+fn area_and_perimiter2(
+    grid: &Grid<char>,
+    start: Pos,
+    visited: &mut BTreeSet<Pos>,
+) -> (usize, usize) {
+    let plant_type = plant(grid, start);
+    let mut q = VecDeque::from([start]);
+    let mut area = 0;
+    let mut perimeter = 0;
+    let mut visited_edges = BTreeSet::new();
+
+    while let Some(pos) = q.pop_front() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        visited.insert(pos);
+        area += 1;
+        for next in pos.neighbours4() {
+            if plant(grid, next) == plant_type {
+                q.push_back(next);
+            }
+        }
+        for side in Dir::ALL {
+            perimeter += visit_edge(grid, pos, side, &mut visited_edges);
+        }
+    }
+    (area, perimeter)
+}
+
+fn visit_edge(
+    grid: &Grid<char>,
+    start: Pos,
+    side: Dir,
+    visited_edges: &mut BTreeSet<PositionAndSide>,
+) -> usize {
+    let plant_type = plant(grid, start);
+
+    if plant(grid, start + side) == plant_type {
+        return 0;
+    }
+
+    if visited_edges.contains(&(start, side)) {
+        return 0;
+    };
+
+    // the edge runs perpendicular to the side it is on
+    let along = side.turn_right().delta();
+
+    let mut pos = start;
+    while plant(grid, pos) == plant_type && plant(grid, pos + side) != plant_type {
+        visited_edges.insert((pos, side));
+        pos += along;
+    }
+
+    let mut pos = start;
+    while plant(grid, pos) == plant_type && plant(grid, pos + side) != plant_type {
+        visited_edges.insert((pos, side));
+        pos -= along;
+    }
+
+    1
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    input.parse()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE"#;
+
+    #[test]
+    fn test_area_and_perimiter() -> anyhow::Result<()> {
+        let grid = parse(INPUT)?;
+        let mut visited = BTreeSet::new();
+        let start = Pos::ORIGIN;
+        let (area, perimeter) = area_and_perimiter(&grid, start, &mut visited);
+        println!(
+            "type={}, area={}, perimeter={}, price={}",
+            grid[start],
+            area,
+            perimeter,
+            area * perimeter
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_area_and_perimiter2() -> anyhow::Result<()> {
+        let grid = parse(INPUT)?;
+        let mut visited = BTreeSet::new();
+        let start = Pos::ORIGIN;
+        let (area, perimeter) = area_and_perimiter2(&grid, start, &mut visited);
+        println!(
+            "type={}, area={}, perimeter={}, price={}",
+            grid[start],
+            area,
+            perimeter,
+            area * perimeter
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 1930);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 1206);
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+aoc_bench::main!(y2024_day_14::Puzzle);
@@ -0,0 +1,248 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, space1};
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::fmt::Display;
+use visualize::{Frame, Recorder};
+
+type Point = (i64, i64);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Robot {
+    p: Point,
+    v: Point,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bathroom {
+    width: i64,
+    height: i64,
+    robots: Vec<Robot>,
+}
+
+pub fn part1(bathroom: &Bathroom) -> anyhow::Result<i64> {
+    let steps = 100;
+    let mut quadrants = [0; 4];
+
+    for robot in bathroom.robots.iter() {
+        let (x, y) = robot.p;
+        let (dx, dy) = robot.v;
+        let np = (
+            (x + dx * steps).rem_euclid(bathroom.width),
+            (y + dy * steps).rem_euclid(bathroom.height),
+        );
+        let quadrant_attempt = point_quadrant(np, bathroom.width, bathroom.height);
+        if let Some(quadrant) = quadrant_attempt {
+            quadrants[quadrant as usize] += 1;
+        }
+    }
+
+    Ok(quadrants.iter().product::<i64>())
+}
+
+pub fn part2(bathroom: &Bathroom) -> anyhow::Result<i64> {
+    let steps = bathroom
+        .christmas_tree()
+        .ok_or_else(|| anyhow!("No solution found"))?;
+    Ok(steps)
+}
+
+/// Animates the 50 seconds up to the Christmas tree of part 2.
+pub fn animate(input: &str, width: i64, height: i64) -> anyhow::Result<Recorder> {
+    let bathroom = parse(input, width, height)?;
+    let tree = bathroom
+        .christmas_tree()
+        .ok_or_else(|| anyhow!("No solution found"))?;
+    let mut recorder = Recorder::new().scale(4).delay(8);
+    for steps in (tree - 49).max(0)..=tree {
+        let grid = bathroom.occupied(steps);
+        recorder.push(Frame::from_fn(width as usize, height as usize, |x, y| {
+            if grid[y * width as usize + x] {
+                [40, 180, 60]
+            } else {
+                [10, 20, 40]
+            }
+        }));
+    }
+    Ok(recorder)
+}
+
+impl Bathroom {
+    /// The tiles with at least one robot after `steps` seconds, row by row.
+    fn occupied(&self, steps: i64) -> Vec<bool> {
+        let mut grid = vec![false; (self.width * self.height) as usize];
+        for robot in self.robots.iter() {
+            let (x, y) = robot.p;
+            let (dx, dy) = robot.v;
+            let (x, y) = (
+                (x + dx * steps).rem_euclid(self.width),
+                (y + dy * steps).rem_euclid(self.height),
+            );
+            grid[(y * self.width + x) as usize] = true;
+        }
+        grid
+    }
+
+    /// The first second at which the robots draw a Christmas tree.
+    fn christmas_tree(&self) -> Option<i64> {
+        let max_steps = self.width * self.height;
+
+        (1..=max_steps).find(|&steps| {
+            // heuristic: If there are more than 20 pixels set in a row
+            // this must be the Christmas tree
+            let mut count = 0;
+            self.occupied(steps).iter().any(|&value| {
+                count = if value { count + 1 } else { 0 };
+                count > 20
+            })
+        })
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Bathroom;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input, 101, 103)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+/// Parses the robots of a `width` x `height` bathroom.
+pub fn parse(input: &str, width: i64, height: i64) -> anyhow::Result<Bathroom> {
+    Ok(parse_error::nom(input, |input| {
+        parse_bathroom(input, width, height)
+    })?)
+}
+
+fn parse_bathroom(input: &str, width: i64, height: i64) -> IResult<&str, Bathroom> {
+    let (rest, robots) = separated_list0(line_ending, parse_robot)(input)?;
+    Ok((
+        rest,
+        Bathroom {
+            width,
+            height,
+            robots,
+        },
+    ))
+}
+
+fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    let (rest, _) = tag("p=")(input)?;
+    let (rest, p) = parse_point(rest)?;
+    let (rest, _) = space1(rest)?;
+    let (rest, _) = tag("v=")(rest)?;
+    let (rest, v) = parse_point(rest)?;
+    Ok((rest, Robot { p, v }))
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    separated_pair(complete::i64, complete::char(','), complete::i64)(input)
+}
+
+fn point_quadrant(p: Point, width: i64, height: i64) -> Option<i64> {
+    let (x, y) = p;
+    let x = coordinate_section(x, width);
+    let y = coordinate_section(y, height);
+    match (x, y) {
+        (Some(0), Some(0)) => Some(0),
+        (Some(0), Some(1)) => Some(1),
+        (Some(1), Some(0)) => Some(2),
+        (Some(1), Some(1)) => Some(3),
+        _ => None,
+    }
+}
+
+fn coordinate_section(c: i64, length: i64) -> Option<i64> {
+    assert!(length > 0);
+    let m = length / 2;
+    if c < m {
+        Some(0)
+    } else if c > m {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::combinator::all_consuming;
+
+    const INPUT: &str = r#"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3"#;
+
+    #[test]
+    fn test_parse_bathroom() -> anyhow::Result<()> {
+        let bathroom =
+            parse_error::nom(INPUT, all_consuming(|input| parse_bathroom(input, 11, 7)))?;
+        println!("{bathroom:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT, 11, 7)?)?;
+        assert_eq!(result, 12);
+        Ok(())
+    }
+
+    #[test]
+    fn animate_works() -> anyhow::Result<()> {
+        // 21 robots that line up after one second
+        let input = (0..21)
+            .map(|x| format!("p={},1 v=1,-1", x))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let recorder = animate(&input, 25, 3)?;
+        assert_eq!(recorder.len(), 2);
+        let tree = &recorder.frames()[1];
+        assert_eq!((tree.width(), tree.height()), (25, 3));
+        assert_eq!(tree.get(21, 0), [40, 180, 60]);
+        assert_eq!(tree.get(0, 0), [10, 20, 40]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coordinate_section() {
+        assert_eq!(Some(0), coordinate_section(0, 7));
+        assert_eq!(Some(0), coordinate_section(1, 7));
+        assert_eq!(Some(0), coordinate_section(2, 7));
+        assert!(coordinate_section(3, 7).is_none());
+        assert_eq!(Some(1), coordinate_section(4, 7));
+        assert_eq!(Some(1), coordinate_section(5, 7));
+        assert_eq!(Some(1), coordinate_section(6, 7));
+
+        assert!(coordinate_section(5, 11).is_none());
+        assert_eq!(Some(0), coordinate_section(1, 11));
+        assert_eq!(Some(1), coordinate_section(6, 11));
+        assert_eq!(Some(1), coordinate_section(10, 11));
+    }
+}
@@ -0,0 +1,301 @@
+use aoc_core::Solution;
+use grid::{Dir, Grid, Pos};
+use nom::character::complete::{line_ending, multispace0, one_of};
+use nom::combinator::{map, map_res};
+use nom::multi::{many0, many1, separated_list0};
+use nom::sequence::preceded;
+use nom::IResult;
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+use visualize::{Frame, Palette, Recorder, Simulation};
+
+pub fn part1(warehouse: &Warehouse) -> anyhow::Result<usize> {
+    let mut warehouse = warehouse.clone();
+
+    for &command in warehouse.commands.iter() {
+        move_robot(command, &mut warehouse.map);
+    }
+    Ok(gps_sum(&warehouse.map.cells, 'O'))
+}
+
+pub fn part2(warehouse: &Warehouse) -> anyhow::Result<usize> {
+    let mut map = warehouse.map.convert();
+
+    for &command in warehouse.commands.iter() {
+        move_robot2(command, &mut map);
+    }
+    Ok(gps_sum(&map.cells, '['))
+}
+
+/// Animates part 2, one frame per 10 moves of the robot.
+pub fn animate(input: &str) -> anyhow::Result<Recorder> {
+    let mut simulation = simulate(input)?;
+    Ok(Recorder::new()
+        .scale(4)
+        .every(10)
+        .delay(2)
+        .record(&mut *simulation))
+}
+
+/// Part 2 one move of the robot at a time, following the robot.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let warehouse = parse_error::nom(input, parse_warehouse)?;
+    Ok(Box::new(Robot {
+        map: warehouse.map.convert(),
+        commands: warehouse.commands,
+        next: 0,
+        palette: Palette::new([30, 30, 30])
+            .with('#', [110, 110, 120])
+            .with('[', [200, 140, 60])
+            .with(']', [170, 110, 40])
+            .with('@', [80, 200, 80]),
+    }))
+}
+
+struct Robot {
+    map: Map,
+    commands: Vec<char>,
+    next: usize,
+    palette: Palette,
+}
+
+impl Simulation for Robot {
+    fn step(&mut self) -> bool {
+        let Some(&command) = self.commands.get(self.next) else {
+            return false;
+        };
+        move_robot2(command, &mut self.map);
+        self.next += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let robot = self.map.robot;
+        Frame::from_text(&self.map.to_string(), &self.palette)
+            .with_focus(robot.col as usize, robot.row as usize)
+    }
+}
+
+fn gps_sum(cells: &Grid<char>, box_cell: char) -> usize {
+    cells
+        .iter()
+        .filter(|(_, c)| **c == box_cell)
+        .map(|(pos, _)| 100 * pos.row as usize + pos.col as usize)
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Warehouse {
+    map: Map,
+    commands: Vec<char>,
+}
+
+fn move_robot2(command: char, map: &mut Map) {
+    if perform_move2(command, map.robot, map) {
+        map.robot += command_direction(command);
+    }
+}
+
+fn perform_move2(command: char, start: Pos, map: &mut Map) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut q = VecDeque::from([start]);
+    let mut moves = Vec::new();
+    let dir = command_direction(command);
+
+    while let Some(pos) = q.pop_front() {
+        if !visited.insert(pos) {
+            continue;
+        }
+        let c = map.cells[pos];
+        if c == '.' {
+            continue;
+        }
+        if c == '#' {
+            return false;
+        }
+        if c == '[' && dir.is_vertical() {
+            let other = pos + Dir::Right;
+            moves.push((other, '.'));
+            q.push_back(other);
+        } else if c == ']' && dir.is_vertical() {
+            let other = pos + Dir::Left;
+            moves.push((other, '.'));
+            q.push_back(other);
+        }
+        moves.push((pos + dir, c));
+        q.push_back(pos + dir);
+    }
+    for &(pos, c) in moves.iter().rev() {
+        map.cells[pos] = c;
+    }
+    map.cells[start] = '.';
+    true
+}
+
+fn move_robot(command: char, map: &mut Map) -> bool {
+    if perform_move(command, map.robot, map) {
+        map.cells[map.robot] = '.';
+        map.robot += command_direction(command);
+        return true;
+    }
+    false
+}
+
+fn perform_move(command: char, pos: Pos, map: &mut Map) -> bool {
+    let cell = map.cells[pos];
+    if cell == '#' {
+        return false;
+    }
+    if cell == '.' {
+        return true;
+    }
+    let next = pos + command_direction(command);
+    let result = perform_move(command, next, map);
+    if result {
+        map.cells[next] = cell;
+    }
+    result
+}
+
+fn command_direction(command: char) -> Dir {
+    Dir::from_char(command).unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Map {
+    robot: Pos,
+    cells: Grid<char>,
+}
+
+impl Map {
+    fn new(cells: Grid<char>) -> Map {
+        let robot = cells.find(|c| *c == '@').unwrap_or_default();
+        Map { robot, cells }
+    }
+
+    /// The map of the second warehouse, in which everything except the robot is twice as wide.
+    fn convert(&self) -> Map {
+        let rows = self.cells.rows().map(|row| {
+            row.iter()
+                .flat_map(|col| match col {
+                    'O' => ['[', ']'],
+                    '.' => ['.', '.'],
+                    '@' => ['@', '.'],
+                    '#' => ['#', '#'],
+                    _ => unreachable!(),
+                })
+                .collect()
+        });
+        Map::new(Grid::from_rows(rows).unwrap())
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Warehouse> {
+    Ok(parse_error::nom(input, parse_warehouse)?)
+}
+
+fn parse_warehouse(input: &str) -> IResult<&str, Warehouse> {
+    let (rest, map) = parse_map(input)?;
+    let (rest, _) = many1(line_ending)(rest)?;
+    let (rest, commands) = parse_commands(rest)?;
+    let (rest, _) = multispace0(rest)?;
+    Ok((rest, Warehouse { map, commands }))
+}
+
+fn parse_map(input: &str) -> IResult<&str, Map> {
+    map(
+        map_res(
+            separated_list0(line_ending, parse_grid_line),
+            Grid::from_rows,
+        ),
+        Map::new,
+    )(input)
+}
+
+fn parse_grid_line(input: &str) -> IResult<&str, Vec<char>> {
+    many1(one_of("#.O@"))(input)
+}
+
+fn parse_commands(input: &str) -> IResult<&str, Vec<char>> {
+    many0(preceded(many0(line_ending), one_of("v<^>")))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Warehouse;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 10092);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 9021);
+        Ok(())
+    }
+
+    #[test]
+    fn animate_works() -> anyhow::Result<()> {
+        let recorder = animate(INPUT)?;
+        // every 10th of the 701 states, the last one included
+        assert_eq!(recorder.len(), 71);
+        let last = recorder.frames().last().unwrap();
+        assert_eq!((last.width(), last.height()), (20, 10));
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Dir, Grid, Pos};
+use nom::character::complete::{line_ending, one_of};
+use nom::combinator::map_res;
+use nom::multi::{many1, separated_list0};
+use nom::IResult;
+use search::{all_shortest_paths, dijkstra};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt;
+
+pub fn part1(maze: &Maze) -> anyhow::Result<usize> {
+    // println!("{maze}");
+    let result_attempt = shortest_path(maze);
+    // println!("{result_attempt:?}");
+    let Some(cost) = result_attempt else {
+        return Err(anyhow!("no path found"));
+    };
+    Ok(cost)
+}
+
+pub fn part2(maze: &Maze) -> anyhow::Result<usize> {
+    let (visited, _cost) = extended_shortest_path(maze);
+    // println!("{visited:?} {_cost}");
+    // let mut maze = maze.clone();
+    // for (pos, cell) in maze.cells.iter_mut() {
+    //     if visited.contains(&pos) {
+    //         *cell = 'O';
+    //     }
+    // }
+    // println!("{maze}");
+    Ok(visited.len())
+}
+
+fn shortest_path(maze: &Maze) -> Option<usize> {
+    dijkstra(
+        &(maze.start_pos, Dir::Right),
+        |position| successors(maze, position),
+        |&(pos, _)| pos == maze.end_pos,
+    )
+    .map(|(_path, cost)| cost)
+}
+
+/// All tiles on any of the best paths through the maze, and the score of those paths.
+fn extended_shortest_path(maze: &Maze) -> (HashSet<Pos>, usize) {
+    let Some(paths) = all_shortest_paths(
+        &(maze.start_pos, Dir::Right),
+        |position| successors(maze, position),
+        |&(pos, _)| pos == maze.end_pos,
+    ) else {
+        return (HashSet::new(), usize::MAX);
+    };
+    let visited = paths.nodes().into_iter().map(|(pos, _)| pos).collect();
+    (visited, paths.cost())
+}
+
+fn turn_cost(dir1: &Dir, dir2: &Dir) -> Option<usize> {
+    if dir1 == dir2 {
+        return Some(0);
+    }
+    if *dir2 == dir1.opposite() {
+        return None;
+    }
+    Some(1000)
+}
+
+fn successors(maze: &Maze, pos: &PosDir) -> Vec<(PosDir, usize)> {
+    let mut result: Vec<(PosDir, usize)> = Vec::with_capacity(3);
+    let (pos, dir) = *pos;
+    for next_dir in Dir::ALL {
+        if let Some(tc) = turn_cost(&dir, &next_dir) {
+            let next = pos + next_dir;
+            if maze.cells[next] != '#' {
+                let next_p = ((next, next_dir), tc + 1);
+                // println!("{pos:?} -> {next_p:?} {}", maze.cells[next]);
+                result.push(next_p)
+            }
+        }
+    }
+    result
+}
+
+type PosDir = (Pos, Dir);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    cells: Grid<char>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl fmt::Display for Maze {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Maze> {
+    Ok(parse_error::nom(input, parse_maze)?)
+}
+
+fn parse_maze(input: &str) -> IResult<&str, Maze> {
+    let (rest, cells) = map_res(
+        separated_list0(line_ending, parse_grid_line),
+        Grid::from_rows,
+    )(input)?;
+    let start_pos = cells.find(|c| *c == 'S').unwrap_or_default();
+    let end_pos = cells.find(|c| *c == 'E').unwrap_or_default();
+    Ok((
+        rest,
+        Maze {
+            cells,
+            start_pos,
+            end_pos,
+        },
+    ))
+}
+
+fn parse_grid_line(input: &str) -> IResult<&str, Vec<char>> {
+    many1(one_of(".#SE"))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Maze;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 7036);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 45);
+        Ok(())
+    }
+}
@@ -1,325 +1,18 @@
-use anyhow::{anyhow, Context};
-use nom::character::complete::{line_ending, one_of};
-use nom::multi::{many1, separated_list0};
-use nom::IResult;
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::fmt::Formatter;
-use std::{fmt, fs};
+use anyhow::Context;
+use std::fs;
+
+use y2024_day_16::{parse, part1, part2};
 
 fn main() -> anyhow::Result<()> {
     let filename = "day-16/input.txt";
     let content = fs::read_to_string(filename).context(format!("cannot load {filename}"))?;
 
-    let result = part1(&content)?;
+    let maze = parse(&content)?;
+    let result = part1(&maze)?;
     println!("{result}");
 
-    let result = part2(&content)?;
+    let result = part2(&maze)?;
     println!("{result}");
 
     Ok(())
 }
-
-fn part1(input: &str) -> anyhow::Result<usize> {
-    let (_, grid) = parse_grid(input).map_err(|e| anyhow!("{e}"))?;
-    // println!("{grid}");
-    let result_attempt = shortest_path(&grid);
-    // println!("{result_attempt:?}");
-    let Some(cost) = result_attempt else {
-        return Err(anyhow!("no path found"));
-    };
-    Ok(cost)
-}
-
-fn part2(input: &str) -> anyhow::Result<usize> {
-    let (_, grid) = parse_grid(input).map_err(|e| anyhow!("{e}"))?;
-    let (visited, _cost) = extended_shortest_path(&grid);
-    // println!("{visited:?} {_cost}");
-    // let mut grid = grid.clone();
-    // for (row_idx, row) in grid.cells.iter_mut().enumerate() {
-    //     for (col_idx, col) in row.iter_mut().enumerate() {
-    //         if visited.contains(&(row_idx, col_idx)) {
-    //             *col = 'O';
-    //         }
-    //     }
-    // }
-    // println!("{grid}");
-    Ok(visited.len())
-}
-
-fn shortest_path(grid: &Grid) -> Option<usize> {
-    let (sr, sc) = grid.start_pos;
-    let (er, ec) = grid.end_pos;
-    let mut q: BinaryHeap<State> = BinaryHeap::new();
-    q.push(State {
-        cost: 0,
-        position: (sr, sc, Direction::East),
-    });
-    let mut dist: HashMap<PosDir, usize> = HashMap::new();
-    dist.insert((sr, sc, Direction::East), 0);
-
-    while let Some(State { cost, position }) = q.pop() {
-        let (row, col, _) = position;
-        if row == er && col == ec {
-            return Some(cost);
-        }
-        let dist_cost = dist.entry(position).or_insert(usize::MAX);
-        if cost > *dist_cost {
-            continue;
-        }
-        if cost < *dist_cost {
-            *dist_cost = cost;
-        }
-        let edges = successors(grid, &position);
-        for (next_pos, next_cost) in edges {
-            let next_state = State {
-                cost: cost + next_cost,
-                position: next_pos,
-            };
-            q.push(next_state);
-        }
-    }
-    None
-}
-
-fn extended_shortest_path(grid: &Grid) -> (HashSet<Position>, usize) {
-    let (sr, sc) = grid.start_pos;
-    let (er, ec) = grid.end_pos;
-    let mut q: BinaryHeap<ExtendedState> = BinaryHeap::new();
-    q.push(ExtendedState {
-        cost: 0,
-        position: (sr, sc, Direction::East),
-        path: vec![(sr, sc, Direction::East)],
-    });
-    let mut dist: HashMap<PosDir, usize> = HashMap::new();
-    dist.insert((sr, sc, Direction::East), 0);
-    let mut visited: HashSet<Position> = HashSet::from([(sr, sc)]);
-    let mut min_cost = usize::MAX;
-
-    while let Some(ExtendedState {
-        cost,
-        position,
-        path,
-    }) = q.pop()
-    {
-        let (row, col, _) = position;
-        if row == er && col == ec && cost <= min_cost {
-            for (r, c, _) in path.iter() {
-                visited.insert((*r, *c));
-            }
-            min_cost = cost;
-        }
-        let dist_cost = dist.entry(position).or_insert(usize::MAX);
-        if cost > *dist_cost {
-            continue;
-        }
-        if cost < *dist_cost {
-            *dist_cost = cost;
-        }
-        let edges = successors(grid, &position);
-        for (edge_pos, edge_cost) in edges {
-            let next_cost = cost + edge_cost;
-            let next_path = path
-                .iter()
-                .copied()
-                .chain(std::iter::once(edge_pos))
-                .collect();
-
-            let next_state = ExtendedState {
-                cost: next_cost,
-                position: edge_pos,
-                path: next_path,
-            };
-
-            q.push(next_state);
-        }
-    }
-    (visited, min_cost)
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct ExtendedState {
-    cost: usize,
-    position: PosDir,
-    path: Vec<PosDir>,
-}
-
-impl Ord for ExtendedState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
-        // .then_with(|| self.position.cmp(&other.position))
-    }
-}
-
-impl PartialOrd for ExtendedState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct State {
-    cost: usize,
-    position: PosDir,
-}
-
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
-        // .then_with(|| self.position.cmp(&other.position))
-    }
-}
-
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn as_vector(&self) -> (i32, i32) {
-        match self {
-            Direction::North => (-1, 0),
-            Direction::East => (0, 1),
-            Direction::South => (1, 0),
-            Direction::West => (0, -1),
-        }
-    }
-}
-
-fn turn_cost(dir1: &Direction, dir2: &Direction) -> Option<usize> {
-    if dir1 == dir2 {
-        return Some(0);
-    }
-    let (dx1, dy1) = dir1.as_vector();
-    let (dx2, dy2) = dir2.as_vector();
-    if dx1 == -dx2 && dy1 == -dy2 {
-        return None;
-    }
-    Some(1000)
-}
-
-const DIRECTIONS: [Direction; 4] = [
-    Direction::North,
-    Direction::East,
-    Direction::South,
-    Direction::West,
-];
-
-fn successors(grid: &Grid, pos: &PosDir) -> Vec<(PosDir, usize)> {
-    let mut result: Vec<(PosDir, usize)> = Vec::with_capacity(3);
-    let (row, col, dir) = *pos;
-    for next_dir in DIRECTIONS.iter() {
-        if let Some(tc) = turn_cost(&dir, next_dir) {
-            let (dr, dc) = next_dir.as_vector();
-            let (next_row, next_col) = ((row as i32 + dr) as usize, (col as i32 + dc) as usize);
-            if grid.cells[next_row][next_col] != '#' {
-                let next_p = ((next_row, next_col, *next_dir), tc + 1);
-                // println!("{pos:?} -> {next_p:?} {}", grid.cells[next_row][next_col]);
-                result.push(next_p)
-            }
-        }
-    }
-    result
-}
-
-type Position = (usize, usize);
-type PosDir = (usize, usize, Direction);
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Grid {
-    width: usize,
-    height: usize,
-    cells: Vec<Vec<char>>,
-    start_pos: Position,
-    end_pos: Position,
-}
-
-impl fmt::Display for Grid {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for row in 0..self.height {
-            for col in 0..self.width {
-                write!(f, "{}", self.cells[row][col])?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-fn parse_grid(input: &str) -> IResult<&str, Grid> {
-    let (rest, cells) = separated_list0(line_ending, parse_grid_line)(input)?;
-    let width = cells.first().map(|r| r.len()).unwrap_or_default();
-    let height = cells.len();
-    let mut start_row = 0;
-    let mut start_col = 0;
-    let mut end_row = 0;
-    let mut end_col = 0;
-    for (row_idx, row) in cells.iter().enumerate() {
-        for (col_idx, col) in row.iter().enumerate() {
-            if *col == 'S' {
-                (start_row, start_col) = (row_idx, col_idx);
-            }
-            if *col == 'E' {
-                (end_row, end_col) = (row_idx, col_idx);
-            }
-        }
-    }
-    Ok((
-        rest,
-        Grid {
-            width,
-            height,
-            cells,
-            start_pos: (start_row, start_col),
-            end_pos: (end_row, end_col),
-        },
-    ))
-}
-
-fn parse_grid_line(input: &str) -> IResult<&str, Vec<char>> {
-    many1(one_of(".#SE"))(input)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = r#"###############
-#.......#....E#
-#.#.###.#.###.#
-#.....#.#...#.#
-#.###.#####.#.#
-#.#.#.......#.#
-#.#.#####.###.#
-#...........#.#
-###.#.#####.#.#
-#...#.....#.#.#
-#.#.#.###.#.#.#
-#.....#...#.#.#
-#.###.#.#.#.#.#
-#S..#.....#...#
-###############"#;
-
-    #[test]
-    fn part1_works() -> anyhow::Result<()> {
-        let result = part1(INPUT)?;
-        assert_eq!(result, 7036);
-        Ok(())
-    }
-
-    #[test]
-    fn part2_works() -> anyhow::Result<()> {
-        let result = part2(INPUT)?;
-        assert_eq!(result, 45);
-        Ok(())
-    }
-}
@@ -0,0 +1,191 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, multispace0};
+use nom::combinator::{eof, map};
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::fmt;
+
+pub fn part1(memory: &Memory, size: usize) -> anyhow::Result<usize> {
+    bfs_with_len(memory, size).ok_or_else(|| anyhow!("no solution found"))
+}
+
+pub fn part2(memory: &Memory, min_size: usize) -> anyhow::Result<Point> {
+    let max_size = memory.points.iter().len();
+    let mut left = min_size;
+    let mut right = max_size;
+
+    while left < right {
+        let m = left + (right - left) / 2;
+        if bfs_with_len(memory, m).is_none() {
+            right = m;
+        } else {
+            left = m + 1;
+        }
+    }
+
+    let p1 = memory.points[left - 1];
+    let p1_valid = bfs_with_len(memory, left).is_none();
+    if !p1_valid {
+        return Err(anyhow!("no solution found"));
+    }
+    Ok(p1)
+}
+
+fn bfs_with_len(memory: &Memory, size: usize) -> Option<usize> {
+    let corrupted = memory.corrupted(size);
+    // println!("{}", memory);
+    let mut q = VecDeque::from([(Pos::ORIGIN, 0)]);
+    let goal = Pos::new(memory.height, memory.width);
+    let mut visited = HashSet::new();
+
+    while let Some((pos, cost)) = q.pop_front() {
+        // println!("({},{}) q={:?}", pos, cost, q);
+        if pos == goal {
+            return Some(cost);
+        }
+        if !visited.insert(pos) {
+            continue;
+        }
+        for next in corrupted.neighbours4(pos) {
+            if !corrupted[next] {
+                q.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+pub type Point = (isize, isize);
+
+/// The memory space with the coordinates of the falling bytes in the order they fall.
+#[derive(Debug)]
+pub struct Memory {
+    width: isize,
+    height: isize,
+    points: Vec<Point>,
+}
+
+impl Memory {
+    /// The memory space after the first `size` bytes have fallen.
+    fn corrupted(&self, size: usize) -> Grid<bool> {
+        let mut grid = Grid::new(self.width as usize + 1, self.height as usize + 1, false);
+        for &(x, y) in self.points.iter().take(size) {
+            grid[Pos::new(y, x)] = true;
+        }
+        grid
+    }
+}
+
+impl FromStr for Memory {
+    type Err = anyhow::Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let memory = parse_error::nom(input, parse_memory)?;
+        Ok(memory)
+    }
+}
+
+impl fmt::Display for Memory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let corrupted = self.corrupted(self.points.len());
+        write!(f, "{}", corrupted.map(|&c| if c { '#' } else { '.' }))
+    }
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Memory> {
+    input.parse()
+}
+
+fn parse_memory(input: &str) -> IResult<&str, Memory> {
+    let parse_point = map(
+        separated_pair(complete::i32, tag(","), complete::i32),
+        |(x, y)| (x as isize, y as isize),
+    );
+    let (input, points) = separated_list0(line_ending, parse_point)(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+    let width = points.iter().map(|(x, _)| *x).max().unwrap_or_default();
+    let height = points.iter().map(|(_, y)| *y).max().unwrap_or_default();
+    Ok((
+        input,
+        Memory {
+            width,
+            height,
+            points,
+        },
+    ))
+}
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input<'a> = Memory;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input, 1024)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        let (x, y) = part2(input, 1024)?;
+        Ok(format!("{x},{y}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?, 12)?;
+        assert_eq!(result, 22);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?, 12)?;
+        assert_eq!(result, (6, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, line_ending, multispace0};
+use nom::combinator::eof;
+use nom::multi::{many1, separated_list0};
+use nom::IResult;
+use search::{bfs, count_paths};
+use std::fmt::Display;
+
+pub fn part1(data: &Towels) -> anyhow::Result<usize> {
+    // println!("{:?}", data);
+    let result = data
+        .designs
+        .iter()
+        .filter(|design| has_solution(design, &data.patterns))
+        .count();
+    Ok(result)
+}
+
+pub fn part2(data: &Towels) -> anyhow::Result<usize> {
+    let result = data
+        .designs
+        .iter()
+        .map(|design| count_solutions(design, &data.patterns))
+        .sum();
+    Ok(result)
+}
+
+fn has_solution(design: &str, patterns: &[&str]) -> bool {
+    bfs(
+        &design.to_string(),
+        |suffix| {
+            patterns
+                .iter()
+                .filter_map(|p| suffix.strip_prefix(p).map(|s| s.to_string()))
+                .collect::<Vec<_>>()
+        },
+        |suffix| suffix.is_empty(),
+    )
+    .is_some()
+}
+// fn has_solution(design: &str, patterns: &[&str]) -> bool {
+//     let mut q = VecDeque::from([design]);
+//     let mut visited = HashSet::new();
+//
+//     while let Some(current) = q.pop_front() {
+//         if !visited.insert(current) {
+//             continue;
+//         }
+//         if current.is_empty() {
+//             return true;
+//         }
+//         for p in patterns.iter() {
+//             if let Some(next) = current.strip_prefix(p) {
+//                 q.push_back(next);
+//             }
+//         }
+//     }
+//
+//     false
+// }
+
+fn count_solutions(design: &str, patterns: &[&str]) -> usize {
+    count_paths(
+        design,
+        |suffix| {
+            patterns
+                .iter()
+                .filter_map(|p| suffix.strip_prefix(p))
+                .collect::<Vec<_>>()
+        },
+        |suffix| suffix.is_empty(),
+    )
+}
+
+#[derive(Debug)]
+pub struct Towels<'a> {
+    patterns: Vec<&'a str>,
+    designs: Vec<&'a str>,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Towels<'_>> {
+    Ok(parse_error::nom(input, parse_input)?)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Towels<'_>> {
+    let (input, patterns) = parse_patterns(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, designs) = parse_designs(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+
+    Ok((input, Towels { patterns, designs }))
+}
+
+fn parse_patterns(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list0(tag(", "), alpha1)(input)
+}
+
+fn parse_designs(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list0(line_ending, alpha1)(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    type Input<'a> = Towels<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_solutions() {
+        let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        let design = "brwrr";
+        let result = count_solutions(design, &patterns);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 16);
+        Ok(())
+    }
+}
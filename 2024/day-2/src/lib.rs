@@ -0,0 +1,103 @@
+use aoc_core::Solution;
+use nom::character::complete;
+use nom::character::complete::{multispace0, newline, space1};
+use nom::multi::separated_list0;
+use nom::sequence::terminated;
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(reports: &[Vec<i32>]) -> anyhow::Result<usize> {
+    let result = reports.iter().filter(|report| is_safe(report)).count();
+    Ok(result)
+}
+
+pub fn part2(reports: &[Vec<i32>]) -> anyhow::Result<usize> {
+    let result = reports.iter().filter(|report| is_safe2(report)).count();
+    Ok(result)
+}
+
+fn is_safe(arr: &[i32]) -> bool {
+    let all_increasing = arr.windows(2).all(|w| w[0] < w[1]);
+    let all_decreasing = arr.windows(2).all(|w| w[0] > w[1]);
+    let in_range = arr.windows(2).all(|w| {
+        let diff = (w[0] - w[1]).abs();
+        (1..=3).contains(&diff)
+    });
+    (all_increasing || all_decreasing) && in_range
+}
+
+fn is_safe2(arr: &[i32]) -> bool {
+    for (i, _) in arr.iter().enumerate() {
+        let mut values = arr.to_vec();
+        values.remove(i);
+        if is_safe(&values) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<i32>>> {
+    let result = parse_error::nom(
+        input,
+        terminated(separated_list0(newline, parse_report), multispace0),
+    )?;
+    Ok(result)
+}
+
+fn parse_report(input: &str) -> IResult<&str, Vec<i32>> {
+    let (rest, list) = separated_list0(space1, complete::i32)(input)?;
+    Ok((rest, list))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let input = r#"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9"#;
+        let result = part1(&parse(input)?)?;
+        assert_eq!(2, result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let input = r#"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9"#;
+        let result = part2(&parse(input)?)?;
+        assert_eq!(4, result);
+        Ok(())
+    }
+}
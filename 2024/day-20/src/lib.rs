@@ -0,0 +1,221 @@
+use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Dir, Grid, Pos};
+use nom::character::complete::{line_ending, multispace0, one_of};
+use nom::combinator::{eof, map_res};
+use nom::multi::{many1, separated_list0};
+use nom::IResult;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<usize> {
+    let end = grid
+        .find(|&c| c == 'E')
+        .ok_or_else(|| anyhow!("cannot find 'E'"))?;
+
+    let from_end = distances_from(grid, end);
+    let mut result = 0;
+
+    for (pos, &c) in grid.iter() {
+        if c == '#' {
+            let cheats = cheats_from_position(grid, pos);
+            for &(start, end) in cheats.iter() {
+                let savings = cheat_savings(start, end, &from_end, 2);
+                if savings >= 100 {
+                    result += 1;
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<usize> {
+    let end = grid
+        .find(|&c| c == 'E')
+        .ok_or_else(|| anyhow!("cannot find 'E'"))?;
+    let from_end = distances_from(grid, end);
+    let mut visited = BTreeSet::new();
+    let mut result = 0;
+
+    for (start, &c) in grid.iter() {
+        if c != '#' {
+            let bla = cheats_from_position2(grid, start, 20, &from_end);
+            for &(end, d) in bla.iter() {
+                let savings = cheat_savings(start, end, &from_end, d as isize);
+                if visited.insert((start, end)) && savings >= 100 {
+                    result += 1;
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn distances_from(grid: &Grid<char>, start: Pos) -> BTreeMap<Pos, usize> {
+    let mut visited = BTreeSet::new();
+    let mut q = VecDeque::from([(start, 0)]);
+    let mut distances = BTreeMap::new();
+
+    while let Some((pos, distance)) = q.pop_front() {
+        if !visited.insert(pos) {
+            continue;
+        }
+        distances.insert(pos, distance);
+        for next in grid.neighbours4(pos) {
+            if grid[next] != '#' {
+                q.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+fn cheats_from_position2(
+    grid: &Grid<char>,
+    start: Pos,
+    max_distance: usize,
+    from_end: &BTreeMap<Pos, usize>,
+) -> Vec<(Pos, usize)> {
+    let mut distances: BTreeMap<Pos, usize> = BTreeMap::new();
+    let mut q = VecDeque::from([(start, 0)]);
+    let mut visited = BTreeSet::new();
+    while let Some((pos, distance)) = q.pop_front() {
+        if distances.contains_key(&pos) {
+            continue;
+        }
+        if distance > max_distance {
+            continue;
+        }
+        if !visited.insert(pos) {
+            continue;
+        }
+        if from_end.contains_key(&pos) {
+            distances.insert(pos, distance);
+        }
+        for next in grid.neighbours4(pos) {
+            q.push_back((next, distance + 1));
+        }
+    }
+    distances.into_iter().collect()
+}
+
+fn cheats_from_position(grid: &Grid<char>, pos: Pos) -> Vec<Cheat> {
+    let is_open = |pos: Pos| grid.get(pos).is_some_and(|&c| c != '#');
+    let mut result = Vec::new();
+    for (a, b) in [(Dir::Up, Dir::Down), (Dir::Left, Dir::Right)] {
+        let (a, b) = (pos.step(a), pos.step(b));
+        if is_open(a) && is_open(b) {
+            result.push((a, b));
+            result.push((b, a));
+        }
+    }
+
+    result
+}
+
+fn cheat_savings(start: Pos, end: Pos, from_end: &BTreeMap<Pos, usize>, distance: isize) -> isize {
+    let Some(distance2) = from_end.get(&start) else {
+        return 0;
+    };
+    let Some(distance1) = from_end.get(&end) else {
+        return 0;
+    };
+    let savings: isize = *distance2 as isize - *distance1 as isize - distance;
+    savings
+}
+
+type Cheat = (Pos, Pos);
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    Ok(parse_error::nom(input, parse_grid)?)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Grid<char>> {
+    let parse_line = many1(one_of(".#SE"));
+    let (input, grid) = map_res(separated_list0(line_ending, parse_line), Grid::from_rows)(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+    Ok((input, grid))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let _result = part1(&parse(INPUT)?)?;
+        // assert_eq!(result, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test1() -> anyhow::Result<()> {
+        let grid = parse_error::nom(INPUT, parse_grid)?;
+        let end = grid
+            .find(|&c| c == 'E')
+            .ok_or_else(|| anyhow!("'E' not found"))?;
+        let from_end = distances_from(&grid, end);
+        let savings = cheat_savings(Pos::new(3, 1), Pos::new(7, 3), &from_end, 6);
+        println!("savings: {}", savings);
+        Ok(())
+    }
+
+    #[test]
+    fn test2() -> anyhow::Result<()> {
+        let grid = parse_error::nom(INPUT, parse_grid)?;
+        let end = grid
+            .find(|&c| c == 'E')
+            .ok_or_else(|| anyhow!("'E' not found"))?;
+        let from_end = distances_from(&grid, end);
+        let cheats = cheats_from_position2(&grid, Pos::new(3, 1), 20, &from_end);
+        println!("cheats: {cheats:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let _result = part2(&parse(INPUT)?)?;
+        // assert_eq!(result, 16);
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use aoc_core::Solution;
+use graph_export::{Graph, Shape, Style};
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, line_ending, multispace0};
+use nom::combinator::eof;
+use nom::multi::separated_list0;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+
+pub fn part1(edges: &EdgeList) -> anyhow::Result<usize> {
+    let adj = build_adj_list(edges);
+    let triples = find_all_triples(&adj);
+    let filtered_triples = triples
+        .iter()
+        .filter(|triple| triple.iter().any(|s| s.starts_with('t')));
+    let result = filtered_triples.count();
+    Ok(result)
+}
+
+pub fn part2(edges: &EdgeList) -> anyhow::Result<String> {
+    let adj = build_adj_list(edges);
+    let results = find_all(&adj);
+    let result = results.iter().max_by(|a, b| a.len().cmp(&b.len())).unwrap();
+    let result: Vec<_> = result.iter().map(|s| s.to_string()).collect();
+    Ok(result.join(","))
+}
+
+/// The network map, with the computers of the largest LAN party and the ones starting with a `t`
+/// highlighted.
+pub fn graph(input: &str) -> anyhow::Result<Graph> {
+    let edges = parse_error::nom(input, parse_edge_list)?;
+    let adj = build_adj_list(&edges);
+    let party = find_all(&adj)
+        .into_iter()
+        .max_by_key(|party| party.len())
+        .unwrap_or_default();
+
+    let mut graph = Graph::undirected();
+    graph.style("party", Style::new().shape(Shape::Hexagon).fill("#ffd700"));
+    graph.style("t", Style::new().shape(Shape::Circle).fill("#a8c8f0"));
+    for (from, tos) in adj.iter() {
+        if party.contains(from) {
+            graph.node(from).kind("party");
+        } else if from.starts_with('t') {
+            graph.node(from).kind("t");
+        }
+        for to in tos.iter().filter(|to| from < *to) {
+            graph.edge(from, to);
+        }
+    }
+    Ok(graph)
+}
+
+type Edge<'a> = (&'a str, &'a str);
+pub type EdgeList<'a> = Vec<Edge<'a>>;
+type AdjList<'a> = BTreeMap<&'a str, BTreeSet<&'a str>>;
+
+fn find_all_triples<'a>(adj: &AdjList<'a>) -> BTreeSet<Vec<&'a str>> {
+    let mut result = BTreeSet::new();
+    let empty_set = BTreeSet::new();
+    for (x, xs) in adj.iter() {
+        for y in xs.iter() {
+            let ys = adj.get(y).unwrap_or(&empty_set);
+            for z in ys.iter() {
+                let zs = adj.get(z).unwrap_or(&empty_set);
+                if z != x && zs.contains(x) {
+                    let mut v = vec![*x, *y, *z];
+                    v.sort();
+                    result.insert(v);
+                }
+            }
+        }
+    }
+    result
+}
+
+fn find_all<'a>(adj: &AdjList<'a>) -> BTreeSet<BTreeSet<&'a str>> {
+    let mut result = BTreeSet::new();
+    let mut q = VecDeque::from_iter(adj.keys().map(|start| (*start, BTreeSet::from([*start]))));
+
+    while let Some((from, reachable)) = q.pop_front() {
+        if result.contains(&reachable) {
+            continue;
+        }
+        let froms = adj.get(from).unwrap();
+        for to in froms.iter() {
+            if reachable.contains(to) {
+                continue;
+            }
+            if !reachable
+                .iter()
+                .all(|r| adj.get(r).map(|r| r.contains(to)).unwrap_or(false))
+            {
+                continue;
+            }
+            let mut next_reachable = reachable.clone();
+            next_reachable.insert(to);
+            q.push_back((to, next_reachable));
+        }
+        result.insert(reachable);
+    }
+
+    result
+}
+
+fn build_adj_list<'a>(edges: &'a [Edge]) -> AdjList<'a> {
+    edges
+        .iter()
+        .fold(BTreeMap::new(), |mut adj_list, (from, to)| {
+            let e = adj_list.entry(from).or_default();
+            e.insert(to);
+            let e = adj_list.entry(to).or_default();
+            e.insert(from);
+            adj_list
+        })
+}
+
+pub fn parse(input: &str) -> anyhow::Result<EdgeList<'_>> {
+    Ok(parse_error::nom(input, parse_edge_list)?)
+}
+
+fn parse_edge_list(input: &str) -> IResult<&str, EdgeList> {
+    let (input, list) = separated_list0(line_ending, parse_edge)(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = eof(input)?;
+    Ok((input, list))
+}
+
+fn parse_edge(input: &str) -> IResult<&str, Edge> {
+    separated_pair(alpha1, tag("-"), alpha1)(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+
+    type Input<'a> = EdgeList<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        println!("{result}");
+        assert_eq!(result, "co,de,ka,ta");
+        Ok(())
+    }
+
+    #[test]
+    fn graph_works() -> anyhow::Result<()> {
+        let graph = graph(INPUT)?;
+        assert_eq!(graph.node_count(), 16);
+        assert_eq!(graph.edge_count(), 32);
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.contains("classDef party fill:#ffd700"));
+        assert_eq!(mermaid.lines().filter(|l| l.ends_with(" party")).count(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use nom::character::complete::{line_ending, one_of};
+use nom::combinator::map_res;
+use nom::multi::{many1, separated_list0, separated_list1};
+use nom::IResult;
+use std::fmt::Display;
+
+pub fn part1(grids: &[Grid<char>]) -> anyhow::Result<usize> {
+    let height = grids.first().map(|g| g.height()).unwrap_or_default();
+    let (locks, keys): (Vec<&Grid<char>>, Vec<&Grid<char>>) =
+        grids.iter().partition(|g| is_lock(g));
+    let lock_heights: Vec<_> = locks.iter().map(|g| get_lock_heights(g, '#')).collect();
+    let key_heights: Vec<_> = keys.iter().map(|g| get_key_heights(g, '#')).collect();
+    let mut result = 0;
+    for lh in lock_heights.iter() {
+        for kh in key_heights.iter() {
+            if matches(lh, kh, height - 1) {
+                result += 1;
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn matches(lock_heights: &[usize], key_heights: &[usize], height: usize) -> bool {
+    lock_heights
+        .iter()
+        .zip(key_heights.iter())
+        .all(|(lock_height, key_height)| lock_height + key_height < height)
+}
+
+fn get_lock_heights(g: &Grid<char>, m: char) -> Vec<usize> {
+    (0..g.width())
+        .map(|col| get_lock_height_for_col(g, m, col))
+        .collect()
+}
+
+fn get_lock_height_for_col(g: &Grid<char>, m: char, col: usize) -> usize {
+    g.column(col).skip(1).take_while(|&&c| c == m).count()
+}
+
+fn get_key_heights(g: &Grid<char>, m: char) -> Vec<usize> {
+    (0..g.width())
+        .map(|col| get_key_height_for_col(g, m, col))
+        .collect()
+}
+
+fn get_key_height_for_col(g: &Grid<char>, m: char, col: usize) -> usize {
+    (0..g.height())
+        .rev()
+        .map(|r| g.row(r))
+        .take_while(|row| row[col] == m)
+        .count()
+        - 1
+}
+
+fn is_lock(grid: &Grid<char>) -> bool {
+    grid.get(Pos::ORIGIN) == Some(&'#')
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Grid<char>>> {
+    Ok(parse_error::nom(input, parse_grids)?)
+}
+
+fn parse_grids(input: &str) -> IResult<&str, Vec<Grid<char>>> {
+    separated_list0(many1(line_ending), parse_grid)(input)
+}
+
+fn parse_grid(input: &str) -> IResult<&str, Grid<char>> {
+    map_res(
+        separated_list1(line_ending, parse_grid_line),
+        Grid::from_rows,
+    )(input)
+}
+
+fn parse_grid_line(input: &str) -> IResult<&str, Vec<char>> {
+    many1(one_of(".#"))(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+    const HAS_PART2: bool = false;
+
+    type Input<'a> = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####"#;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    let mut result = 0;
+
+    for pos in grid.positions().filter(|&pos| grid[pos] == 'X') {
+        for dir in Pos::ORIGIN.neighbours8() {
+            if get(grid, pos + dir) == 'M'
+                && get(grid, pos + dir * 2) == 'A'
+                && get(grid, pos + dir * 3) == 'S'
+            {
+                result += 1;
+            }
+        }
+    }
+    result
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    let mut result = 0;
+
+    for pos in grid.positions().filter(|&pos| grid[pos] == 'A') {
+        let is_mas = |a: Pos, b: Pos| {
+            matches!(
+                (get(grid, pos + a), get(grid, pos + b)),
+                ('M', 'S') | ('S', 'M')
+            )
+        };
+        if is_mas(Pos::new(-1, -1), Pos::new(1, 1)) && is_mas(Pos::new(1, -1), Pos::new(-1, 1)) {
+            result += 1;
+        }
+    }
+    result
+}
+
+fn get(grid: &Grid<char>, pos: Pos) -> char {
+    grid.get(pos).copied().unwrap_or(' ')
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    input.parse()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = r#"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"#;
+        let result = part1(&parse(input).unwrap());
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = r#"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"#;
+        let result = part2(&parse(input).unwrap());
+        assert_eq!(result, 9);
+    }
+}
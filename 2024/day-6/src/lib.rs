@@ -1,32 +1,36 @@
-use anyhow::anyhow;
+use aoc_core::Solution;
+use grid::{Dir, Grid, Pos};
 use nom::character::complete::{line_ending, one_of};
+use nom::combinator::map_res;
 use nom::multi::{many1, separated_list1};
 use nom::IResult;
 use rayon::prelude::*;
 use std::collections::HashSet;
 use std::fmt;
+use std::fmt::Display;
+use visualize::{Frame, Simulation};
 
-pub fn count_guard_positions(grid: &Grid) -> usize {
-    let mut grid = grid.clone();
-    while grid.is_on_grid(grid.pos.row, grid.pos.col) {
-        grid.step();
+pub fn count_guard_positions(lab: &Lab) -> usize {
+    let mut lab = lab.clone();
+    while lab.is_on_grid(lab.pos) {
+        lab.step();
     }
-    grid.positions.len()
+    lab.positions.len()
 }
 
-pub fn count_obstructions(grid: &Grid) -> usize {
-    let mut copy = grid.clone();
-    while copy.is_on_grid(copy.pos.row, copy.pos.col) {
+pub fn count_obstructions(lab: &Lab) -> usize {
+    let mut copy = lab.clone();
+    while copy.is_on_grid(copy.pos) {
         copy.step();
     }
     let positions = copy.positions;
     let count = positions
         .par_iter()
-        .filter(|p| p.row != grid.pos.row || p.col != grid.pos.col)
-        .filter(|p| {
-            let mut copy = grid.clone();
-            copy.grid[p.row as usize][p.col as usize] = State::Occupied;
-            while copy.is_on_grid(copy.pos.row, copy.pos.col) {
+        .filter(|&&p| p != lab.pos)
+        .filter(|&&p| {
+            let mut copy = lab.clone();
+            copy.map[p] = State::Occupied;
+            while copy.is_on_grid(copy.pos) {
                 if copy.step2() {
                     // true means, step2 detected a loop
                     return true;
@@ -38,179 +42,112 @@ pub fn count_obstructions(grid: &Grid) -> usize {
     count
 }
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq, Ord, Hash)]
-pub struct Point {
-    pub row: isize,
-    pub col: isize,
-}
-
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Ord)]
 pub enum State {
     Empty,
     Occupied,
 }
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Ord, Hash)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl Direction {
-    pub fn rotated_right(self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-
-    pub fn delta(&self) -> (isize, isize) {
-        match self {
-            Direction::Up => (1, 0),
-            Direction::Right => (0, 1),
-            Direction::Down => (-1, 0),
-            Direction::Left => (0, -1),
-        }
-    }
-}
-
+/// The lab map together with the guard's patrol so far.
 #[derive(Debug, Clone)]
-pub struct Grid {
-    pub grid: Vec<Vec<State>>,
-    pub width: isize,
-    pub height: isize,
-    pub pos: Point,
-    pub direction: Direction,
-    pub positions: HashSet<Point>,
-    pub pos_directions: HashSet<(Point, Direction)>,
+pub struct Lab {
+    pub map: Grid<State>,
+    pub pos: Pos,
+    pub direction: Dir,
+    pub positions: HashSet<Pos>,
+    pub pos_directions: HashSet<(Pos, Dir)>,
 }
 
-impl Grid {
-    pub fn is_occupied(&self, row: isize, col: isize) -> bool {
-        self.is_on_grid(row, col) && self.grid[row as usize][col as usize] == State::Occupied
+impl Lab {
+    pub fn is_occupied(&self, pos: Pos) -> bool {
+        self.map.get(pos) == Some(&State::Occupied)
     }
 
-    pub fn is_on_grid(&self, row: isize, col: isize) -> bool {
-        0 <= row && row < self.height && 0 <= col && col < self.width
+    pub fn is_on_grid(&self, pos: Pos) -> bool {
+        self.map.contains(pos)
     }
 
-    pub fn move_by(&mut self, dr: isize, dc: isize) {
-        self.pos.row += dr;
-        self.pos.col += dc;
-        if self.is_on_grid(self.pos.row, self.pos.col) {
+    pub fn move_by(&mut self, delta: Pos) {
+        self.pos += delta;
+        if self.is_on_grid(self.pos) {
             self.positions.insert(self.pos);
             self.pos_directions.insert((self.pos, self.direction));
         }
     }
 
     pub fn rotate_right(&mut self) {
-        self.direction = self.direction.rotated_right();
+        self.direction = self.direction.turn_right();
     }
 
     pub fn step(&mut self) -> bool {
-        let (dr, dc) = self.direction.delta();
-        let next_row = self.pos.row + dr;
-        let next_col = self.pos.col + dc;
+        let next = self.pos.step(self.direction);
 
-        if self.is_occupied(next_row, next_col) {
+        if self.is_occupied(next) {
             self.rotate_right()
         } else {
-            self.move_by(dr, dc);
+            self.move_by(self.direction.delta());
         }
 
         false
     }
 
     pub fn step2(&mut self) -> bool {
-        let (dr, dc) = self.direction.delta();
-        let next_row = self.pos.row + dr;
-        let next_col = self.pos.col + dc;
-
-        if self.pos_directions.contains(&(
-            Point {
-                row: next_row,
-                col: next_col,
-            },
-            self.direction,
-        )) {
+        let next = self.pos.step(self.direction);
+
+        if self.pos_directions.contains(&(next, self.direction)) {
             return true;
         }
 
-        if self.is_occupied(next_row, next_col) {
+        if self.is_occupied(next) {
             self.rotate_right()
         } else {
-            self.move_by(dr, dc);
+            self.move_by(self.direction.delta());
         }
 
         false
     }
 }
 
-impl fmt::Display for Grid {
+impl fmt::Display for Lab {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (row_idx, row) in self.grid.iter().enumerate().rev() {
-            for (col_idx, state) in row.iter().enumerate() {
-                let c = match state {
-                    State::Empty
-                        if row_idx as isize == self.pos.row && col_idx as isize == self.pos.col =>
-                    {
-                        match self.direction {
-                            Direction::Up => '^',
-                            Direction::Left => '>',
-                            Direction::Down => 'v',
-                            Direction::Right => '<',
-                        }
-                    }
-                    State::Empty => '.',
-                    State::Occupied => '#',
-                };
-                write!(f, "{}", c)?;
+        for (pos, state) in self.map.iter() {
+            let c = match state {
+                State::Empty if pos == self.pos => match self.direction {
+                    Dir::Up => '^',
+                    Dir::Right => '>',
+                    Dir::Down => 'v',
+                    Dir::Left => '<',
+                },
+                State::Empty => '.',
+                State::Occupied => '#',
+            };
+            write!(f, "{}", c)?;
+            if pos.col as usize + 1 == self.map.width() {
+                writeln!(f)?;
             }
-            writeln!(f)?;
         }
         Ok(())
     }
 }
 
-pub fn parse_grid(input: &str) -> anyhow::Result<Grid> {
-    let (_, mut grid_chars) =
-        separated_list1(line_ending, parse_row)(input).map_err(|e| anyhow!("{e}"))?;
-    grid_chars.reverse();
-    let (row_idx, row) = grid_chars
-        .iter()
-        .enumerate()
-        .find(|(_, row)| row.contains(&'^'))
+pub fn parse(input: &str) -> anyhow::Result<Lab> {
+    let grid: Grid<char> = parse_error::nom(
+        input,
+        map_res(separated_list1(line_ending, parse_row), Grid::from_rows),
+    )?;
+    let start = grid
+        .find(|&c| c == '^')
         .ok_or_else(|| anyhow::anyhow!("No start found in grid"))?;
-    let col_idx = row.iter().position(|&c| c == '^').unwrap();
-    let grid: Vec<_> = grid_chars
-        .iter()
-        .map(|row| {
-            let r: Vec<_> = row
-                .iter()
-                .map(|c| match c {
-                    '#' => State::Occupied,
-                    _ => State::Empty,
-                })
-                .collect();
-            r
-        })
-        .collect();
-    let start = Point {
-        row: row_idx as isize,
-        col: col_idx as isize,
-    };
-    Ok(Grid {
-        width: grid[0].len() as isize,
-        height: grid.len() as isize,
-        grid,
+    let map = grid.map(|&c| match c {
+        '#' => State::Occupied,
+        _ => State::Empty,
+    });
+    Ok(Lab {
+        map,
         pos: start,
-        direction: Direction::Up,
+        direction: Dir::Up,
         positions: HashSet::from([start]),
-        pos_directions: HashSet::from([(start, Direction::Up)]),
+        pos_directions: HashSet::from([(start, Dir::Up)]),
     })
 }
 
@@ -218,6 +155,74 @@ fn parse_row(input: &str) -> IResult<&str, Vec<char>> {
     many1(one_of("#.^"))(input)
 }
 
+/// The patrol of part 1 one step at a time, until the guard leaves the map.
+pub fn simulate(input: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    Ok(Box::new(Patrol(parse(input)?)))
+}
+
+struct Patrol(Lab);
+
+impl Simulation for Patrol {
+    fn step(&mut self) -> bool {
+        let lab = &mut self.0;
+        if !lab.is_on_grid(lab.pos) {
+            return false;
+        }
+        lab.step();
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let lab = &self.0;
+        let frame = Frame::from_fn(lab.map.width(), lab.map.height(), |x, y| {
+            let p = Pos::from((y, x));
+            if p == lab.pos {
+                [230, 60, 60]
+            } else if lab.is_occupied(p) {
+                [120, 120, 130]
+            } else if lab.positions.contains(&p) {
+                [90, 150, 220]
+            } else {
+                [20, 20, 30]
+            }
+        });
+        if lab.is_on_grid(lab.pos) {
+            frame.with_focus(lab.pos.col as usize, lab.pos.row as usize)
+        } else {
+            frame
+        }
+    }
+}
+
+pub fn part1(lab: &Lab) -> anyhow::Result<usize> {
+    Ok(count_guard_positions(lab))
+}
+
+pub fn part2(lab: &Lab) -> anyhow::Result<usize> {
+    Ok(count_obstructions(lab))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Lab;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(count_guard_positions(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        Ok(count_obstructions(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,8 +239,8 @@ mod tests {
 ........#.
 #.........
 ......#..."#;
-        let grid = parse_grid(input).map_err(|e| anyhow!("{e}"))?;
-        let result = count_guard_positions(&grid);
+        let lab = parse(input)?;
+        let result = count_guard_positions(&lab);
         assert_eq!(result, 41);
         Ok(())
     }
@@ -252,9 +257,34 @@ mod tests {
 ........#.
 #.........
 ......#..."#;
-        let grid = parse_grid(input).map_err(|e| anyhow!("{e}"))?;
-        let result = count_obstructions(&grid);
+        let lab = parse(input)?;
+        let result = count_obstructions(&lab);
         assert_eq!(result, 6);
         Ok(())
     }
+
+    #[test]
+    fn simulate_works() -> anyhow::Result<()> {
+        let input = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."#;
+        let mut patrol = simulate(input)?;
+        assert_eq!(patrol.frame().focus(), Some((4, 6)));
+        while patrol.step() {}
+        let frame = patrol.frame();
+        assert_eq!(frame.focus(), None);
+        let visited = (0..frame.height())
+            .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| frame.get(x, y) == [90, 150, 220])
+            .count();
+        assert_eq!(visited, 41);
+        Ok(())
+    }
 }
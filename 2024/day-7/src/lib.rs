@@ -0,0 +1,248 @@
+use aoc_core::Solution;
+use nom::bytes::complete::tag;
+use nom::character::complete;
+use nom::character::complete::{line_ending, multispace0, space1};
+use nom::multi::separated_list0;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+use rayon::prelude::*;
+use std::fmt::Display;
+
+pub fn part1(equations: &[Equation]) -> anyhow::Result<u64> {
+    let result = equations
+        .par_iter()
+        .filter(|equation| has_solutions(equation.goal, &equation.values))
+        .map(|equation| equation.goal)
+        .sum();
+
+    Ok(result)
+}
+
+pub fn part2(equations: &[Equation]) -> anyhow::Result<u64> {
+    let result = equations
+        .par_iter()
+        .filter(|equation| has_solutions2(equation.goal, &equation.values))
+        .map(|equation| equation.goal)
+        .sum();
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Equation {
+    goal: u64,
+    values: Vec<u64>,
+}
+
+fn has_solutions(goal: u64, values: &[u64]) -> bool {
+    if values.is_empty() {
+        return goal == 0;
+    }
+
+    // the first value starts the calculation instead of being added to or multiplied with 0
+    if let [value] = values {
+        return goal == *value;
+    }
+
+    let (&last_value, rest) = values.split_last().unwrap();
+
+    if goal % last_value == 0 && has_solutions(goal / last_value, rest) {
+        return true;
+    }
+
+    if goal >= last_value && has_solutions(goal - last_value, rest) {
+        return true;
+    }
+
+    false
+}
+
+fn has_solutions2(goal: u64, values: &[u64]) -> bool {
+    if values.is_empty() {
+        return goal == 0;
+    }
+
+    // the first value starts the calculation instead of being added to or multiplied with 0
+    if let [value] = values {
+        return goal == *value;
+    }
+
+    let (&last_value, rest) = values.split_last().unwrap();
+
+    if goal % last_value == 0 && has_solutions2(goal / last_value, rest) {
+        return true;
+    }
+
+    if goal >= last_value && has_solutions2(goal - last_value, rest) {
+        return true;
+    }
+
+    if ends_with(goal, last_value) && has_solutions2(truncate(goal, last_value), rest) {
+        return true;
+    }
+
+    false
+}
+
+fn ends_with(x: u64, y: u64) -> bool {
+    let mut x = x;
+    let mut y = y;
+    while y > 0 {
+        if x % 10 != y % 10 {
+            return false;
+        }
+        x /= 10;
+        y /= 10;
+    }
+    true
+}
+
+fn truncate(x: u64, y: u64) -> u64 {
+    let mut x = x;
+    let mut y = y;
+    while y > 0 {
+        x /= 10;
+        y /= 10;
+    }
+    x
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Equation>> {
+    Ok(parse_error::nom(input, parse_input)?)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Equation>> {
+    terminated(parse_equation_list, multispace0)(input)
+}
+
+fn parse_equation_list(input: &str) -> IResult<&str, Vec<Equation>> {
+    separated_list0(line_ending, parse_equation)(input)
+}
+
+fn parse_equation(input: &str) -> IResult<&str, Equation> {
+    let (rest, goal) = complete::u64(input)?;
+    let (rest, _) = tuple((tag(":"), space1))(rest)?;
+    let (rest, values) = separated_list0(space1, complete::u64)(rest)?;
+    Ok((rest, Equation { goal, values }))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    const INPUT: &str = r#"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20"#;
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 3749);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 11387);
+        Ok(())
+    }
+
+    /// Evaluates every combination of `operators` left to right, like the puzzle does.
+    fn solvable(goal: u64, values: &[u64], operators: &[fn(u64, u64) -> u64]) -> bool {
+        let (&first, rest) = values.split_first().unwrap();
+        let mut results = vec![first];
+        for &value in rest {
+            results = results
+                .iter()
+                .flat_map(|&result| operators.iter().map(move |op| op(result, value)))
+                .collect();
+        }
+        results.contains(&goal)
+    }
+
+    fn add(a: u64, b: u64) -> u64 {
+        a + b
+    }
+
+    fn mul(a: u64, b: u64) -> u64 {
+        a * b
+    }
+
+    fn concat(a: u64, b: u64) -> u64 {
+        format!("{a}{b}").parse().unwrap()
+    }
+
+    /// Equations as they appear in the input. Half of them are made solvable by computing the
+    /// goal with random operators, the others get a random goal.
+    fn equation_line() -> impl Strategy<Value = String> {
+        let values = prop::collection::vec(1..100u64, 1..7);
+        (
+            values,
+            any::<bool>(),
+            prop::collection::vec(0..3usize, 6),
+            1..10_000u64,
+        )
+            .prop_map(|(values, solvable, ops, goal)| {
+                let goal = if solvable {
+                    let operators: [fn(u64, u64) -> u64; 3] = [add, mul, concat];
+                    values[1..]
+                        .iter()
+                        .zip(ops.iter())
+                        .fold(values[0], |acc, (&v, &i)| operators[i](acc, v))
+                } else {
+                    goal
+                };
+                let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+                format!("{goal}: {}", values.join(" "))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn has_solutions_matches_brute_force(line in equation_line()) {
+            let equation = parse_error::nom(&line, parse_equation).unwrap();
+            prop_assert_eq!(
+                has_solutions(equation.goal, &equation.values),
+                solvable(equation.goal, &equation.values, &[add, mul])
+            );
+        }
+
+        #[test]
+        fn has_solutions2_matches_brute_force(line in equation_line()) {
+            let equation = parse_error::nom(&line, parse_equation).unwrap();
+            prop_assert_eq!(
+                has_solutions2(equation.goal, &equation.values),
+                solvable(equation.goal, &equation.values, &[add, mul, concat])
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+/// The city map with the positions of the antennas of each frequency.
+#[derive(Debug, Clone)]
+pub struct City {
+    map: Grid<char>,
+    antennas: BTreeMap<char, Vec<Pos>>,
+}
+
+pub fn part1(city: &City) -> anyhow::Result<usize> {
+    let mut positions: BTreeSet<Pos> = BTreeSet::new();
+    for freq_antennas in city.antennas.values() {
+        for (i, &a) in freq_antennas.iter().enumerate() {
+            for (j, &b) in freq_antennas.iter().enumerate() {
+                if i != j {
+                    let p = a + (b - a) * 2;
+                    if city.map.contains(p) {
+                        positions.insert(p);
+                    }
+                }
+            }
+        }
+    }
+    Ok(positions.len())
+}
+
+pub fn part2(city: &City) -> anyhow::Result<usize> {
+    let mut positions: BTreeSet<Pos> = BTreeSet::new();
+    for freq_antennas in city.antennas.values() {
+        for (i, &a) in freq_antennas.iter().enumerate() {
+            for (j, &b) in freq_antennas.iter().enumerate() {
+                if i != j {
+                    let delta = b - a;
+                    let mut p = b;
+                    while city.map.contains(p) {
+                        positions.insert(p);
+                        p += delta;
+                    }
+                }
+            }
+        }
+    }
+    Ok(positions.len())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<City> {
+    let map: Grid<char> = input.parse()?;
+    let mut antennas: BTreeMap<char, Vec<Pos>> = BTreeMap::new();
+    for (pos, &c) in map.iter() {
+        if c.is_alphanumeric() {
+            antennas.entry(c).or_default().push(pos);
+        }
+    }
+
+    Ok(City { map, antennas })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input<'a> = City;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"#;
+
+    const INPUT2: &str = r#"T....#....
+...T......
+.T....#...
+.........#
+..#.......
+..........
+...#......
+..........
+....#.....
+.........."#;
+
+    #[test]
+    fn test_part1() -> anyhow::Result<()> {
+        let result = part1(&parse(INPUT)?)?;
+        assert_eq!(result, 14);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT2)?)?;
+        assert_eq!(result, 9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_1() -> anyhow::Result<()> {
+        let result = part2(&parse(INPUT)?)?;
+        assert_eq!(result, 34);
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use winnow::ModalResult;
+use winnow::Parser;
+use winnow::ascii::{digit1, line_ending, multispace0};
+use winnow::combinator::{alt, eof, separated, terminated};
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Command {
+    direction: Direction,
+    distance: i32,
+}
+
+pub fn part1(commands: &[Command]) -> anyhow::Result<i32> {
+    let mut dial = 50;
+    let mut count = 0;
+    for cmd in commands.iter() {
+        match cmd.direction {
+            Direction::Left => dial -= cmd.distance,
+            Direction::Right => dial += cmd.distance,
+        }
+        let dial = dial.rem_euclid(100);
+        if dial == 0 {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn part2(commands: &[Command]) -> anyhow::Result<i32> {
+    let mut dial: i32 = 50;
+    let mut count = 0;
+    for cmd in commands.iter() {
+        let delta = match cmd.direction {
+            Direction::Left => -1,
+            Direction::Right => 1
+        };
+        for _ in 0..cmd.distance {
+            dial = (dial + delta).rem_euclid(100);
+            if dial == 0 {
+                count += 1;
+            }
+        }
+    }
+    Ok(count)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Command>> {
+    Ok(parse_error::winnow(input, terminated(parse_input, (multispace0, eof)))?)
+}
+
+fn parse_input(input: &mut &str) -> ModalResult<Vec<Command>> {
+    separated(1.., parse_command, line_ending).parse_next(input)
+}
+
+fn parse_command(input: &mut &str) -> ModalResult<Command> {
+    (parse_direction, parse_distance)
+        .map(|(direction, distance)| Command {
+            direction,
+            distance,
+        })
+        .parse_next(input)
+}
+
+fn parse_direction(input: &mut &str) -> ModalResult<Direction> {
+    alt(('L'.value(Direction::Left), 'R'.value(Direction::Right))).parse_next(input)
+}
+
+fn parse_distance(input: &mut &str) -> ModalResult<i32> {
+    digit1.parse_to::<i32>().parse_next(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<Command>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = r#"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82"#;
+        assert_eq!(part1(&parse(input).unwrap()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = r#"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82"#;
+        assert_eq!(part2(&parse(input).unwrap()).unwrap(), 6);
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use winnow::ascii::{digit1, multispace0};
+use winnow::combinator::{delimited, eof, separated, separated_pair, terminated};
+use winnow::{ModalResult, Parser};
+
+pub fn part1(ranges: &[Range]) -> anyhow::Result<u64> {
+    let mut sum = 0;
+
+    for &(a, b) in ranges.iter() {
+        for i in a..=b {
+            if is_invalid(i) {
+                sum += i;
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+pub fn part2(ranges: &[Range]) -> anyhow::Result<u64> {
+    let mut sum = 0;
+
+    for &(a, b) in ranges.iter() {
+        for i in a..=b {
+            if is_invalid2(i) {
+                sum += i;
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+fn is_invalid2(n: u64) -> bool {
+    let s = n.to_string();
+    let bs = s.as_bytes();
+    let size = bs.len();
+    for i in 1..size {
+        if size.is_multiple_of(i) {
+            let chunks = bs.chunks_exact(i).collect::<Vec<&[u8]>>();
+            if chunks.windows(2).all(|w| w[0] == w[1]) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn is_invalid(n: u64) -> bool {
+    let divisor = divisor(n);
+    divisor != 0 && (n / divisor == n % divisor)
+}
+
+fn divisor(n: u64) -> u64 {
+    let num_digits = n.ilog10() as u64 + 1;
+    if !num_digits.is_multiple_of(2) {
+        return 0;
+    }
+    let mut start: u64 = 1;
+    let mut n: u64 = num_digits / 2;
+    while n > 0 {
+        n -= 1;
+        start *= 10;
+    }
+    start
+}
+
+pub type Range = (u64, u64);
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Range>> {
+    Ok(parse_error::winnow(
+        input,
+        terminated(parse_ranges, (multispace0, eof)),
+    )?)
+}
+
+fn parse_ranges(input: &mut &str) -> ModalResult<Vec<Range>> {
+    separated(1.., parse_range, delimited(multispace0, ',', multispace0)).parse_next(input)
+}
+
+fn parse_range(input: &mut &str) -> ModalResult<Range> {
+    separated_pair(parse_int, "-", parse_int).parse_next(input)
+}
+
+fn parse_int(input: &mut &str) -> ModalResult<u64> {
+    digit1.parse_to::<u64>().parse_next(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Range>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124"#;
+
+    #[test]
+    fn test_is_invalid() {
+        assert!(is_invalid(11));
+        assert!(!is_invalid(12));
+    }
+
+    #[test]
+    fn test_is_invalid2() {
+        assert!(is_invalid2(11));
+        // assert!(!is_invalid(12));
+    }
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(INPUT).unwrap()).unwrap();
+        assert_eq!(result, 1227775554);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(INPUT).unwrap()).unwrap();
+        assert_eq!(result, 4174379265);
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use winnow::ModalResult;
+use winnow::Parser;
+use winnow::ascii::{digit1, line_ending, multispace0};
+use winnow::combinator::{eof, separated, terminated};
+
+pub fn part1(banks: &[Vec<u32>]) -> anyhow::Result<usize> {
+    let sum = banks.iter().map(|bank| get_max(bank, 2)).sum::<usize>();
+    Ok(sum)
+}
+
+pub fn part2(banks: &[Vec<u32>]) -> anyhow::Result<usize> {
+    let sum = banks.iter().map(|bank| get_max(bank, 12)).sum::<usize>();
+    Ok(sum)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<u32>>> {
+    Ok(parse_error::winnow(
+        input,
+        terminated(parse_banks, (multispace0, eof)),
+    )?)
+}
+
+fn parse_banks(input: &mut &str) -> ModalResult<Vec<Vec<u32>>> {
+    separated(1.., parse_digits, line_ending).parse_next(input)
+}
+
+fn parse_digits(input: &mut &str) -> ModalResult<Vec<u32>> {
+    digit1
+        .map(|digits: &str| {
+            digits
+                .chars()
+                .map(|digit| digit.to_digit(10).unwrap_or(0))
+                .collect::<Vec<_>>()
+        })
+        .parse_next(input)
+}
+
+fn get_max(bank: &[u32], count: usize) -> usize {
+    let mut result = 0;
+    let mut index = 0;
+
+    for size in (1..=count).rev() {
+        let (found_idx, &found_digit) = bank[index..=bank.len() - size]
+            .iter()
+            .enumerate()
+            // max_by_key returns the last occurrence of the max value
+            // we want the first one. So we reverse the slice.
+            .rev()
+            .max_by_key(|(_, digit)| *digit)
+            .unwrap();
+        index += found_idx + 1;
+        result = result * 10 + found_digit as usize;
+    }
+    result
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"987654321111111
+811111111111119
+234234234234278
+818181911112111"#;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(INPUT).unwrap()).unwrap(), 357);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()).unwrap(), 3121910778619);
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+use winnow::ModalResult;
+use winnow::Parser;
+use winnow::ascii::{line_ending, multispace0};
+use winnow::combinator::{eof, repeat, separated, terminated};
+use winnow::token::one_of;
+
+fn is_roll(grid: &Grid<char>, pos: Pos) -> bool {
+    matches!(grid.get(pos), Some('@' | 'x'))
+}
+
+fn count_adjacent(grid: &Grid<char>, pos: Pos) -> usize {
+    pos.neighbours8()
+        .into_iter()
+        .filter(|&next| is_roll(grid, next))
+        .count()
+}
+
+pub fn part1(grid: &Grid<char>) -> anyhow::Result<u64> {
+    let mut count = 0;
+    for pos in grid.positions() {
+        if grid[pos] == '@' && count_adjacent(grid, pos) < 4 {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn part2(grid: &Grid<char>) -> anyhow::Result<u64> {
+    let mut grid = grid.clone();
+    let mut count = 0;
+    //println!("{}", grid);
+    loop {
+        let mut found = 0;
+        for pos in grid.positions() {
+            if grid[pos] == '@' && count_adjacent(&grid, pos) < 4 {
+                count += 1;
+                found += 1;
+                grid[pos] = 'x';
+            }
+        }
+        if found == 0 {
+            break;
+        }
+        //println!("found: {found}");
+        // println!("{}", grid);
+        for (_, cell) in grid.iter_mut() {
+            if *cell == 'x' {
+                *cell = '.';
+            }
+        }
+    }
+    Ok(count)
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Grid<char>> {
+    let rows = parse_error::winnow(input, terminated(parse_grid, (multispace0, eof)))?;
+    Grid::from_rows(rows)
+}
+
+fn parse_grid(input: &mut &str) -> ModalResult<Vec<Vec<char>>> {
+    separated(1.., parse_row, line_ending).parse_next(input)
+}
+
+fn parse_row(input: &mut &str) -> ModalResult<Vec<char>> {
+    repeat(1.., parse_cell).parse_next(input)
+}
+
+fn parse_cell(input: &mut &str) -> ModalResult<char> {
+    one_of(['.', '@']).parse_next(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@."#;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(INPUT).unwrap()).unwrap(), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()).unwrap(), 43);
+    }
+}
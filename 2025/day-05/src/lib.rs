@@ -0,0 +1,107 @@
+use aoc_core::Solution;
+use interval_set::{Interval, IntervalSet};
+use std::fmt::Display;
+use winnow::ascii::{digit1, line_ending, multispace0, multispace1};
+use winnow::combinator::{eof, separated, separated_pair, terminated};
+use winnow::{ModalResult, Parser};
+
+#[derive(Debug, Clone)]
+pub struct Database {
+    ranges: IntervalSet<u64>,
+    numbers: Vec<u64>,
+}
+
+pub fn part1(data: &Database) -> anyhow::Result<usize> {
+    let result = data
+        .numbers
+        .iter()
+        .filter(|&&number| data.ranges.contains(number))
+        .count();
+    Ok(result)
+}
+
+pub fn part2(data: &Database) -> anyhow::Result<u64> {
+    Ok(data.ranges.coverage())
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Database> {
+    Ok(parse_error::winnow(
+        input,
+        terminated(parse_input, (multispace0, eof)),
+    )?)
+}
+
+fn parse_input(input: &mut &str) -> ModalResult<Database> {
+    separated_pair(parse_ranges, multispace1, parse_numbers)
+        .parse_next(input)
+        .map(|(ranges, numbers)| Database {
+            ranges: ranges.into_iter().collect(),
+            numbers,
+        })
+}
+
+fn parse_ranges(input: &mut &str) -> ModalResult<Vec<Interval<u64>>> {
+    separated(1.., parse_range, line_ending).parse_next(input)
+}
+
+fn parse_numbers(input: &mut &str) -> ModalResult<Vec<u64>> {
+    separated(1.., parse_number, line_ending).parse_next(input)
+}
+
+fn parse_range(input: &mut &str) -> ModalResult<Interval<u64>> {
+    separated_pair(parse_number, "-", parse_number)
+        .map(|(first, last)| Interval::inclusive(first, last))
+        .parse_next(input)
+}
+
+fn parse_number(input: &mut &str) -> ModalResult<u64> {
+    digit1.parse_to::<u64>().parse_next(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Database;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32"#;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(INPUT).unwrap()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()).unwrap(), 14);
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::Solution;
+use grid::{Grid, Pos};
+use std::fmt::Display;
+
+pub fn part1(manifold: &Manifold) -> anyhow::Result<usize> {
+    let Manifold { grid, start } = manifold;
+    let mut result = 0;
+    let mut current_beams = vec![false; grid.width()];
+    current_beams[start.col as usize] = true;
+    for row in grid.rows().skip(1) {
+        for (col, cell) in row.iter().enumerate() {
+            if *cell == '^' && current_beams[col] {
+                result += 1;
+                current_beams[col] = false;
+                current_beams[col - 1] = true;
+                current_beams[col + 1] = true;
+            }
+        }
+    }
+    Ok(result)
+}
+
+pub fn part2(manifold: &Manifold) -> anyhow::Result<usize> {
+    let Manifold { grid, start } = manifold;
+    let mut current_beams: Vec<usize> = vec![0; grid.width()];
+    current_beams[start.col as usize] = 1;
+
+    for row in grid.rows().skip(1) {
+        for (col, cell) in row.iter().enumerate() {
+            if *cell == '^' && current_beams[col] != 0 {
+                let current = current_beams[col];
+                current_beams[col] = 0;
+                current_beams[col - 1] += current;
+                current_beams[col + 1] += current;
+            }
+        }
+    }
+
+    Ok(current_beams.into_iter().sum())
+}
+
+/// The tachyon manifold together with the position of the beam's source.
+pub struct Manifold {
+    grid: Grid<char>,
+    start: Pos,
+}
+
+pub fn parse(input: &str) -> anyhow::Result<Manifold> {
+    let grid: Grid<char> = input.parse()?;
+    let start = grid.find(|c| *c == 'S').unwrap_or_default();
+    Ok(Manifold { grid, start })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Manifold;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+..............."#;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(INPUT).unwrap()).unwrap(), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(INPUT).unwrap()).unwrap(), 40);
+    }
+}
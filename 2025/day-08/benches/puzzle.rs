@@ -0,0 +1 @@
+aoc_bench::main!(y2025_day_08::Puzzle);
@@ -0,0 +1,139 @@
+use aoc_core::Solution;
+use std::fmt::Display;
+use union_find::UnionFind;
+use winnow::ascii::{digit1, line_ending, multispace0};
+use winnow::combinator::{eof, separated, terminated};
+use winnow::{ModalResult, Parser};
+
+pub fn part1(points: &[Point], n: usize) -> anyhow::Result<usize> {
+    let mut connections: Vec<(usize, usize)> =
+        Vec::with_capacity(points.len() * (points.len() + 1) / 2);
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            connections.push((i, j));
+        }
+    }
+    connections.sort_unstable_by_key(|&(i, j)| distance(&points[i], &points[j]));
+    let mut uf = UnionFind::new(points.len());
+    for &(i, j) in connections.iter().take(n) {
+        uf.union(i, j);
+    }
+    let mut sizes: Vec<usize> = uf.sizes().collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(sizes.iter().take(3).product())
+}
+
+pub fn part2(points: &[Point]) -> anyhow::Result<usize> {
+    let mut connections: Vec<(usize, usize)> =
+        Vec::with_capacity(points.len() * (points.len() + 1) / 2);
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            connections.push((i, j));
+        }
+    }
+    connections.sort_unstable_by_key(|&(i, j)| distance(&points[i], &points[j]));
+
+    let mut uf = UnionFind::new(points.len());
+    let mut result: (usize, usize) = (0, 0);
+    for (i, j) in connections.iter().copied() {
+        uf.union(i, j);
+        if uf.count() == 1 {
+            result = (i, j);
+            break;
+        }
+    }
+
+    let (i, j) = result;
+    let (x1, _y1, _z1) = points[i];
+    let (x2, _y2, _z2) = points[j];
+
+    Ok((x1 * x2) as usize)
+}
+
+fn distance((x1, y1, z1): &Point, (x2, y2, z2): &Point) -> i64 {
+    fn sqr(a: i64) -> i64 {
+        a * a
+    }
+    (sqr(x1 - x2) + sqr(y1 - y2) + sqr(z1 - z2)).isqrt()
+}
+
+pub type Point = (i64, i64, i64);
+
+pub fn parse(input: &str) -> anyhow::Result<Vec<Point>> {
+    Ok(parse_error::winnow(
+        input,
+        terminated(parse_points, (multispace0, eof)),
+    )?)
+}
+
+fn parse_points(input: &mut &str) -> ModalResult<Vec<Point>> {
+    separated(1.., parse_point, line_ending).parse_next(input)
+}
+
+fn parse_point(input: &mut &str) -> ModalResult<Point> {
+    (parse_int, ',', parse_int, ',', parse_int)
+        .map(|(x, _, y, _, z)| (x, y, z))
+        .parse_next(input)
+}
+fn parse_int(input: &mut &str) -> ModalResult<i64> {
+    digit1.parse_to::<i64>().parse_next(input)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Point>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part1(input, 1000)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689"#;
+
+    #[test]
+    fn test_part1() {
+        let result = part1(&parse(INPUT).unwrap(), 10).unwrap();
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    fn test_part2() {
+        let result = part2(&parse(INPUT).unwrap()).unwrap();
+        assert_eq!(result, 25272);
+    }
+}
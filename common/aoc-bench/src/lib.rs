@@ -0,0 +1,54 @@
+//! Criterion benchmarks shared by the day crates.
+//!
+//! A day benchmarks its [`Solution`] with a `benches/puzzle.rs` of just
+//! `aoc_bench::main!(y2024_day_6::Puzzle);`. Parsing and the parts are timed on their own as
+//! `prepare`, `part1` and `part2` in the group `<year>/day-<dd>`, and `aoc bench-report`
+//! summarizes the results. Run it with e.g. `cargo bench -p y2024-day-6 --bench puzzle`.
+
+use std::hint::black_box;
+use std::path::Path;
+
+use aoc_core::Solution;
+pub use criterion;
+use criterion::Criterion;
+
+/// Benchmarks the day in `dir` on its input, skipping it when there is none.
+pub fn bench<S: Solution>(c: &mut Criterion, dir: &str) {
+    let input = match aoc_input::load(Path::new(dir), S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping {} day {}: {e:#}", S::YEAR, S::DAY);
+            return;
+        }
+    };
+    let mut group = c.benchmark_group(format!("{}/day-{:02}", S::YEAR, S::DAY));
+    // some days take seconds per iteration
+    group.sample_size(10);
+    group.bench_function("prepare", |b| b.iter(|| S::parse(black_box(&input))));
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("cannot parse {} day {}: {e:#}", S::YEAR, S::DAY);
+            group.finish();
+            return;
+        }
+    };
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    if S::HAS_PART2 {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+/// Defines the `main` of a day's benchmark, given the path of its `Puzzle`.
+#[macro_export]
+macro_rules! main {
+    ($puzzle:path) => {
+        fn bench_puzzle(c: &mut $crate::criterion::Criterion) {
+            $crate::bench::<$puzzle>(c, env!("CARGO_MANIFEST_DIR"));
+        }
+
+        $crate::criterion::criterion_group!(benches, bench_puzzle);
+        $crate::criterion::criterion_main!(benches);
+    };
+}
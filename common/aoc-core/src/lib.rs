@@ -0,0 +1,140 @@
+use std::fmt::{self, Display};
+
+use anyhow::anyhow;
+
+/// Common interface of all puzzle solutions.
+///
+/// The raw puzzle input is parsed once by [`Solution::parse`] and then handed to both parts.
+/// Days that parse inside their part functions simply use `&str` as their `Input`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// `false` for puzzles that only have a first part, which is usually day 25.
+    const HAS_PART2: bool = true;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display>;
+
+    fn part2(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+        let _ = input;
+        Err::<String, _>(anyhow!(
+            "{} day {} has no second part",
+            Self::YEAR,
+            Self::DAY
+        ))
+    }
+}
+
+/// The answer to one part of a puzzle.
+///
+/// The parts answer with anything printable, from `i32` to letters read off a screen. Answers
+/// printed as a plain integer are kept as numbers for machine readable reports, all others are
+/// kept as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn new(answer: impl Display) -> Self {
+        let text = answer.to_string();
+        match text.parse::<i128>() {
+            // "007" or "+7" would not print the same as a number
+            Ok(number) if number.to_string() == text => Answer::Number(number),
+            _ => Answer::Text(text),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+/// A parsed puzzle input, ready to solve either part without parsing again.
+pub trait Prepared {
+    fn part1(&self) -> anyhow::Result<Answer>;
+    fn part2(&self) -> anyhow::Result<Answer>;
+
+    /// Solves part 1 or 2.
+    fn solve(&self, part: u8) -> anyhow::Result<Answer> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => Err(anyhow!("there is no part {part}")),
+        }
+    }
+}
+
+struct Parsed<'a, S: Solution> {
+    input: S::Input<'a>,
+}
+
+impl<S: Solution> Prepared for Parsed<'_, S> {
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(Answer::new(S::part1(&self.input)?))
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(Answer::new(S::part2(&self.input)?))
+    }
+}
+
+/// Parses the input once so that both parts can be solved, or timed, on their own.
+pub fn prepare<S: Solution + 'static>(input: &str) -> anyhow::Result<Box<dyn Prepared + '_>> {
+    Ok(Box::new(Parsed::<S> {
+        input: S::parse(input)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 25;
+        const HAS_PART2: bool = false;
+
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> anyhow::Result<Self::Input<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> anyhow::Result<impl Display> {
+            Ok(input.iter().map(|line| line.len()).sum::<usize>())
+        }
+    }
+
+    #[test]
+    fn prepares_input_once() {
+        let prepared = prepare::<Lengths>("ab\ncde\n").unwrap();
+        assert_eq!(prepared.part1().unwrap(), Answer::Number(5));
+        assert_eq!(prepared.solve(1).unwrap(), Answer::Number(5));
+        let err = prepared.part2().unwrap_err();
+        assert_eq!(err.to_string(), "2000 day 25 has no second part");
+        assert!(prepared.solve(3).is_err());
+    }
+
+    #[test]
+    fn keeps_numbers_apart_from_text() {
+        assert_eq!(Answer::new(-42i32), Answer::Number(-42));
+        assert_eq!(Answer::new(u64::MAX), Answer::Number(u64::MAX as i128));
+        assert_eq!(Answer::new("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::new("+7"), Answer::Text("+7".to_string()));
+        assert_eq!(Answer::new("6,4"), Answer::Text("6,4".to_string()));
+        assert_eq!(Answer::new("007").to_string(), "007");
+        assert_eq!(Answer::Number(12).to_string(), "12");
+    }
+}
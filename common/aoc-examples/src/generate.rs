@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use crate::Example;
+
+/// What a function of a day returns, which decides how a test unwraps it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Returns {
+    Result,
+    Option,
+    Value,
+}
+
+/// How the parts of a day are called, read off the `pub fn`s of its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    /// The parts take what `parse` returns, if the day has a `parse` function.
+    pub parse: Option<Returns>,
+    pub part1: Returns,
+    pub part2: Returns,
+}
+
+impl Default for Signature {
+    /// Parts that take the raw input and return a `Result`.
+    fn default() -> Self {
+        Self {
+            parse: None,
+            part1: Returns::Result,
+            part2: Returns::Result,
+        }
+    }
+}
+
+impl Signature {
+    /// Functions missing from `source` keep their [`Default`] signature.
+    pub fn of(source: &str) -> Self {
+        let default = Self::default();
+        Self {
+            parse: returns(source, "parse"),
+            part1: returns(source, "part1").unwrap_or(default.part1),
+            part2: returns(source, "part2").unwrap_or(default.part2),
+        }
+    }
+
+    fn call(&self, part: u8, input: &str) -> String {
+        let input = match self.parse {
+            Some(returns) => format!("&parse({input}){}", unwrap(returns)),
+            None => input.to_string(),
+        };
+        let returns = if part == 1 { self.part1 } else { self.part2 };
+        format!("part{part}({input}){}", unwrap(returns))
+    }
+}
+
+/// The return type of the public function `name`, if there is one.
+fn returns(source: &str, name: &str) -> Option<Returns> {
+    let start = source.find(&format!("pub fn {name}("))?;
+    let header = &source[start..];
+    let header = &header[..header.find('{').unwrap_or(header.len())];
+    let returns = header
+        .split_once("->")
+        .map_or("", |(_, returns)| returns.trim());
+    Some(
+        if returns.starts_with("anyhow::Result") || returns.starts_with("Result") {
+            Returns::Result
+        } else if returns.starts_with("Option") {
+            Returns::Option
+        } else {
+            Returns::Value
+        },
+    )
+}
+
+fn unwrap(returns: Returns) -> &'static str {
+    match returns {
+        Returns::Result => "?",
+        Returns::Option => ".unwrap()",
+        Returns::Value => "",
+    }
+}
+
+/// Generates a test module checking both parts against the examples, in the style of the
+/// existing day crates and calling the parts the way `signature` says.
+pub fn generate_tests(examples: &[Example], signature: &Signature) -> String {
+    let mut code = String::new();
+    code.push_str("#[cfg(test)]\nmod tests {\n    use super::*;\n");
+
+    let mut inputs: Vec<&str> = Vec::new();
+    for example in examples.iter() {
+        if !inputs.contains(&example.input.as_str()) {
+            inputs.push(&example.input);
+        }
+    }
+    for (idx, input) in inputs.iter().enumerate() {
+        let _ = write!(
+            code,
+            "\n    const {}: &str = {};\n",
+            input_name(idx),
+            raw_string(input.trim_end_matches('\n'))
+        );
+    }
+
+    for example in examples.iter() {
+        let idx = inputs.iter().position(|i| *i == example.input).unwrap();
+        let _ = write!(
+            code,
+            "
+    #[test]
+    fn part{part}_works() -> anyhow::Result<()> {{
+        let result = {call};
+        let expected = {expected};
+        assert_eq!(result, expected);
+        Ok(())
+    }}
+",
+            part = example.part,
+            call = signature.call(example.part, &input_name(idx)),
+            expected = literal(&example.answer),
+        );
+    }
+    code.push_str("}\n");
+    code
+}
+
+fn input_name(idx: usize) -> String {
+    if idx == 0 {
+        "INPUT".to_string()
+    } else {
+        format!("INPUT{}", idx + 1)
+    }
+}
+
+/// Numbers stay numbers so that they compare with the integer results of most days.
+fn literal(answer: &str) -> String {
+    if !answer.is_empty() && answer.parse::<i128>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{answer:?}")
+    }
+}
+
+fn raw_string(text: &str) -> String {
+    let mut hashes = "#".to_string();
+    while text.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{text}\"{hashes}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_repo_style_tests() {
+        let example = |part: u8, input: &str, answer: &str| Example {
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        let code = generate_tests(
+            &[
+                example(1, "1 2\n3 4\n", "114"),
+                example(2, "\"#\n", "4,6,3"),
+            ],
+            &Signature::default(),
+        );
+        assert_eq!(
+            code,
+            r###"#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"1 2
+3 4"#;
+
+    const INPUT2: &str = r##""#"##;
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        let result = part1(INPUT)?;
+        let expected = 114;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> anyhow::Result<()> {
+        let result = part2(INPUT2)?;
+        let expected = "4,6,3";
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
+"###
+        );
+    }
+
+    #[test]
+    fn calls_parts_like_the_day() {
+        let source = "pub fn part1(input: &str) -> Option<usize> {}
+pub fn part2(commands: &[char]) -> i64 {}
+pub fn parse(input: &str) -> anyhow::Result<Vec<char>> {}
+";
+        let signature = Signature::of(source);
+        assert_eq!(
+            signature,
+            Signature {
+                parse: Some(Returns::Result),
+                part1: Returns::Option,
+                part2: Returns::Value,
+            }
+        );
+        assert_eq!(signature.call(1, "INPUT"), "part1(&parse(INPUT)?).unwrap()");
+        assert_eq!(signature.call(2, "INPUT2"), "part2(&parse(INPUT2)?)");
+        assert_eq!(Signature::of(""), Signature::default());
+        assert_eq!(Signature::default().call(1, "INPUT"), "part1(INPUT)?");
+    }
+}
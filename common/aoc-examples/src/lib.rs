@@ -0,0 +1,22 @@
+//! Example inputs and expected answers taken from a saved puzzle page.
+//!
+//! Each part of a puzzle is an `<article>` on the page. The example of a part is its first
+//! `<pre><code>` block and the expected answer is the last emphasized `<code><em>` in the
+//! article, which is where the puzzle text states the example's result. The second part
+//! usually has no example block of its own and reuses the one of the first part.
+
+mod generate;
+mod scrape;
+mod verify;
+
+pub use generate::{generate_tests, Returns, Signature};
+pub use scrape::scrape;
+pub use verify::verify_tests;
+
+/// The example and expected answer of one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
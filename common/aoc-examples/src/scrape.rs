@@ -0,0 +1,141 @@
+use anyhow::bail;
+
+use crate::Example;
+
+/// Finds the examples of all parts on a puzzle page. Parts without an expected answer, like
+/// the second part of a page saved before it was unlocked, are left out.
+pub fn scrape(html: &str) -> anyhow::Result<Vec<Example>> {
+    let articles = between_all(html, "<article", "</article>");
+    if articles.is_empty() {
+        bail!("no puzzle description found on the page");
+    }
+    let mut examples: Vec<Example> = Vec::new();
+    let mut last_input = None;
+    for (idx, article) in articles.iter().enumerate() {
+        let input = between_all(article, "<pre><code>", "</code></pre>")
+            .first()
+            .map(|block| decode_entities(&strip_tags(block)));
+        let input = match input.or(last_input.clone()) {
+            Some(input) => input,
+            None => continue,
+        };
+        last_input = Some(input.clone());
+        if let Some(answer) = last_answer(article) {
+            examples.push(Example {
+                part: idx as u8 + 1,
+                input,
+                answer,
+            });
+        }
+    }
+    if examples.is_empty() {
+        bail!("no examples with an answer found on the page");
+    }
+    Ok(examples)
+}
+
+/// The text of the last emphasized code in the article, written either as
+/// `<code><em>` or as `<em><code>`.
+fn last_answer(article: &str) -> Option<String> {
+    ["<code><em>", "<em><code>"]
+        .iter()
+        .filter_map(|start| article.rfind(start).map(|pos| pos + start.len()))
+        .max()
+        .and_then(|pos| {
+            let rest = &article[pos..];
+            rest.find("</").map(|end| decode_entities(&rest[..end]))
+        })
+}
+
+/// All the substrings between `start` and the following `end`, excluding both. `start` may
+/// be the beginning of a tag with attributes, which are skipped.
+fn between_all<'a>(text: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut result = Vec::new();
+    let mut rest = text;
+    while let Some(pos) = rest.find(start) {
+        rest = &rest[pos + start.len()..];
+        if !start.ends_with('>') {
+            match rest.find('>') {
+                Some(pos) => rest = &rest[pos + 1..],
+                None => break,
+            }
+        }
+        match rest.find(end) {
+            Some(pos) => {
+                result.push(&rest[..pos]);
+                rest = &rest[pos + end.len()..];
+            }
+            None => break,
+        }
+    }
+    result
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 <em>6</em> 10 15 21
+</code></pre>
+<p>The first step is <code><em>3</em></code>, and so on.</p>
+<p>Adding them all gives <code><em>114</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1684566095</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The sum is <code><em>a&lt;b</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn finds_examples_and_answers() {
+        let examples = scrape(PAGE).unwrap();
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n";
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: 1,
+                    input: input.to_string(),
+                    answer: "114".to_string()
+                },
+                Example {
+                    part: 2,
+                    input: input.to_string(),
+                    answer: "a<b".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn needs_a_puzzle() {
+        let err = scrape("<html></html>").unwrap_err();
+        assert_eq!(err.to_string(), "no puzzle description found on the page");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::Example;
+
+/// Checks that the test modules of a day's source files use every example input and expected
+/// answer, returning a description of everything that is missing.
+pub fn verify_tests(examples: &[Example], sources: &[String]) -> Vec<String> {
+    let tests: Vec<&str> = sources
+        .iter()
+        .filter_map(|source| Some(&source[source.find("#[cfg(test)]")?..]))
+        .collect();
+    if tests.is_empty() {
+        return vec!["the source has no test module".to_string()];
+    }
+    let tests = tests.join("\n");
+    let literals: Vec<String> = string_literals(&tests)
+        .iter()
+        .map(|literal| normalize(literal))
+        .collect();
+
+    let mut problems = Vec::new();
+    for example in examples.iter() {
+        if !literals.contains(&normalize(&example.input)) {
+            problems.push(format!(
+                "part {}: the example input is missing from the tests",
+                example.part
+            ));
+        }
+        if !contains_token(&tests, &example.answer) {
+            problems.push(format!(
+                "part {}: the expected answer {} is missing from the tests",
+                example.part, example.answer
+            ));
+        }
+    }
+    problems
+}
+
+/// Ignores trailing whitespace, which editors and `rustfmt` don't preserve reliably.
+fn normalize(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_end().to_string()
+}
+
+fn contains_token(text: &str, token: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(token).any(|(pos, _)| {
+        let before = text[..pos].chars().next_back();
+        let after = text[pos + token.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+/// The contents of the plain and raw string literals in Rust source, with the common escapes
+/// of plain strings resolved.
+fn string_literals(source: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut rest = source;
+    while let Some(pos) = rest.find('"') {
+        let prefix = &rest[..pos];
+        let hashes = prefix.len() - prefix.trim_end_matches('#').len();
+        let is_raw = prefix[..prefix.len() - hashes].ends_with('r');
+        let body = &rest[pos + 1..];
+        if is_raw {
+            let end = format!("\"{}", "#".repeat(hashes));
+            let Some(len) = body.find(&end) else { break };
+            literals.push(body[..len].to_string());
+            rest = &body[len + end.len()..];
+        } else {
+            let mut literal = String::new();
+            let mut chars = body.char_indices();
+            let mut len = body.len();
+            while let Some((idx, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        len = idx + 1;
+                        break;
+                    }
+                    '\\' => match chars.next() {
+                        Some((_, 'n')) => literal.push('\n'),
+                        Some((_, 't')) => literal.push('\t'),
+                        // a line continuation skips the leading whitespace of the next line
+                        Some((_, '\n')) => {
+                            let skipped = body[idx + 2..]
+                                .chars()
+                                .take_while(|c| c.is_whitespace())
+                                .count();
+                            for _ in 0..skipped {
+                                chars.next();
+                            }
+                        }
+                        Some((_, c)) => literal.push(c),
+                        None => {}
+                    },
+                    c => literal.push(c),
+                }
+            }
+            literals.push(literal);
+            rest = &body[len.min(body.len())..];
+        }
+    }
+    literals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r###"pub fn part1(input: &str) -> anyhow::Result<i64> { todo!() }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = r#"0 3 6
+1 3 6"#;
+
+    const INPUT2: &str = "ab\n\
+        cd\n";
+
+    #[test]
+    fn part1_works() -> anyhow::Result<()> {
+        assert_eq!(part1(INPUT)?, 1140);
+        Ok(())
+    }
+}
+"###;
+
+    #[test]
+    fn finds_missing_examples() {
+        let example = |part: u8, input: &str, answer: &str| Example {
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        let sources = [SOURCE.to_string()];
+        assert!(verify_tests(&[example(1, "0 3 6 \n1 3 6\n", "1140")], &sources).is_empty());
+        assert!(verify_tests(&[example(2, "ab\ncd\n", "1140")], &sources).is_empty());
+        assert_eq!(
+            verify_tests(&[example(1, "0 3 6\n", "114")], &sources),
+            vec![
+                "part 1: the example input is missing from the tests",
+                "part 1: the expected answer 114 is missing from the tests",
+            ]
+        );
+        assert_eq!(
+            verify_tests(&[example(1, "0", "0")], &["fn main() {}".to_string()]),
+            vec!["the source has no test module"]
+        );
+    }
+
+    #[test]
+    fn checks_every_source_file() {
+        let example = Example {
+            part: 2,
+            input: "0 3 6\n1 3 6\n".to_string(),
+            answer: "42".to_string(),
+        };
+        let other = "#[cfg(test)]\nmod tests {\n    const EXPECTED: u32 = 42;\n}\n";
+        let sources = ["fn main() {}".to_string(), SOURCE.to_string()];
+        assert_eq!(
+            verify_tests(std::slice::from_ref(&example), &sources),
+            vec!["part 2: the expected answer 42 is missing from the tests"]
+        );
+        let sources = [SOURCE.to_string(), other.to_string()];
+        assert!(verify_tests(&[example], &sources).is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+//! Random puzzle inputs, to stress the solutions with inputs bigger than the real ones.
+//!
+//! The generators write inputs in the format of their puzzle, which the day crates parse like
+//! their real inputs. Their size is tunable, with a default close to the real inputs, and the
+//! same seed always gives the same input.
+
+use anyhow::anyhow;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod y2022_day_15;
+mod y2023_day_17;
+mod y2023_day_20;
+mod y2023_day_22;
+mod y2023_day_24;
+mod y2024_day_16;
+
+/// The input generator of one puzzle.
+#[derive(Debug)]
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// What the size counts, like "sensors".
+    pub unit: &'static str,
+    /// About the size of the real inputs.
+    pub default_size: usize,
+    generate: fn(&mut StdRng, usize) -> String,
+}
+
+impl Generator {
+    /// An input of about `size` units. Sizes too small for the puzzle are rounded up.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+pub static GENERATORS: [Generator; 6] = [
+    Generator {
+        year: 2022,
+        day: 15,
+        unit: "sensors",
+        default_size: 30,
+        generate: y2022_day_15::generate,
+    },
+    Generator {
+        year: 2023,
+        day: 17,
+        unit: "blocks per side",
+        default_size: 141,
+        generate: y2023_day_17::generate,
+    },
+    Generator {
+        year: 2023,
+        day: 20,
+        unit: "counters",
+        default_size: 4,
+        generate: y2023_day_20::generate,
+    },
+    Generator {
+        year: 2023,
+        day: 22,
+        unit: "bricks",
+        default_size: 1200,
+        generate: y2023_day_22::generate,
+    },
+    Generator {
+        year: 2023,
+        day: 24,
+        unit: "hailstones",
+        default_size: 300,
+        generate: y2023_day_24::generate,
+    },
+    Generator {
+        year: 2024,
+        day: 16,
+        unit: "tiles per side",
+        default_size: 141,
+        generate: y2024_day_16::generate,
+    },
+];
+
+pub fn find(year: u16, day: u8) -> anyhow::Result<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|g| g.year == year && g.day == day)
+        .ok_or_else(|| anyhow!("there is no input generator for {year} day {day}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_the_same_input_for_a_seed() -> anyhow::Result<()> {
+        for generator in GENERATORS.iter() {
+            let input = generator.generate(10, 7);
+            assert_eq!(generator.generate(10, 7), input);
+            assert_ne!(generator.generate(10, 8), input);
+        }
+        assert!(find(2023, 1).is_err());
+        assert_eq!(find(2023, 22)?.unit, "bricks");
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc-gen",
+    about = "Generates random puzzle inputs to stress the solutions"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generates an input for a day
+    Generate {
+        year: u16,
+        day: u8,
+        /// Size of the input, in the unit shown by `list`. Defaults to about the real size
+        #[arg(short, long)]
+        size: Option<usize>,
+        /// Seed of the random numbers. A random seed is picked and printed if omitted
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Writes the input to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Lists the days with an input generator
+    List,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let generator = aoc_gen::find(year, day)?;
+            let seed = seed.unwrap_or_else(|| {
+                let seed = rand::random();
+                eprintln!("seed: {seed}");
+                seed
+            });
+            let input = generator.generate(size.unwrap_or(generator.default_size), seed);
+            match output {
+                Some(path) => fs::write(&path, input)
+                    .with_context(|| format!("cannot write {}", path.display()))?,
+                None => print!("{input}"),
+            }
+        }
+        Command::List => {
+            for generator in aoc_gen::GENERATORS.iter() {
+                println!(
+                    "{}/{:02}: size in {}, {} by default",
+                    generator.year, generator.day, generator.unit, generator.default_size
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Sensors and their closest beacons in the 4000000 x 4000000 search area.
+//!
+//! A random spot is kept free for the distress beacon by picking beacons closer to their sensor
+//! than that spot. Other spots may stay uncovered too, so the second part can find another one.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const LIMIT: i32 = 4_000_000;
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let distress = (rng.gen_range(0..=LIMIT), rng.gen_range(0..=LIMIT));
+    let mut input = String::new();
+    let mut sensors = 0;
+    while sensors < size.max(1) {
+        let sensor = (rng.gen_range(0..=LIMIT), rng.gen_range(0..=LIMIT));
+        let distance = (sensor.0 - distress.0).abs() + (sensor.1 - distress.1).abs();
+        if distance < 2 {
+            continue;
+        }
+        let radius = rng.gen_range(distance / 2..distance);
+        let dx = rng.gen_range(-radius..=radius);
+        let dy = (radius - dx.abs()) * if rng.gen() { 1 } else { -1 };
+        input.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.0,
+            sensor.1,
+            sensor.0 + dx,
+            sensor.1 + dy
+        ));
+        sensors += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2022, 15)?.generate(10, seed);
+            assert_eq!(input.lines().count(), 10);
+            let entries = y2022_day_15::parse(&input)?;
+            y2022_day_15::part1(&entries, 2_000_000)?;
+            y2022_day_15::part2(&entries, 4_000_000)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+//! Heat loss maps: square grids of digits from 1 to 9.
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2);
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(1..=9))));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2023, 17)?.generate(12, seed);
+            assert_eq!(input.lines().count(), 12);
+            let grid = y2023_day_17::parse(&input)?;
+            y2023_day_17::part1(&grid)?;
+            y2023_day_17::part2(&grid)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+//! Module networks built like the real inputs: binary counters that feed `rx` through a final
+//! conjunction.
+//!
+//! Every counter is a chain of 12 flip-flops next to a conjunction. The flip-flops of the bits
+//! set in the counter's period report to the conjunction, and the others are reset by it, so that
+//! it sends a low pulse every period presses. An inverter turns that into a high pulse for the
+//! final conjunction, which sends a low pulse to `rx` once all counters do so in the same press.
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const BITS: usize = 12;
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let counters = size.max(1);
+    let mut names = names(rng, counters * (BITS + 2) + 1);
+    let last = names.pop().unwrap();
+
+    let mut lines = Vec::new();
+    let mut starts = Vec::new();
+    for _ in 0..counters {
+        let period = rng.gen_range(1 << (BITS - 1)..1 << BITS) | 1;
+        let flip_flops: Vec<String> = names.drain(..BITS).collect();
+        let hub = names.pop().unwrap();
+        let inverter = names.pop().unwrap();
+
+        let mut hub_outputs = vec![inverter.clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            }
+            if period & (1 << bit) == 0 || bit == 0 {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        hub_outputs.shuffle(rng);
+        lines.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("&{last} -> rx"));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    lines.join("\n") + "\n"
+}
+
+/// Distinct random module names, as short as their count allows.
+fn names(rng: &mut StdRng, count: usize) -> Vec<String> {
+    let mut len = 2;
+    while 26usize.pow(len) < 4 * count {
+        len += 1;
+    }
+    let mut names = HashSet::from(["rx".to_string(), "broadcaster".to_string()]);
+    let mut result = Vec::new();
+    while result.len() < count {
+        let name: String = (0..len)
+            .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+            .collect();
+        if names.insert(name.clone()) {
+            result.push(name);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2023, 20)?.generate(2, seed);
+            assert_eq!(input.lines().count(), 2 * 14 + 2);
+            let state = y2023_day_20::parse(&input)?;
+            y2023_day_20::part1(&state)?;
+            y2023_day_20::part2(&state)?;
+        }
+        Ok(())
+    }
+}
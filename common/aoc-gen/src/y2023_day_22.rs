@@ -0,0 +1,61 @@
+//! Snapshots of falling bricks in a 10 x 10 column, with taller columns for more bricks.
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let height = size as i64 / 3 + 10;
+    let mut occupied: HashSet<[i64; 3]> = HashSet::new();
+    let mut input = String::new();
+    let mut bricks = 0;
+    while bricks < size {
+        let start = [
+            rng.gen_range(0..10),
+            rng.gen_range(0..10),
+            rng.gen_range(1..=height),
+        ];
+        let axis = rng.gen_range(0..3);
+        let mut end = start;
+        end[axis] += rng.gen_range(0..5);
+        if axis < 2 {
+            end[axis] = end[axis].min(9);
+        }
+        let cubes: Vec<[i64; 3]> = (start[axis]..=end[axis])
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] = i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+        occupied.extend(cubes);
+        input.push_str(&format!(
+            "{},{},{}~{},{},{}\n",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+        bricks += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2023, 22)?.generate(60, seed);
+            assert_eq!(input.lines().count(), 60);
+            let bricks = y2023_day_22::parse(&input)?;
+            y2023_day_22::part1(&bricks)?;
+            y2023_day_22::part2(&bricks)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Hailstones that a single rock thrown from an integer position hits at integer times.
+//!
+//! Every hailstone is placed where the rock passes at its collision time, moved back by its own
+//! velocity. No hailstone shares a velocity component with the rock, which would leave the second
+//! part without a way to tell the rock's velocity.
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+struct Hailstone {
+    pos: [i64; 3],
+    vel: [i64; 3],
+}
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let (_, hailstones) = throw(rng, size);
+    let mut input = String::new();
+    for Hailstone { pos, vel } in hailstones {
+        input.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]
+        ));
+    }
+    input
+}
+
+/// The rock and the hailstones it hits.
+fn throw(rng: &mut StdRng, size: usize) -> (Hailstone, Vec<Hailstone>) {
+    let rock = Hailstone {
+        pos: [(); 3].map(|_| rng.gen_range(100_000_000_000_000..300_000_000_000_000)),
+        vel: [(); 3].map(|_| rng.gen_range(-200..=200)),
+    };
+    let mut times = HashSet::new();
+    let mut hailstones = Vec::new();
+    while hailstones.len() < size.max(1) {
+        let time: i64 = rng.gen_range(10_000_000_000..1_000_000_000_000);
+        if !times.insert(time) {
+            continue;
+        }
+        let vel = rock.vel.map(|v| loop {
+            let vel = rng.gen_range(-300..=300);
+            if vel != v {
+                break vel;
+            }
+        });
+        let pos = [0, 1, 2].map(|i| rock.pos[i] + (rock.vel[i] - vel[i]) * time);
+        hailstones.push(Hailstone { pos, vel });
+    }
+    (rock, hailstones)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2023, 24)?.generate(20, seed);
+            assert_eq!(input.lines().count(), 20);
+            y2023_day_24::part1(&y2023_day_24::parse(&input)?)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn finds_the_rock() -> anyhow::Result<()> {
+        let mut rng = StdRng::seed_from_u64(24);
+        let (rock, _) = throw(&mut rng, 300);
+        let input = generate(&mut StdRng::seed_from_u64(24), 300);
+        assert_eq!(
+            y2023_day_24::part2(&y2023_day_24::parse(&input)?)?,
+            rock.pos.iter().sum::<i64>()
+        );
+        Ok(())
+    }
+}
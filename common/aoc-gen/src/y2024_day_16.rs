@@ -0,0 +1,76 @@
+//! Reindeer mazes with the start in the lower left and the end in the upper right corner.
+//!
+//! The corridors are carved by a randomized depth first search between the tiles at odd
+//! positions, which leaves a single path between any two of them. Like in the real inputs,
+//! some more walls are then knocked down, so that there are several ways to the end.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub(crate) fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = (size.max(5) - 1) / 2 * 2 + 1;
+    let mut tiles = vec![vec!['#'; size]; size];
+
+    let mut stack: Vec<(usize, usize)> = vec![(size - 2, 1)];
+    tiles[size - 2][1] = '.';
+    while let Some(&(row, col)) = stack.last() {
+        let mut next: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .filter_map(|&(dr, dc)| {
+                let next = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+                (next.0 < size - 1 && next.1 < size - 1 && tiles[next.0][next.1] == '#')
+                    .then_some(next)
+            })
+            .collect();
+        next.shuffle(rng);
+        match next.first() {
+            Some(&(next_row, next_col)) => {
+                tiles[(row + next_row) / 2][(col + next_col) / 2] = '.';
+                tiles[next_row][next_col] = '.';
+                stack.push((next_row, next_col));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // walls between two corridors, which are at an odd position in one direction only
+    for _ in 0..size * size / 40 {
+        let row = rng.gen_range(1..size - 1);
+        let col = if row % 2 == 0 {
+            rng.gen_range(0..size / 2) * 2 + 1
+        } else {
+            rng.gen_range(1..size / 2) * 2
+        };
+        tiles[row][col] = '.';
+    }
+
+    tiles[size - 2][1] = 'S';
+    tiles[1][size - 2] = 'E';
+    let mut input = String::new();
+    for row in tiles {
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find;
+
+    #[test]
+    fn is_parsed() -> anyhow::Result<()> {
+        for seed in 0..5 {
+            let input = find(2024, 16)?.generate(20, seed);
+            assert_eq!(input.lines().count(), 19);
+            assert!(input.lines().all(|line| line.len() == 19));
+            let maze = y2024_day_16::parse(&input)?;
+            y2024_day_16::part1(&maze)?;
+            y2024_day_16::part2(&maze)?;
+        }
+        Ok(())
+    }
+}
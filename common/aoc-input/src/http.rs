@@ -0,0 +1,161 @@
+use std::env;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail};
+
+use crate::Fetch;
+
+/// Downloads inputs from `<base url>/<year>/day/<day>/input` with a session cookie.
+///
+/// Requests are spaced at least `min_interval` apart so that fetching a whole year doesn't
+/// hammer the site.
+#[derive(Debug)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: Option<String>,
+    user_agent: String,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+    pub const USER_AGENT: &'static str = "github.com/ralli/advent-of-code input fetcher";
+
+    /// An empty session means that there is none and fetching fails.
+    pub fn new(session: impl Into<String>) -> Self {
+        let session = session.into().trim().to_string();
+        Self {
+            base_url: Self::BASE_URL.to_string(),
+            session: (!session.is_empty()).then_some(session),
+            user_agent: Self::USER_AGENT.to_string(),
+            min_interval: Duration::from_secs(3),
+            last_request: Mutex::new(None),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Uses the session in `$AOC_SESSION` and the user agent in `$AOC_USER_AGENT` if set.
+    /// Without a session every fetch fails, but cached inputs can still be read.
+    pub fn from_env() -> Self {
+        let mut fetcher = Self::new(env::var("AOC_SESSION").unwrap_or_default());
+        if let Ok(user_agent) = env::var("AOC_USER_AGENT") {
+            fetcher.user_agent = user_agent;
+        }
+        fetcher
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sleeps until `min_interval` has passed since the previous request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl Fetch for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| anyhow!("AOC_SESSION is not set"))?;
+        self.throttle();
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", &self.user_agent)
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let message = body.lines().next().unwrap_or_default();
+                bail!("{url} returned {status}: {message}")
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockServer;
+
+    #[test]
+    fn sends_session_and_user_agent() {
+        let server = MockServer::start();
+        server.respond("/2022/day/3/input", 200, "vJrwpWtwJgWrhcsFMMfFFhFp\n");
+        server.respond("/2022/day/4/input", 404, "not found\n");
+        let fetcher = HttpFetcher::new("abc\n")
+            .with_base_url(server.url())
+            .with_user_agent("tests")
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(
+            fetcher.fetch(2022, 3).unwrap(),
+            "vJrwpWtwJgWrhcsFMMfFFhFp\n"
+        );
+        let err = fetcher.fetch(2022, 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{}/2022/day/4/input returned 404: not found", server.url())
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, "/2022/day/3/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert_eq!(requests[0].header("user-agent"), Some("tests"));
+    }
+
+    #[test]
+    fn spaces_requests() {
+        let server = MockServer::start();
+        server.respond("/2022/day/1/input", 200, "1\n");
+        let fetcher = HttpFetcher::new("abc")
+            .with_base_url(server.url())
+            .with_min_interval(Duration::from_millis(100));
+
+        let start = Instant::now();
+        fetcher.fetch(2022, 1).unwrap();
+        fetcher.fetch(2022, 1).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn needs_a_session() {
+        let fetcher = HttpFetcher::new(" ");
+        assert_eq!(
+            fetcher.fetch(2022, 1).unwrap_err().to_string(),
+            "AOC_SESSION is not set"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Puzzle inputs from a local cache, downloaded from the Advent of Code site on a miss.
+//!
+//! Inputs are cached as `<cache dir>/<year>/day-<dd>.txt`. Where the missing inputs come
+//! from is decided by a [`Fetch`] implementation: [`HttpFetcher`] downloads them with the
+//! session cookie of a logged in user, and tests point it at a [`MockServer`] instead.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::Context;
+
+mod http;
+mod mock;
+
+pub use http::HttpFetcher;
+pub use mock::{MockServer, Request};
+
+/// Looks for the input file inside the directory of a day crate. The days use different
+/// naming schemes, so the common ones are tried in turn.
+pub fn local_input(dir: &Path, day: u8) -> Option<PathBuf> {
+    let dir_name = dir.file_name()?.to_string_lossy().to_string();
+    [
+        "input.txt".to_string(),
+        format!("{dir_name}.txt"),
+        format!("day-{day}.txt"),
+    ]
+    .into_iter()
+    .map(|name| dir.join(name))
+    .find(|path| path.is_file())
+}
+
+/// The input of a day: its local input file if it has one, otherwise the shared download
+/// cache.
+pub fn load(dir: &Path, year: u16, day: u8) -> anyhow::Result<String> {
+    match local_input(dir, day) {
+        Some(path) => {
+            fs::read_to_string(&path).with_context(|| format!("cannot load {}", path.display()))
+        }
+        None => InputCache::shared()
+            .get(year, day)
+            .with_context(|| format!("no input file for {year} day {day} in {}", dir.display())),
+    }
+}
+
+/// A source of puzzle inputs.
+pub trait Fetch {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String>;
+}
+
+/// Puzzle inputs cached in a directory, fetched once when they are first needed.
+///
+/// Misses are fetched one at a time, so threads sharing a cache never download the same
+/// input twice and the fetcher's throttling applies to all of them.
+#[derive(Debug)]
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+    fetching: Mutex<()>,
+}
+
+impl InputCache<HttpFetcher> {
+    /// The cache in `$AOC_CACHE_DIR`, or `~/.cache/aoc`, downloading with the session in
+    /// `$AOC_SESSION`.
+    pub fn from_env() -> Self {
+        let dir = match env::var_os("AOC_CACHE_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => env::var_os("HOME")
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(".cache/aoc"),
+        };
+        InputCache::new(dir, HttpFetcher::from_env())
+    }
+
+    /// The cache from [`InputCache::from_env`] shared by the whole process, so that all its
+    /// fetches are serialized and throttled together.
+    pub fn shared() -> &'static Self {
+        static CACHE: OnceLock<InputCache<HttpFetcher>> = OnceLock::new();
+        CACHE.get_or_init(InputCache::from_env)
+    }
+}
+
+impl<F: Fetch> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher,
+            fetching: Mutex::new(()),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// Returns the cached input, fetching and storing it first if it isn't cached yet.
+    pub fn get(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let path = self.path(year, day);
+        if path.is_file() {
+            return Self::load(&path);
+        }
+        let _fetching = self.fetching.lock().unwrap_or_else(|e| e.into_inner());
+        // another thread may have fetched it while this one was waiting
+        if path.is_file() {
+            return Self::load(&path);
+        }
+        let input = self
+            .fetcher
+            .fetch(year, day)
+            .with_context(|| format!("cannot fetch the input of {year} day {day}"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("cannot create {}", parent.display()))?;
+        }
+        fs::write(&path, &input).with_context(|| format!("cannot write {}", path.display()))?;
+        Ok(input)
+    }
+
+    fn load(path: &Path) -> anyhow::Result<String> {
+        fs::read_to_string(path).with_context(|| format!("cannot load {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    struct Counting {
+        calls: Cell<usize>,
+    }
+
+    impl Fetch for Counting {
+        fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            if day > 25 {
+                anyhow::bail!("no such day");
+            }
+            Ok(format!("{year}-{day}\n"))
+        }
+    }
+
+    #[test]
+    fn fetches_each_input_once() {
+        let dir = env::temp_dir().join(format!("aoc-input-cache-{}", std::process::id()));
+        let cache = InputCache::new(
+            &dir,
+            Counting {
+                calls: Cell::new(0),
+            },
+        );
+
+        assert_eq!(cache.get(2023, 5).unwrap(), "2023-5\n");
+        assert_eq!(cache.get(2023, 5).unwrap(), "2023-5\n");
+        assert_eq!(cache.fetcher.calls.get(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2023/day-05.txt")).unwrap(),
+            "2023-5\n"
+        );
+
+        let err = cache.get(2023, 26).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "cannot fetch the input of 2023 day 26: no such day"
+        );
+        assert!(!cache.path(2023, 26).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct Slow {
+        calls: AtomicUsize,
+        running: AtomicUsize,
+    }
+
+    impl Fetch for Slow {
+        fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(self.running.fetch_add(1, Ordering::SeqCst), 0);
+            thread::sleep(Duration::from_millis(20));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(format!("{year}-{day}\n"))
+        }
+    }
+
+    #[test]
+    fn fetches_one_input_at_a_time() {
+        let dir = env::temp_dir().join(format!("aoc-input-shared-{}", std::process::id()));
+        let cache = InputCache::new(
+            &dir,
+            Slow {
+                calls: AtomicUsize::new(0),
+                running: AtomicUsize::new(0),
+            },
+        );
+
+        thread::scope(|s| {
+            for day in [1, 1, 1, 2, 2, 3] {
+                let cache = &cache;
+                s.spawn(move || assert_eq!(cache.get(2024, day).unwrap(), format!("2024-{day}\n")));
+            }
+        });
+        assert_eq!(cache.fetcher.calls.load(Ordering::SeqCst), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+
+/// The recorded answers of one year, keyed by day and part.
+///
+/// The file has one `day.part: answer` line per answer. Answers spanning several lines
+/// are stored with escaped newlines. Blank lines and `#` comments are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// Location of the answers file of a year, next to the year's day crates.
+    pub fn path(year: u16) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../..")
+            .join(year.to_string())
+            .join("answers.txt")
+    }
+
+    /// Loads an answers file, a missing file has no answers yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .with_context(|| format!("cannot parse {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("cannot load {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("cannot write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn has_day(&self, day: u8) -> bool {
+        self.answers.keys().any(|&(d, _)| d == day)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (idx, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_line = || -> anyhow::Result<(u8, u8, String)> {
+                let (key, answer) = line.split_once(':').ok_or_else(|| anyhow!("missing ':'"))?;
+                let (day, part) = key
+                    .trim()
+                    .split_once('.')
+                    .ok_or_else(|| anyhow!("expected day.part, got '{key}'"))?;
+                let answer = answer.strip_prefix(' ').unwrap_or(answer);
+                Ok((day.parse()?, part.parse()?, unescape(answer)?))
+            };
+            let (day, part, answer) = parse_line().with_context(|| format!("line {}", idx + 1))?;
+            answers.insert(day, part, answer);
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# recorded by `aoc check --bless`")?;
+        for ((day, part), answer) in self.answers.iter() {
+            writeln!(f, "{day}.{part}: {}", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> anyhow::Result<String> {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            other => bail!("invalid escape sequence '\\{}'", other.unwrap_or(' ')),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.insert(10, 2, " #\n# ".to_string());
+        answers.insert(1, 1, "42".to_string());
+        answers.insert(1, 2, "a\\b".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(
+            text,
+            "# recorded by `aoc check --bless`\n1.1: 42\n1.2: a\\\\b\n10.2:  #\\n# \n"
+        );
+        let parsed: Answers = text.parse().unwrap();
+        assert_eq!(parsed.get(1, 1), Some("42"));
+        assert_eq!(parsed.get(1, 2), Some("a\\b"));
+        assert_eq!(parsed.get(10, 2), Some(" #\n# "));
+        assert!(parsed.has_day(10));
+        assert!(!parsed.has_day(2));
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let err = "1.1: 5\n\n1-2: 6".parse::<Answers>().unwrap_err();
+        assert_eq!(format!("{err:#}"), "line 3: expected day.part, got '1-2'");
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+/// The mean time of one benchmark, and of a saved baseline of it if there is one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// The day, e.g. `2024/day-06`.
+    pub group: String,
+    /// `prepare`, `part1` or `part2`.
+    pub function: String,
+    pub mean: f64,
+    pub baseline: Option<f64>,
+}
+
+impl Measurement {
+    /// Relative change against the baseline in percent, positive when slower.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.map(|base| (self.mean - base) / base * 100.0)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimate {
+    /// Nanoseconds per iteration.
+    point_estimate: f64,
+}
+
+/// Where `cargo bench` leaves its results in each of the year workspaces.
+pub fn default_dirs() -> anyhow::Result<Vec<PathBuf>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let mut dirs: Vec<PathBuf> = subdirs(&root)?
+        .into_iter()
+        .filter(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.parse::<u16>().is_ok())
+        })
+        .map(|dir| dir.join("target/criterion"))
+        .filter(|dir| dir.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Reads the latest criterion results of the day benchmarks below `dirs`, each laid out as
+/// `<group>/<function>/new/{benchmark,estimates}.json` with baselines next to `new`.
+pub fn load(dirs: &[PathBuf], baseline: Option<&str>) -> anyhow::Result<Vec<Measurement>> {
+    if dirs.is_empty() {
+        bail!("no benchmark results, run `cargo bench --bench puzzle` in a year workspace first");
+    }
+    let mut measurements = Vec::new();
+    for dir in dirs.iter() {
+        if !dir.is_dir() {
+            bail!("no benchmark results in {}", dir.display());
+        }
+        load_dir(dir, baseline, &mut measurements)?;
+    }
+    measurements.sort_by(|a, b| (&a.group, &a.function).cmp(&(&b.group, &b.function)));
+    Ok(measurements)
+}
+
+fn load_dir(
+    dir: &Path,
+    baseline: Option<&str>,
+    measurements: &mut Vec<Measurement>,
+) -> anyhow::Result<()> {
+    for group_dir in subdirs(dir)? {
+        for function_dir in subdirs(&group_dir)? {
+            let new_dir = function_dir.join("new");
+            if !new_dir.join("benchmark.json").is_file() {
+                continue;
+            }
+            let id: BenchmarkId = read_json(&new_dir.join("benchmark.json"))?;
+            let Some(function) = id.function_id else {
+                continue;
+            };
+            let estimates: Estimates = read_json(&new_dir.join("estimates.json"))?;
+            let baseline = match baseline {
+                Some(name) => {
+                    let path = function_dir.join(name).join("estimates.json");
+                    path.is_file()
+                        .then(|| read_json::<Estimates>(&path))
+                        .transpose()?
+                        .map(|e| e.mean.point_estimate)
+                }
+                None => None,
+            };
+            measurements.push(Measurement {
+                group: id.group_id,
+                function,
+                mean: estimates.mean.point_estimate,
+                baseline,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn subdirs(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    Ok(dirs)
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> anyhow::Result<T> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("cannot load {}", path.display()))?;
+    serde_json::from_str(&text).with_context(|| format!("cannot parse {}", path.display()))
+}
+
+/// The days with the largest total time, slowest first.
+pub fn slowest(measurements: &[Measurement], count: usize) -> Vec<(&str, f64)> {
+    let mut totals: BTreeMap<&str, f64> = BTreeMap::new();
+    for m in measurements.iter() {
+        *totals.entry(m.group.as_str()).or_default() += m.mean;
+    }
+    let mut totals: Vec<(&str, f64)> = totals.into_iter().collect();
+    totals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    totals.truncate(count);
+    totals
+}
+
+/// The benchmarks that got slower than the baseline by more than `threshold` percent.
+pub fn regressions(measurements: &[Measurement], threshold: f64) -> Vec<&Measurement> {
+    let mut result: Vec<&Measurement> = measurements
+        .iter()
+        .filter(|m| m.change().is_some_and(|change| change > threshold))
+        .collect();
+    result.sort_by(|a, b| b.change().unwrap().total_cmp(&a.change().unwrap()));
+    result
+}
+
+pub fn print_report(measurements: &[Measurement], top: usize, threshold: Option<f64>) {
+    println!("slowest days:");
+    for (group, total) in slowest(measurements, top) {
+        let parts: Vec<String> = measurements
+            .iter()
+            .filter(|m| m.group == group)
+            .map(|m| format!("{} {}", m.function, format_ns(m.mean)))
+            .collect();
+        println!("  {group}: {} ({})", format_ns(total), parts.join(", "));
+    }
+    if let Some(threshold) = threshold {
+        let regressions = regressions(measurements, threshold);
+        println!("regressions over {threshold}%: {}", regressions.len());
+        for m in regressions {
+            println!(
+                "  {}/{}: {} -> {} ({:+.1}%)",
+                m.group,
+                m.function,
+                format_ns(m.baseline.unwrap()),
+                format_ns(m.mean),
+                m.change().unwrap()
+            );
+        }
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    format!("{:.2?}", Duration::from_secs_f64(ns / 1e9))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(group: &str, function: &str, mean: f64, baseline: Option<f64>) -> Measurement {
+        Measurement {
+            group: group.to_string(),
+            function: function.to_string(),
+            mean,
+            baseline,
+        }
+    }
+
+    #[test]
+    fn ranks_days_and_regressions() {
+        let measurements = vec![
+            measurement("2022/day-20", "prepare", 10.0, None),
+            measurement("2022/day-20", "part1", 500.0, Some(400.0)),
+            measurement("2024/day-06", "part2", 300.0, Some(100.0)),
+            measurement("2024/day-01", "part1", 5.0, Some(5.1)),
+        ];
+
+        assert_eq!(
+            slowest(&measurements, 2),
+            vec![("2022/day-20", 510.0), ("2024/day-06", 300.0)]
+        );
+
+        let slower: Vec<(&str, &str)> = regressions(&measurements, 10.0)
+            .iter()
+            .map(|m| (m.group.as_str(), m.function.as_str()))
+            .collect();
+        assert_eq!(
+            slower,
+            vec![("2024/day-06", "part2"), ("2022/day-20", "part1")]
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use aoc::registry::Day;
+use aoc_core::Answer;
+
+use crate::answers::Answers;
+
+/// Result of checking one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    /// No answer has been recorded for the part yet.
+    Unrecorded(String),
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    Error(String),
+    Panic(String),
+}
+
+/// Counts of a check run over one or more years.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub unrecorded: usize,
+    pub failed: usize,
+    pub missing_input: usize,
+    pub blessed: usize,
+}
+
+impl Summary {
+    fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Unrecorded(_) => self.unrecorded += 1,
+            _ => self.failed += 1,
+        }
+    }
+}
+
+/// Solves a part, turning a panic of the solution into an [`Outcome::Panic`].
+pub fn check_part(
+    solve: impl FnOnce() -> anyhow::Result<Answer>,
+    expected: Option<&str>,
+) -> Outcome {
+    let actual = match catch(solve) {
+        Ok(answer) => answer.to_string(),
+        Err(outcome) => return outcome,
+    };
+    match expected {
+        None => Outcome::Unrecorded(actual),
+        Some(expected) if expected == actual => Outcome::Pass,
+        Some(expected) => Outcome::Mismatch {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+/// Runs a solution, turning its error or panic into the matching [`Outcome`].
+fn catch<T>(solve: impl FnOnce() -> anyhow::Result<T>) -> Result<T, Outcome> {
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(Outcome::Error(format!("{e:#}"))),
+        Err(payload) => Err(Outcome::Panic(panic_message(payload))),
+    }
+}
+
+/// The message a solution panicked with.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Re-runs the days of a year against their inputs and compares them with the answers
+/// file. With `bless` the current answers of all days that ran are written back.
+pub fn check_year(
+    year: u16,
+    days: &[Day],
+    bless: bool,
+    summary: &mut Summary,
+) -> anyhow::Result<()> {
+    let path = Answers::path(year);
+    let mut answers = Answers::load(&path)?;
+
+    // the solutions report their own panics, which would only clutter the report
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut changed = false;
+    for day in days.iter().filter(|d| d.year == year) {
+        let input = match day.load_input(None) {
+            Ok(input) => input,
+            Err(e) => {
+                if answers.has_day(day.day) {
+                    println!(
+                        "{year} day {:2}: MISSING INPUT, answers not checked: {e:#}",
+                        day.day
+                    );
+                } else {
+                    println!("{year} day {:2}: missing input: {e:#}", day.day);
+                }
+                summary.missing_input += 1;
+                continue;
+            }
+        };
+        // a day failing to parse fails both parts
+        let prepared = catch(|| (day.prepare)(&input));
+        for part in day.parts() {
+            let start = Instant::now();
+            let outcome = match &prepared {
+                Ok(prepared) => check_part(|| prepared.solve(part), answers.get(day.day, part)),
+                Err(outcome) => outcome.clone(),
+            };
+            let elapsed = start.elapsed();
+            let label = format!("{year} day {:2} part {part}", day.day);
+            match &outcome {
+                Outcome::Pass => println!("{label}: ok ({elapsed:.2?})"),
+                Outcome::Unrecorded(actual) => println!("{label}: unrecorded: {actual}"),
+                Outcome::Mismatch { expected, actual } => {
+                    println!("{label}: MISMATCH expected {expected}, got {actual}")
+                }
+                Outcome::Error(e) => println!("{label}: ERROR {e}"),
+                Outcome::Panic(message) => println!("{label}: PANIC {message}"),
+            }
+            summary.add(&outcome);
+            if bless {
+                if let Outcome::Unrecorded(actual) | Outcome::Mismatch { actual, .. } = outcome {
+                    answers.insert(day.day, part, actual);
+                    summary.blessed += 1;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+
+    if changed {
+        answers.save(&path)?;
+        println!("updated {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(input: &str) -> anyhow::Result<Answer> {
+        match input {
+            "panic" => panic!("boom"),
+            "error" => anyhow::bail!("bad input"),
+            _ => Ok(Answer::new(input.len())),
+        }
+    }
+
+    #[test]
+    fn compares_with_recorded_answers() {
+        assert_eq!(check_part(|| answer("abc"), Some("3")), Outcome::Pass);
+        assert_eq!(
+            check_part(|| answer("abc"), Some("4")),
+            Outcome::Mismatch {
+                expected: "4".to_string(),
+                actual: "3".to_string()
+            }
+        );
+        assert_eq!(
+            check_part(|| answer("abc"), None),
+            Outcome::Unrecorded("3".to_string())
+        );
+        assert_eq!(
+            check_part(|| answer("error"), Some("5")),
+            Outcome::Error("bad input".to_string())
+        );
+        assert_eq!(
+            check_part(|| answer("panic"), Some("5")),
+            Outcome::Panic("boom".to_string())
+        );
+    }
+}
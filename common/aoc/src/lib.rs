@@ -0,0 +1,17 @@
+//! The registry of all solved days, shared by the `aoc` runner and the benchmarks.
+
+pub mod registry;
+#[cfg(feature = "y2019")]
+mod y2019;
+#[cfg(feature = "y2020")]
+mod y2020;
+#[cfg(feature = "y2021")]
+mod y2021;
+#[cfg(feature = "y2022")]
+mod y2022;
+#[cfg(feature = "y2023")]
+mod y2023;
+#[cfg(feature = "y2024")]
+mod y2024;
+#[cfg(feature = "y2025")]
+mod y2025;
@@ -0,0 +1,430 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use std::{env, fs, panic, thread};
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand};
+use graph_export::Format;
+use visualize::{Playback, Simulation};
+
+use aoc::registry::{self, Day};
+
+use crate::check::Summary;
+use crate::report::{Record, Reporter};
+
+mod answers;
+mod bench_report;
+mod check;
+mod pool;
+mod report;
+
+/// Some solutions recurse deeply, so the commands run with more stack than the main thread has.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions of all years")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs a whole year, a single day or a single part of a day
+    Run {
+        year: u16,
+        day: Option<u8>,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` reads from stdin. Defaults to the input file in the day's directory,
+        /// then to the download cache (`$AOC_CACHE_DIR`, fetching with `$AOC_SESSION`)
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+    },
+    /// Runs all days of one or more years in parallel, each in a process of its own with a time
+    /// limit
+    Parallel {
+        #[arg(required = true)]
+        years: Vec<u16>,
+        /// Number of days solved at the same time. Defaults to the number of CPUs
+        #[arg(short, long)]
+        jobs: Option<usize>,
+        /// Seconds a day may take for both parts
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+        #[arg(long, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+    },
+    /// Checks the answers of all days with an input against the year's answers file
+    Check {
+        /// Checks all registered years if omitted
+        year: Option<u16>,
+        /// Records the current answers instead of failing on new or changed ones
+        #[arg(long)]
+        bless: bool,
+        /// Fails when a registered day has no input instead of skipping it
+        #[arg(long)]
+        require_input: bool,
+    },
+    /// Generates the example tests of a day from its saved puzzle page, or verifies them
+    Examples {
+        year: u16,
+        day: u8,
+        /// The puzzle page saved from the browser
+        page: PathBuf,
+        /// Checks the day's existing tests instead of printing new ones
+        #[arg(long)]
+        verify: bool,
+    },
+    /// Summarizes the results of the days' `puzzle` benchmarks
+    BenchReport {
+        /// Number of slowest days to show
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Compares against a baseline saved with `--save-baseline <name>`. Criterion itself
+        /// overwrites the baseline called `base` on every run
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Slowdown in percent reported as a regression
+        #[arg(long, default_value_t = 5.0, requires = "baseline")]
+        threshold: f64,
+        /// Criterion output directory, by default those of all year workspaces
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+    /// Records the simulation of a day as images
+    Visualize {
+        year: u16,
+        day: u8,
+        /// An animated `.gif`, a `.png` of the final state or a directory for numbered PNG frames
+        output: PathBuf,
+        /// Input file, `-` reads from stdin. Found like for `run` if omitted
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Replays the simulation of a day in the terminal, or the recording of its visualization
+    Replay {
+        year: u16,
+        day: u8,
+        /// Steps per second, changed with `+` and `-` while playing
+        #[arg(long, default_value_t = 10)]
+        fps: u32,
+        /// Input file, `-` reads from stdin. Found like for `run` if omitted
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Exports the graph a day works on as Graphviz DOT or Mermaid
+    Graph {
+        year: u16,
+        day: u8,
+        /// A `.dot`/`.gv` or `.mmd`/`.mermaid` file. Printed to stdout if omitted
+        output: Option<PathBuf>,
+        /// Prints Mermaid instead of DOT to stdout
+        #[arg(long, conflicts_with = "output")]
+        mermaid: bool,
+        /// Input file, `-` reads from stdin. Found like for `run` if omitted
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Lists the registered days
+    List { year: Option<u16> },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    thread::Builder::new()
+        .name("aoc".to_string())
+        .stack_size(STACK_SIZE)
+        .spawn(move || run(cli))
+        .context("cannot start the main thread")?
+        .join()
+        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let days = registry::find(year, day)?;
+            let mut reporter = Reporter::new(format);
+            for day in days.iter() {
+                run_day(day, part, input.as_ref(), &mut reporter);
+            }
+            let failures = reporter.finish();
+            if failures > 0 {
+                bail!("{failures} part(s) failed");
+            }
+        }
+        Command::Parallel {
+            years,
+            jobs,
+            timeout,
+            format,
+        } => {
+            let mut days = Vec::new();
+            for year in years {
+                days.extend(registry::find(year, None)?);
+            }
+            let jobs = match jobs {
+                Some(jobs) => jobs,
+                None => thread::available_parallelism().map_or(1, |n| n.get()),
+            };
+            let timeout = Duration::try_from_secs_f64(timeout).context("invalid timeout")?;
+            let exe = env::current_exe().context("cannot locate the aoc binary")?;
+            let start = Instant::now();
+            let mut reporter = Reporter::new(format);
+            let outcomes = pool::run_days(days, jobs, timeout, &mut reporter, &|day| {
+                pool::aoc_command(&exe, day)
+            });
+            let elapsed = start.elapsed();
+            reporter.finish();
+            let summary = pool::summary(&outcomes, elapsed);
+            // keeps stdout machine readable
+            if format == report::Format::Text {
+                print!("\n{summary}");
+            } else {
+                eprint!("{summary}");
+            }
+            let failures = outcomes
+                .iter()
+                .filter(|o| o.status != pool::Status::Ok)
+                .count();
+            if failures > 0 {
+                bail!("{failures} day(s) failed");
+            }
+        }
+        Command::Check {
+            year,
+            bless,
+            require_input,
+        } => {
+            let days = registry::all();
+            let mut years: Vec<u16> = match year {
+                Some(year) => registry::find(year, None)?.iter().map(|d| d.year).collect(),
+                None => days.iter().map(|d| d.year).collect(),
+            };
+            years.dedup();
+            let mut summary = Summary::default();
+            for year in years {
+                check::check_year(year, &days, bless, &mut summary)?;
+            }
+            println!(
+                "{} passed, {} failed, {} unrecorded, {} without input",
+                summary.passed, summary.failed, summary.unrecorded, summary.missing_input
+            );
+            if bless {
+                println!("{} answer(s) recorded", summary.blessed);
+            } else if summary.failed > 0 {
+                bail!("{} part(s) failed", summary.failed);
+            }
+            if require_input && summary.missing_input > 0 {
+                bail!("{} day(s) have no input", summary.missing_input);
+            }
+        }
+        Command::Examples {
+            year,
+            day,
+            page,
+            verify,
+        } => {
+            let html = fs::read_to_string(&page)
+                .with_context(|| format!("cannot load {}", page.display()))?;
+            let examples = aoc_examples::scrape(&html)?;
+            let day = &registry::find(year, Some(day))?[0];
+            let sources = rust_sources(&Path::new(day.dir).join("src"))?;
+            if verify {
+                let problems = aoc_examples::verify_tests(&examples, &sources);
+                for problem in problems.iter() {
+                    println!("{problem}");
+                }
+                if !problems.is_empty() {
+                    bail!(
+                        "the tests of {year} day {} don't match the puzzle page",
+                        day.day
+                    );
+                }
+                println!("the tests of {year} day {} use all examples", day.day);
+            } else {
+                let signature = aoc_examples::Signature::of(&sources.join("\n"));
+                print!("{}", aoc_examples::generate_tests(&examples, &signature));
+            }
+        }
+        Command::BenchReport {
+            top,
+            baseline,
+            threshold,
+            dir,
+        } => {
+            let dirs = match dir {
+                Some(dir) => vec![dir],
+                None => bench_report::default_dirs()?,
+            };
+            let measurements = bench_report::load(&dirs, baseline.as_deref())?;
+            bench_report::print_report(&measurements, top, baseline.is_some().then_some(threshold));
+        }
+        Command::Visualize {
+            year,
+            day,
+            output,
+            input,
+        } => {
+            let day = &registry::find(year, Some(day))?[0];
+            let Some(visualize) = day.visualize else {
+                bail!("{year} day {} has no visualization", day.day);
+            };
+            let input = day.load_input(input.as_deref())?;
+            let recorder = visualize(&input)?;
+            recorder.save(&output)?;
+            println!(
+                "{} frame(s) written to {}",
+                recorder.len(),
+                output.display()
+            );
+        }
+        Command::Replay {
+            year,
+            day,
+            fps,
+            input,
+        } => {
+            let day = &registry::find(year, Some(day))?[0];
+            let input = day.load_input(input.as_deref())?;
+            let mut simulation: Box<dyn Simulation> = match (day.simulate, day.visualize) {
+                (Some(simulate), _) => simulate(&input)?,
+                (None, Some(visualize)) => {
+                    let recorder = visualize(&input)?;
+                    if recorder.is_empty() {
+                        bail!("the visualization of {year} day {} is empty", day.day);
+                    }
+                    Box::new(Playback::new(recorder.into_frames()))
+                }
+                (None, None) => bail!("{year} day {} has no simulation", day.day),
+            };
+            visualize::terminal::replay(&mut *simulation, fps)?;
+        }
+        Command::Graph {
+            year,
+            day,
+            output,
+            mermaid,
+            input,
+        } => {
+            let day = &registry::find(year, Some(day))?[0];
+            let Some(export) = day.graph else {
+                bail!("{year} day {} has no graph", day.day);
+            };
+            let input = day.load_input(input.as_deref())?;
+            let graph = export(&input)?;
+            match output {
+                Some(output) => {
+                    graph.save(&output)?;
+                    println!(
+                        "{} node(s) and {} edge(s) written to {}",
+                        graph.node_count(),
+                        graph.edge_count(),
+                        output.display()
+                    );
+                }
+                None if mermaid => print!("{}", graph.render(Format::Mermaid)),
+                None => print!("{}", graph.render(Format::Dot)),
+            }
+        }
+        Command::List { year } => {
+            for day in registry::all()
+                .iter()
+                .filter(|d| year.is_none_or(|year| d.year == year))
+            {
+                let parts = if day.has_part2 { "1, 2" } else { "1" };
+                let visualization = if day.visualize.is_some() {
+                    ", visualization"
+                } else {
+                    ""
+                };
+                let simulation = if day.simulate.is_some() {
+                    ", simulation"
+                } else {
+                    ""
+                };
+                let graph = if day.graph.is_some() { ", graph" } else { "" };
+                println!(
+                    "{} day {:2}: parts {parts}{visualization}{simulation}{graph}",
+                    day.year, day.day
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs the selected parts of a day, parsing its input once, and reports their results.
+fn run_day(day: &Day, part: Option<u8>, input: Option<&PathBuf>, reporter: &mut Reporter) {
+    reporter.start_day(day.year, day.day);
+    let record = |part, answer, elapsed, error| Record {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        elapsed,
+        error,
+    };
+    let input = match day.load_input(input.map(|p| p.as_path())) {
+        Ok(input) => input,
+        Err(e) => {
+            reporter.add(record(None, None, Duration::ZERO, Some(format!("{e:#}"))));
+            return;
+        }
+    };
+    let start = Instant::now();
+    let prepared = match (day.prepare)(&input) {
+        Ok(prepared) => prepared,
+        Err(e) => {
+            reporter.add(record(None, None, start.elapsed(), Some(format!("{e:#}"))));
+            return;
+        }
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => day.parts(),
+    };
+    for p in parts {
+        if !day.parts().contains(&p) {
+            reporter.add(record(Some(p), None, Duration::ZERO, None));
+            continue;
+        }
+        let start = Instant::now();
+        let result = prepared.solve(p);
+        let elapsed = start.elapsed();
+        match result {
+            Ok(answer) => reporter.add(record(Some(p), Some(answer), elapsed, None)),
+            Err(e) => reporter.add(record(Some(p), None, elapsed, Some(format!("{e:#}")))),
+        }
+    }
+}
+
+/// The contents of every `.rs` file below `dir`, in path order.
+fn rust_sources(dir: &Path) -> anyhow::Result<Vec<String>> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| format!("cannot list {}", dir.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    let mut sources = Vec::new();
+    for path in entries {
+        if path.is_dir() {
+            sources.extend(rust_sources(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            sources.push(
+                fs::read_to_string(&path)
+                    .with_context(|| format!("cannot load {}", path.display()))?,
+            );
+        }
+    }
+    Ok(sources)
+}
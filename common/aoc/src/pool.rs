@@ -0,0 +1,476 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::registry::Day;
+use aoc_core::Answer;
+use serde::Deserialize;
+
+use crate::report::{Record, Reporter};
+
+/// How a day ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    Ok,
+    Failed,
+    Panicked,
+    /// The solver was killed by a signal, e.g. after overflowing its stack.
+    Crashed,
+    TimedOut,
+}
+
+/// The results of one day, in the order of its parts.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub records: Vec<Record>,
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+/// The command solving a single day in the pool: `aoc run <year> <day>` with the binary at
+/// `exe`, given its input on stdin and reporting in ndjson.
+pub fn aoc_command(exe: &Path, day: &Day) -> Command {
+    let mut command = Command::new(exe);
+    command
+        .arg("run")
+        .arg(day.year.to_string())
+        .arg(day.day.to_string())
+        .args(["--format", "ndjson", "--input", "-"]);
+    command
+}
+
+/// Runs the days on `jobs` workers and returns their outcomes, sorted by year and day.
+///
+/// Each day is solved by a process of its own, started with `command`, which its worker kills
+/// once it runs longer than `timeout`. The inputs are loaded here and passed on stdin, so that
+/// all days share the download cache and its throttling.
+pub fn run_days(
+    days: Vec<Day>,
+    jobs: usize,
+    timeout: Duration,
+    reporter: &mut Reporter,
+    command: &(impl Fn(&Day) -> Command + Sync),
+) -> Vec<Outcome> {
+    let queue = Mutex::new(days.into_iter());
+    let shared = Mutex::new((reporter, Vec::new()));
+    thread::scope(|s| {
+        for _ in 0..jobs.max(1) {
+            s.spawn(|| loop {
+                let Some(day) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let outcome = run_day(&day, timeout, command);
+                let mut shared = shared.lock().unwrap();
+                let (reporter, outcomes) = &mut *shared;
+                reporter.start_day(outcome.year, outcome.day);
+                for record in outcome.records.iter() {
+                    reporter.add(record.clone());
+                }
+                outcomes.push(outcome);
+            });
+        }
+    });
+
+    let (_, mut outcomes) = shared.into_inner().unwrap();
+    outcomes.sort_by_key(|o: &Outcome| (o.year, o.day));
+    outcomes
+}
+
+fn run_day(day: &Day, timeout: Duration, command: impl Fn(&Day) -> Command) -> Outcome {
+    let (year, number) = (day.year, day.day);
+    let start = Instant::now();
+    let failed = |error: String| Outcome {
+        year,
+        day: number,
+        records: vec![record(year, number, None, Duration::ZERO, error)],
+        elapsed: start.elapsed(),
+        status: Status::Failed,
+    };
+    let input = match day.load_input(None) {
+        Ok(input) => input,
+        Err(e) => return failed(format!("{e:#}")),
+    };
+    let mut child = match command(day)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return failed(format!("cannot start the solver: {e}")),
+    };
+
+    let (mut stdin, stdout, mut stderr) = (
+        child.stdin.take().unwrap(),
+        child.stdout.take().unwrap(),
+        child.stderr.take().unwrap(),
+    );
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|s| {
+        // a solver that stops reading its input ends these threads by exiting
+        s.spawn(move || stdin.write_all(input.as_bytes()));
+        s.spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if let Some(record) = parse_record(&line, year, number) {
+                    if sender.send(record).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        let errors = s.spawn(move || {
+            let mut errors = String::new();
+            let _ = stderr.read_to_string(&mut errors);
+            errors
+        });
+
+        let deadline = start + timeout;
+        let parts = day.parts();
+        let mut records = Vec::new();
+        let mut status = Status::Ok;
+        // the solver closes its stdout when it exits
+        while let Ok(record) =
+            receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        {
+            if record.failed() && status == Status::Ok {
+                status = Status::Failed;
+            }
+            records.push(record);
+        }
+        // the part that was running when the solver stopped is the first one without a result
+        let stopped_in = parts.get(records.len()).copied();
+        match wait_until(&mut child, deadline) {
+            Some(exit) => {
+                let errors = errors.join().unwrap_or_default();
+                if let Some((crash, error)) = crash(exit, &errors) {
+                    records.push(record(year, number, stopped_in, Duration::ZERO, error));
+                    status = crash;
+                } else if !exit.success() && records.is_empty() {
+                    let error = errors
+                        .lines()
+                        .last()
+                        .map_or_else(|| format!("solver {exit}"), str::to_string);
+                    records.push(record(year, number, None, Duration::ZERO, error));
+                    status = Status::Failed;
+                }
+            }
+            None => {
+                let _ = child.kill();
+                let _ = child.wait();
+                // all parts were solved just before the solver was killed
+                if stopped_in.is_some() || records.is_empty() {
+                    let error = format!("timed out after {timeout:.2?}");
+                    records.push(record(year, number, stopped_in, timeout, error));
+                    status = Status::TimedOut;
+                }
+            }
+        }
+        Outcome {
+            year,
+            day: number,
+            records,
+            elapsed: start.elapsed(),
+            status,
+        }
+    })
+}
+
+/// Waits for the solver to exit, polling until `deadline`.
+fn wait_until(child: &mut Child, deadline: Instant) -> Option<ExitStatus> {
+    loop {
+        match child.try_wait() {
+            Ok(Some(exit)) => return Some(exit),
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(5)),
+            _ => return None,
+        }
+    }
+}
+
+/// How the solver went down, if it panicked or was killed by a signal, and the error to
+/// report for it.
+fn crash(exit: ExitStatus, stderr: &str) -> Option<(Status, String)> {
+    if let Some(message) = panic_message(stderr) {
+        return Some((Status::Panicked, format!("panic: {message}")));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = exit.signal() {
+            let error = match stderr.lines().last() {
+                Some(line) => format!("crashed with signal {signal}: {line}"),
+                None => format!("crashed with signal {signal}"),
+            };
+            return Some((Status::Crashed, error));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = exit;
+    None
+}
+
+/// The message of a panic as printed by the default panic hook.
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !(line.starts_with("thread '") && line.contains("' panicked at ")));
+    lines.next()?;
+    let message: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("note: ") && !line.starts_with("stack backtrace:"))
+        .collect();
+    Some(message.join("\n"))
+}
+
+/// A line of `aoc run --format ndjson`, see [`Record`].
+#[derive(Deserialize)]
+struct Line {
+    part: Option<u8>,
+    answer: Option<serde_json::Value>,
+    elapsed_ms: f64,
+    error: Option<String>,
+}
+
+/// Reads a record printed by the solver, skipping anything else it prints.
+fn parse_record(line: &str, year: u16, day: u8) -> Option<Record> {
+    let line: Line = serde_json::from_str(line).ok()?;
+    let answer = line.answer.map(|answer| match answer {
+        serde_json::Value::String(text) => Answer::new(text),
+        other => Answer::new(other),
+    });
+    Some(Record {
+        year,
+        day,
+        part: line.part,
+        answer,
+        elapsed: Duration::try_from_secs_f64(line.elapsed_ms / 1000.0).unwrap_or_default(),
+        error: line.error,
+    })
+}
+
+/// A record of a failed part.
+fn record(year: u16, day: u8, part: Option<u8>, elapsed: Duration, error: String) -> Record {
+    Record {
+        year,
+        day,
+        part,
+        answer: None,
+        elapsed,
+        error: Some(error),
+    }
+}
+
+/// A table with a line per day and the totals of all days.
+pub fn summary(outcomes: &[Outcome], elapsed: Duration) -> String {
+    let mut out = format!(
+        "{:<4} {:>3}  {:<20}  {:<20}  {:>10}  status\n",
+        "year", "day", "part 1", "part 2", "time"
+    );
+    let mut counts: BTreeMap<Status, usize> = BTreeMap::new();
+    for outcome in outcomes {
+        *counts.entry(outcome.status).or_default() += 1;
+        // a day failing before its parts shows that failure for both of them
+        let part = |part| {
+            outcome
+                .records
+                .iter()
+                .find(|r| r.part == Some(part) || r.part.is_none())
+                .map(cell)
+                .unwrap_or_else(|| "-".to_string())
+        };
+        writeln!(
+            out,
+            "{:<4} {:>3}  {:<20}  {:<20}  {:>10}  {}",
+            outcome.year,
+            outcome.day,
+            part(1),
+            part(2),
+            format!("{:.2?}", outcome.elapsed),
+            status_name(outcome.status)
+        )
+        .unwrap();
+    }
+    let totals: Vec<String> = counts
+        .iter()
+        .map(|(&status, count)| format!("{count} {}", status_name(status)))
+        .collect();
+    writeln!(
+        out,
+        "{} day(s): {} in {elapsed:.2?}",
+        outcomes.len(),
+        totals.join(", ")
+    )
+    .unwrap();
+    out
+}
+
+fn status_name(status: Status) -> &'static str {
+    match status {
+        Status::Ok => "ok",
+        Status::Failed => "failed",
+        Status::Panicked => "panicked",
+        Status::Crashed => "crashed",
+        Status::TimedOut => "timed out",
+    }
+}
+
+/// The answer of a part shortened to fit its column, or what went wrong.
+fn cell(record: &Record) -> String {
+    let text = match (&record.answer, &record.error) {
+        (_, Some(e)) if e.starts_with("panic") => "panic".to_string(),
+        (_, Some(e)) if e.starts_with("crashed") => "crash".to_string(),
+        (_, Some(e)) if e.starts_with("timed out") => "timeout".to_string(),
+        (_, Some(_)) => "error".to_string(),
+        (Some(answer), None) => {
+            let answer = answer.to_string();
+            let lines = answer.lines().count();
+            if lines > 1 {
+                format!("({lines} lines)")
+            } else {
+                answer
+            }
+        }
+        (None, None) => "-".to_string(),
+    };
+    if text.chars().count() > 20 {
+        text.chars().take(19).chain(['…']).collect()
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::report::Format;
+
+    fn day(day: u8, dir: &'static str) -> Day {
+        Day {
+            year: 2000,
+            day,
+            dir,
+            needs_input: !dir.is_empty(),
+            has_part2: true,
+            prepare: |_| anyhow::bail!("solved by the script"),
+            visualize: None,
+            simulate: None,
+            graph: None,
+        }
+    }
+
+    /// Stands in for `aoc run`, with a shell script per day.
+    fn script(day: &Day) -> Command {
+        let script = match day.day {
+            1 => concat!(
+                "read answer; ",
+                r#"echo "{\"part\":1,\"answer\":$answer,\"elapsed_ms\":1.5,\"error\":null}"; "#,
+                r#"echo '{"part":2,"answer":null,"elapsed_ms":0.5,"error":"no second part"}'; "#,
+                "exit 1",
+            ),
+            2 => "echo 'Error: cannot parse' >&2; exit 1",
+            3 => {
+                r#"printf "thread 'aoc' panicked at src/lib.rs:1:1:\nboom\nnote: run with RUST_BACKTRACE=1\n" >&2; exit 101"#
+            }
+            4 => "exec sleep 5",
+            _ => "kill -SEGV $$",
+        };
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(script);
+        command
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn isolates_failing_days() {
+        let dir = std::env::temp_dir().join(format!("aoc-pool-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), "42\n").unwrap();
+        let input_dir: &'static str = dir.to_str().unwrap().to_string().leak();
+
+        let days = vec![
+            day(5, ""),
+            day(4, ""),
+            day(3, ""),
+            day(2, ""),
+            day(1, input_dir),
+        ];
+        let mut reporter = Reporter::new(Format::Json);
+        let outcomes = run_days(days, 2, Duration::from_secs(1), &mut reporter, &script);
+        fs::remove_dir_all(&dir).unwrap();
+        let statuses: Vec<(u8, Status)> = outcomes.iter().map(|o| (o.day, o.status)).collect();
+        assert_eq!(
+            statuses,
+            [
+                (1, Status::Failed),
+                (2, Status::Failed),
+                (3, Status::Panicked),
+                (4, Status::TimedOut),
+                (5, Status::Crashed)
+            ]
+        );
+        assert_eq!(outcomes[0].records[0].answer, Some(Answer::Number(42)));
+        assert_eq!(outcomes[0].records[0].elapsed, Duration::from_micros(1500));
+        assert_eq!(
+            outcomes[1].records[0].error.as_deref(),
+            Some("Error: cannot parse")
+        );
+        assert_eq!(outcomes[2].records[0].error.as_deref(), Some("panic: boom"));
+        assert_eq!(outcomes[2].records[0].part, Some(1));
+        assert_eq!(outcomes[3].records.len(), 1);
+        assert_eq!(outcomes[3].records[0].part, Some(1));
+        assert!(outcomes[3].elapsed < Duration::from_secs(4));
+        assert_eq!(
+            outcomes[4].records[0].error.as_deref(),
+            Some("crashed with signal 11")
+        );
+        assert_eq!(reporter.finish(), 5);
+
+        let summary = summary(&outcomes, Duration::from_secs(1));
+        let lines: Vec<&str> = summary.lines().collect();
+        assert!(lines[1].starts_with("2000   1  42                    error"));
+        assert!(lines[3].contains("panic") && lines[3].ends_with("panicked"));
+        assert!(lines[4].contains("timeout") && lines[4].ends_with("timed out"));
+        assert!(lines[5].contains("crash") && lines[5].ends_with("crashed"));
+        assert_eq!(
+            lines[6],
+            "5 day(s): 2 failed, 1 panicked, 1 crashed, 1 timed out in 1.00s"
+        );
+    }
+
+    #[test]
+    fn reads_panic_messages() {
+        let stderr = "thread 'aoc' panicked at src/lib.rs:10:5:\nindex out of bounds\n\
+                      the len is 3\nnote: run with `RUST_BACKTRACE=1`\n";
+        assert_eq!(
+            panic_message(stderr).as_deref(),
+            Some("index out of bounds\nthe len is 3")
+        );
+        assert_eq!(panic_message("Error: 1 part(s) failed\n"), None);
+    }
+
+    #[test]
+    fn shortens_cells() {
+        let record = |answer: &str| Record {
+            year: 2000,
+            day: 1,
+            part: Some(1),
+            answer: Some(Answer::new(answer)),
+            elapsed: Duration::ZERO,
+            error: None,
+        };
+        assert_eq!(cell(&record("12345")), "12345");
+        assert_eq!(cell(&record("#..#\n####\n#..#")), "(3 lines)");
+        assert_eq!(
+            cell(&record("abcdefghijklmnopqrstuvwxyz")),
+            "abcdefghijklmnopqrs…"
+        );
+    }
+}
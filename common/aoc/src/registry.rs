@@ -0,0 +1,158 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use aoc_core::{prepare, Prepared, Solution};
+
+use graph_export::Graph;
+use visualize::{Recorder, Simulation};
+
+/// Parses the raw puzzle input once, for solving both parts on the result.
+pub type Prepare = for<'a> fn(&'a str) -> anyhow::Result<Box<dyn Prepared + 'a>>;
+
+/// Runs the simulation of a puzzle and records its states as images.
+pub type Visualize = fn(&str) -> anyhow::Result<Recorder>;
+
+/// Sets up the simulation of a puzzle, to be stepped through by the caller.
+pub type Simulate = fn(&str) -> anyhow::Result<Box<dyn Simulation>>;
+
+/// Builds the graph a puzzle works on, for exporting it as DOT or Mermaid.
+pub type Export = fn(&str) -> anyhow::Result<Graph>;
+
+/// A single puzzle day as seen by the runner.
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Directory of the day crate, used to locate its default input file.
+    pub dir: &'static str,
+    /// `false` for the few days whose puzzle input is hard-coded in the solution.
+    pub needs_input: bool,
+    /// `false` for puzzles with a first part only.
+    pub has_part2: bool,
+    pub prepare: Prepare,
+    pub visualize: Option<Visualize>,
+    pub simulate: Option<Simulate>,
+    pub graph: Option<Export>,
+}
+
+impl Day {
+    pub fn of<S: Solution + 'static>(dir: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            dir,
+            needs_input: true,
+            has_part2: S::HAS_PART2,
+            prepare: prepare::<S>,
+            visualize: None,
+            simulate: None,
+            graph: None,
+        }
+    }
+
+    pub fn without_input(mut self) -> Self {
+        self.needs_input = false;
+        self
+    }
+
+    pub fn with_visualization(mut self, visualize: Visualize) -> Self {
+        self.visualize = Some(visualize);
+        self
+    }
+
+    pub fn with_simulation(mut self, simulate: Simulate) -> Self {
+        self.simulate = Some(simulate);
+        self
+    }
+
+    pub fn with_graph(mut self, graph: Export) -> Self {
+        self.graph = Some(graph);
+        self
+    }
+
+    /// The parts the day has a solution for.
+    pub fn parts(&self) -> Vec<u8> {
+        if self.has_part2 {
+            vec![1, 2]
+        } else {
+            vec![1]
+        }
+    }
+
+    /// The input file inside the day's directory, if there is one.
+    pub fn default_input(&self) -> Option<PathBuf> {
+        aoc_input::local_input(Path::new(self.dir), self.day)
+    }
+
+    pub fn load_input(&self, source: Option<&Path>) -> anyhow::Result<String> {
+        if !self.needs_input {
+            return Ok(String::new());
+        }
+        match source {
+            Some(path) if path == Path::new("-") => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("cannot read input from stdin")?;
+                Ok(input)
+            }
+            Some(path) => {
+                fs::read_to_string(path).with_context(|| format!("cannot load {}", path.display()))
+            }
+            None => aoc_input::load(Path::new(self.dir), self.year, self.day),
+        }
+    }
+}
+
+/// Absolute path of a day crate, relative to the repository root.
+macro_rules! day_dir {
+    ($path:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../../", $path)
+    };
+}
+
+/// Registers the `Puzzle` of a day crate.
+macro_rules! day {
+    ($path:literal, $krate:ident) => {
+        $crate::registry::Day::of::<$krate::Puzzle>($crate::registry::day_dir!($path))
+    };
+}
+
+pub(crate) use {day, day_dir};
+
+pub fn all() -> Vec<Day> {
+    #[allow(unused_mut)]
+    let mut days = Vec::new();
+    #[cfg(feature = "y2019")]
+    days.extend(crate::y2019::days());
+    #[cfg(feature = "y2020")]
+    days.extend(crate::y2020::days());
+    #[cfg(feature = "y2021")]
+    days.extend(crate::y2021::days());
+    #[cfg(feature = "y2022")]
+    days.extend(crate::y2022::days());
+    #[cfg(feature = "y2023")]
+    days.extend(crate::y2023::days());
+    #[cfg(feature = "y2024")]
+    days.extend(crate::y2024::days());
+    #[cfg(feature = "y2025")]
+    days.extend(crate::y2025::days());
+    days
+}
+
+pub fn find(year: u16, day: Option<u8>) -> anyhow::Result<Vec<Day>> {
+    let days: Vec<Day> = all()
+        .into_iter()
+        .filter(|d| d.year == year && day.is_none_or(|day| d.day == day))
+        .collect();
+    if days.is_empty() {
+        match day {
+            Some(day) => Err(anyhow!("no solution registered for {year} day {day}")),
+            None => Err(anyhow!("no solutions registered for {year}")),
+        }
+    } else {
+        Ok(days)
+    }
+}
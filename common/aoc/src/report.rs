@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use aoc_core::Answer;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+/// How `run` reports its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines
+    Text,
+    /// A single JSON array of all results, printed at the end of the run
+    Json,
+    /// One JSON object per line as soon as a part is solved
+    Ndjson,
+}
+
+/// The result of one part, or of a day whose input cannot be loaded. Parts without a solution
+/// have neither an answer nor an error.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    /// Missing when the day failed before solving any part.
+    pub part: Option<u8>,
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Option<Answer>,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Numbers stay JSON numbers as long as they fit into 64 bits.
+fn serialize_answer<S: Serializer>(answer: &Option<Answer>, s: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        None => s.serialize_none(),
+        Some(Answer::Number(n)) => match (i64::try_from(*n), u64::try_from(*n)) {
+            (Ok(n), _) => s.serialize_i64(n),
+            (_, Ok(n)) => s.serialize_u64(n),
+            _ => s.collect_str(n),
+        },
+        Some(Answer::Text(text)) => s.serialize_str(text),
+    }
+}
+
+fn serialize_millis<S: Serializer>(elapsed: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f64(elapsed.as_secs_f64() * 1000.0)
+}
+
+/// Prints the records of a run in the chosen format.
+#[derive(Debug)]
+pub struct Reporter {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn start_day(&self, year: u16, day: u8) {
+        if self.format == Format::Text {
+            println!("{year} day {day}");
+        }
+    }
+
+    pub fn add(&mut self, record: Record) {
+        match self.format {
+            Format::Text => println!("{}", text(&record)),
+            Format::Json => {}
+            Format::Ndjson => println!("{}", serde_json::to_string(&record).unwrap()),
+        }
+        self.records.push(record);
+    }
+
+    /// Prints the JSON array and returns the number of failures.
+    pub fn finish(mut self) -> usize {
+        if self.format == Format::Json {
+            // days running in parallel finish in any order
+            self.records.sort_by_key(|r| (r.year, r.day, r.part));
+            println!("{}", serde_json::to_string_pretty(&self.records).unwrap());
+        }
+        self.records.iter().filter(|r| r.failed()).count()
+    }
+}
+
+fn text(record: &Record) -> String {
+    let elapsed = record.elapsed;
+    let Some(part) = record.part else {
+        return format!("  {}", record.error.as_deref().unwrap_or_default());
+    };
+    match (&record.answer, &record.error) {
+        (_, Some(e)) => format!("  part {part}: error: {e} ({elapsed:.2?})"),
+        (Some(answer), None) => {
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                let lines: Vec<String> = answer.lines().map(|line| format!("    {line}")).collect();
+                format!("  part {part}: ({elapsed:.2?})\n{}", lines.join("\n"))
+            } else {
+                format!("  part {part}: {answer} ({elapsed:.2?})")
+            }
+        }
+        (None, None) => format!("  part {part}: no solution"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: Option<u8>, answer: Option<Answer>, error: Option<&str>) -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            part,
+            answer,
+            elapsed: Duration::from_micros(1500),
+            error: error.map(|e| e.to_string()),
+        }
+    }
+
+    #[test]
+    fn serializes_records() {
+        let solved = record(Some(1), Some(Answer::Number(13140)), None);
+        assert_eq!(
+            serde_json::to_string(&solved).unwrap(),
+            r#"{"year":2022,"day":10,"part":1,"answer":13140,"elapsed_ms":1.5,"error":null}"#
+        );
+        let huge = record(Some(1), Some(Answer::Number(i128::MAX)), None);
+        assert!(serde_json::to_string(&huge)
+            .unwrap()
+            .contains(&format!(r#""answer":"{}""#, i128::MAX)));
+        let failed = record(None, None, Some("no input"));
+        assert_eq!(
+            serde_json::to_string(&failed).unwrap(),
+            r#"{"year":2022,"day":10,"part":null,"answer":null,"elapsed_ms":1.5,"error":"no input"}"#
+        );
+    }
+
+    #[test]
+    fn prints_text() {
+        let screen = Answer::Text("##..\n#..#".to_string());
+        assert_eq!(
+            text(&record(Some(2), Some(screen), None)),
+            "  part 2: (1.50ms)\n    ##..\n    #..#"
+        );
+        assert_eq!(
+            text(&record(Some(1), None, Some("bad input"))),
+            "  part 1: error: bad input (1.50ms)"
+        );
+        assert_eq!(text(&record(None, None, Some("no input"))), "  no input");
+    }
+}
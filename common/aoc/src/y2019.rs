@@ -0,0 +1,15 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2019/day-1", y2019_day_1),
+        day!("2019/day-2", y2019_day_2),
+        day!("2019/day-3", y2019_day_3),
+        day!("2019/day-4", y2019_day_4).without_input(),
+        day!("2019/day-5", y2019_day_5),
+        day!("2019/day-6", y2019_day_6).with_graph(y2019_day_6::graph),
+        day!("2019/day-7", y2019_day_7),
+        day!("2019/day-8", y2019_day_8),
+        day!("2019/day-9", y2019_day_9),
+    ]
+}
@@ -0,0 +1,21 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2020/day-1", y2020_day_1),
+        day!("2020/day-2", y2020_day_2),
+        day!("2020/day-3", y2020_day_3),
+        day!("2020/day-4", y2020_day_4),
+        day!("2020/day-5", y2020_day_5),
+        day!("2020/day-6", y2020_day_6),
+        day!("2020/day-7", y2020_day_7).with_graph(y2020_day_7::graph),
+        day!("2020/day-8", y2020_day_8),
+        day!("2020/day-9", y2020_day_9),
+        day!("2020/day-10", y2020_day_10),
+        day!("2020/day-11", y2020_day_11).with_visualization(y2020_day_11::animate),
+        day!("2020/day-12", y2020_day_12),
+        day!("2020/day-13", y2020_day_13),
+        day!("2020/day-14", y2020_day_14),
+        day!("2020/day-15", y2020_day_15).without_input(),
+    ]
+}
@@ -0,0 +1,24 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2021/day-1", y2021_day_1),
+        day!("2021/day-2", y2021_day_2),
+        day!("2021/day-3", y2021_day_3),
+        day!("2021/day-4", y2021_day_4),
+        day!("2021/day-5", y2021_day_5),
+        day!("2021/day-6", y2021_day_6),
+        day!("2021/day-7", y2021_day_7),
+        day!("2021/day-8", y2021_day_8),
+        day!("2021/day-9", y2021_day_9),
+        day!("2021/day-10", y2021_day_10),
+        day!("2021/day-11", y2021_day_11),
+        day!("2021/day-12", y2021_day_12).with_graph(y2021_day_12::graph),
+        day!("2021/day-13", y2021_day_13),
+        day!("2021/day-14", y2021_day_14),
+        day!("2021/day-15", y2021_day_15),
+        day!("2021/day-16", y2021_day_16),
+        day!("2021/day-17", y2021_day_17),
+        day!("2021/day-18", y2021_day_18),
+    ]
+}
@@ -0,0 +1,35 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2022/day-1", y2022_day_1),
+        day!("2022/day-2", y2022_day_2),
+        day!("2022/day-3", y2022_day_3),
+        day!("2022/day-4", y2022_day_4),
+        day!("2022/day-5", y2022_day_5),
+        day!("2022/day-6", y2022_day_6),
+        day!("2022/day-7", y2022_day_7),
+        day!("2022/day-8", y2022_day_8),
+        day!("2022/day-9", y2022_day_9).with_simulation(y2022_day_9::simulate),
+        day!("2022/day-10", y2022_day_10),
+        day!("2022/day-11", y2022_day_11),
+        day!("2022/day-12", y2022_day_12),
+        day!("2022/day-13", y2022_day_13),
+        day!("2022/day-14", y2022_day_14)
+            .with_visualization(y2022_day_14::animate)
+            .with_simulation(y2022_day_14::simulate),
+        day!("2022/day-15", y2022_day_15),
+        day!("2022/day-16", y2022_day_16),
+        day!("2022/day-17", y2022_day_17).with_simulation(y2022_day_17::simulate),
+        day!("2022/day-18", y2022_day_18),
+        day!("2022/day-19", y2022_day_19),
+        day!("2022/day-20", y2022_day_20),
+        day!("2022/day-21", y2022_day_21),
+        day!("2022/day-22", y2022_day_22),
+        day!("2022/day-23", y2022_day_23)
+            .with_visualization(y2022_day_23::animate)
+            .with_simulation(y2022_day_23::simulate),
+        day!("2022/day-24", y2022_day_24),
+        day!("2022/day-25", y2022_day_25),
+    ]
+}
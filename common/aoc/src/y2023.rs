@@ -0,0 +1,31 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2023/day-1", y2023_day_1),
+        day!("2023/day-2", y2023_day_2),
+        day!("2023/day-3", y2023_day_3),
+        day!("2023/day-4", y2023_day_4),
+        day!("2023/day-5", y2023_day_5),
+        day!("2023/day-6", y2023_day_6),
+        day!("2023/day-7", y2023_day_7),
+        day!("2023/day-8", y2023_day_8),
+        day!("2023/day-9", y2023_day_9),
+        day!("2023/day-10", y2023_day_10),
+        day!("2023/day-11", y2023_day_11),
+        day!("2023/day-12", y2023_day_12),
+        day!("2023/day-13", y2023_day_13),
+        day!("2023/day-14", y2023_day_14),
+        day!("2023/day-15", y2023_day_15),
+        day!("2023/day-16", y2023_day_16),
+        day!("2023/day-17", y2023_day_17),
+        day!("2023/day-18", y2023_day_18),
+        day!("2023/day-19", y2023_day_19),
+        day!("2023/day-20", y2023_day_20).with_graph(y2023_day_20::graph),
+        day!("2023/day-21", y2023_day_21),
+        day!("2023/day-22", y2023_day_22),
+        day!("2023/day-23", y2023_day_23).with_graph(y2023_day_23::graph),
+        day!("2023/day-24", y2023_day_24),
+        day!("2023/day-25", y2023_day_25).with_graph(y2023_day_25::graph),
+    ]
+}
@@ -0,0 +1,34 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2024/day-1", y2024_day_1),
+        day!("2024/day-2", y2024_day_2),
+        day!("2024/day-3", y2024_day_3),
+        day!("2024/day-4", y2024_day_4),
+        day!("2024/day-5", y2024_day_5),
+        day!("2024/day-6", y2024_day_6).with_simulation(y2024_day_6::simulate),
+        day!("2024/day-7", y2024_day_7),
+        day!("2024/day-8", y2024_day_8),
+        day!("2024/day-9", y2024_day_9),
+        day!("2024/day-10", y2024_day_10),
+        day!("2024/day-11", y2024_day_11),
+        day!("2024/day-12", y2024_day_12),
+        day!("2024/day-13", y2024_day_13),
+        day!("2024/day-14", y2024_day_14)
+            .with_visualization(|input| y2024_day_14::animate(input, 101, 103)),
+        day!("2024/day-15", y2024_day_15)
+            .with_visualization(y2024_day_15::animate)
+            .with_simulation(y2024_day_15::simulate),
+        day!("2024/day-16", y2024_day_16),
+        day!("2024/day-17", y2024_day_17),
+        day!("2024/day-18", y2024_day_18),
+        day!("2024/day-19", y2024_day_19),
+        day!("2024/day-20", y2024_day_20),
+        day!("2024/day-21", y2024_day_21),
+        day!("2024/day-22", y2024_day_22),
+        day!("2024/day-23", y2024_day_23).with_graph(y2024_day_23::graph),
+        day!("2024/day-24", y2024_day_24).with_graph(y2024_day_24::graph),
+        day!("2024/day-25", y2024_day_25),
+    ]
+}
@@ -0,0 +1,18 @@
+use crate::registry::{day, Day};
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!("2025/day-01", y2025_day_01),
+        day!("2025/day-02", y2025_day_02),
+        day!("2025/day-03", y2025_day_03),
+        day!("2025/day-04", y2025_day_04),
+        day!("2025/day-05", y2025_day_05),
+        day!("2025/day-06", y2025_day_06),
+        day!("2025/day-07", y2025_day_07),
+        day!("2025/day-08", y2025_day_08),
+        day!("2025/day-09", y2025_day_09),
+        day!("2025/day-10", y2025_day_10),
+        day!("2025/day-11", y2025_day_11),
+        day!("2025/day-12", y2025_day_12),
+    ]
+}
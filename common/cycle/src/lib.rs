@@ -0,0 +1,188 @@
+//! Detects when a simulation starts repeating itself, so that results for absurdly large step
+//! counts can be computed from the first few steps.
+//!
+//! Steps are numbered from 0, which is the initial state.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states from step `start` on repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step whose state equals the state at `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// The value at `step` of something that repeats along with the states, given its values
+    /// for (at least) all steps before the end of the first cycle.
+    pub fn nth<'a, T>(&self, history: &'a [T], step: usize) -> &'a T {
+        &history[self.equivalent_step(step)]
+    }
+
+    /// Extrapolates a metric that grows by the same amount in every cycle, like the height of
+    /// a growing pile, to `step`. `history` holds the metric for every step up to and
+    /// including `start + length`.
+    pub fn extrapolate(&self, history: &[i64], step: usize) -> i64 {
+        if step < history.len() {
+            return history[step];
+        }
+        let per_cycle = history[self.start + self.length] - history[self.start];
+        let cycles = ((step - self.start) / self.length) as i64;
+        history[self.equivalent_step(step)] + cycles * per_cycle
+    }
+}
+
+/// Finds a cycle by remembering a key for every state seen so far. Useful when the
+/// simulation updates its state in place.
+#[derive(Debug, Clone)]
+pub struct Detector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> Detector<K> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Records the key of the next step, starting with step 0. Returns the cycle once a key
+    /// repeats.
+    pub fn push(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                length: step - start,
+            }),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies `step` until the key of a state repeats. Returns the cycle and all states up to
+/// and including the end of the first cycle.
+pub fn find_cycle<S, K, FS, FK>(initial: S, mut step: FS, mut key: FK) -> (Cycle, Vec<S>)
+where
+    K: Eq + Hash,
+    FS: FnMut(&S) -> S,
+    FK: FnMut(&S) -> K,
+{
+    let mut detector = Detector::new();
+    let mut states = vec![initial];
+    loop {
+        let state = states.last().unwrap();
+        if let Some(cycle) = detector.push(key(state)) {
+            return (cycle, states);
+        }
+        let next = step(state);
+        states.push(next);
+    }
+}
+
+/// Brent's algorithm, which needs no hashing and only keeps two states around. Returns the
+/// cycle and the state at its start.
+pub fn brent<S, F>(initial: S, mut step: F) -> (Cycle, S)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // Find the length by letting the hare run ahead of the tortoise in powers of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare one cycle ahead both meet at the start.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    (Cycle { start, length }, tortoise)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+    fn next(n: &u32) -> u32 {
+        if *n == 5 {
+            2
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let expected = Cycle {
+            start: 2,
+            length: 4,
+        };
+        let (cycle, states) = find_cycle(0, next, |n| *n);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, [0, 1, 2, 3, 4, 5, 2]);
+        assert_eq!(brent(0, next), (expected, 2));
+        assert_eq!(
+            brent(7, |n| *n),
+            (
+                Cycle {
+                    start: 0,
+                    length: 1
+                },
+                7
+            )
+        );
+    }
+
+    #[test]
+    fn fast_forwards() {
+        let cycle = Cycle {
+            start: 2,
+            length: 4,
+        };
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(11), 3);
+        assert_eq!(*cycle.nth(&[0, 1, 2, 3, 4, 5], 1_000_000), 4);
+        // grows by 10 per cycle
+        let heights = [0, 1, 5, 6, 8, 12, 15];
+        assert_eq!(cycle.extrapolate(&heights, 4), 8);
+        assert_eq!(cycle.extrapolate(&heights, 11), 26);
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+use crate::{Graph, Shape};
+
+pub(crate) fn render(graph: &Graph) -> String {
+    let (keyword, arrow) = if graph.directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+    let mut out = format!("{keyword} {{\n");
+    for node in graph.nodes.iter() {
+        let mut attributes = Vec::new();
+        if node.label.is_some() {
+            attributes.push(format!("label={}", quote(node.label())));
+        }
+        if let Some(style) = graph.node_style(node) {
+            let mut styles = Vec::new();
+            match style.shape {
+                Some(Shape::Rounded) => {
+                    attributes.push("shape=box".to_string());
+                    styles.push("rounded");
+                }
+                Some(shape) => attributes.push(format!("shape={}", shape_name(shape))),
+                None => {}
+            }
+            if let Some(fill) = &style.fill {
+                styles.push("filled");
+                attributes.push(format!("fillcolor={}", quote(fill)));
+            }
+            if !styles.is_empty() {
+                attributes.push(format!("style={}", quote(&styles.join(","))));
+            }
+        }
+        write_statement(&mut out, &quote(&node.id), &attributes);
+    }
+    for edge in graph.edges.iter() {
+        let attributes: Vec<_> = edge
+            .label
+            .iter()
+            .map(|label| format!("label={}", quote(label)))
+            .collect();
+        let statement = format!(
+            "{} {arrow} {}",
+            quote(&graph.nodes[edge.from].id),
+            quote(&graph.nodes[edge.to].id)
+        );
+        write_statement(&mut out, &statement, &attributes);
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn write_statement(out: &mut String, statement: &str, attributes: &[String]) {
+    if attributes.is_empty() {
+        writeln!(out, "  {statement};").unwrap();
+    } else {
+        writeln!(out, "  {statement} [{}];", attributes.join(", ")).unwrap();
+    }
+}
+
+fn shape_name(shape: Shape) -> &'static str {
+    match shape {
+        Shape::Box | Shape::Rounded => "box",
+        Shape::Circle => "circle",
+        Shape::Diamond => "diamond",
+        Shape::Hexagon => "hexagon",
+    }
+}
+
+/// A quoted DOT string, in which `\n` starts a new line.
+fn quote(s: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, Shape, Style};
+
+    #[test]
+    fn renders_dot() {
+        let mut graph = Graph::directed();
+        graph.style(
+            "flip-flop",
+            Style::new().shape(Shape::Rounded).fill("#ffd27f"),
+        );
+        graph.style("output", Style::new().shape(Shape::Circle));
+        graph.node("broadcaster");
+        graph.node("a").label("%a").kind("flip-flop");
+        graph.node("rx").kind("output");
+        graph.edge("broadcaster", "a");
+        graph.edge("a", "rx").label("say \"hi\"\nnow");
+        assert_eq!(
+            graph.to_dot(),
+            r##"digraph {
+  "broadcaster";
+  "a" [label="%a", shape=box, fillcolor="#ffd27f", style="rounded,filled"];
+  "rx" [shape=circle];
+  "broadcaster" -> "a";
+  "a" -> "rx" [label="say \"hi\"\nnow"];
+}
+"##
+        );
+
+        let mut graph = Graph::undirected();
+        graph.edge("a", "b");
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n  \"a\";\n  \"b\";\n  \"a\" -- \"b\";\n}\n"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Exports the graphs built by the puzzles as Graphviz DOT or as Mermaid flowcharts.
+//!
+//! Nodes are identified by their names and created on first use, also by the edges connecting
+//! them. Every node can have a kind, which picks the [`Style`] it is drawn with:
+//!
+//! ```
+//! use graph_export::{Graph, Shape, Style};
+//!
+//! let mut graph = Graph::directed();
+//! graph.style("gate", Style::new().shape(Shape::Diamond).fill("#ffd27f"));
+//! graph.node("z00").label("z00\nAND").kind("gate");
+//! graph.edge("x00", "z00").label("1");
+//! assert!(graph.to_dot().contains(r#""x00" -> "z00" [label="1"];"#));
+//! ```
+
+mod dot;
+mod mermaid;
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+/// The output formats, chosen by the extension of the output file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `.dot` or `.gv`, rendered with e.g. `dot -Tsvg`.
+    Dot,
+    /// `.mmd` or `.mermaid`, rendered by GitHub in Markdown code blocks.
+    Mermaid,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> anyhow::Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("dot" | "gv") => Ok(Format::Dot),
+            Some("mmd" | "mermaid") => Ok(Format::Mermaid),
+            _ => bail!(
+                "cannot tell the graph format of {}, use .dot, .gv, .mmd or .mermaid",
+                path.display()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Box,
+    Rounded,
+    Circle,
+    Diamond,
+    Hexagon,
+}
+
+/// How the nodes of a kind are drawn.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Style {
+    shape: Option<Shape>,
+    fill: Option<String>,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    /// A colour both formats understand, like `#ffd27f` or `lightblue`.
+    pub fn fill(mut self, colour: &str) -> Self {
+        self.fill = Some(colour.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    label: Option<String>,
+    kind: Option<String>,
+}
+
+impl Node {
+    fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.id)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: usize,
+    to: usize,
+    label: Option<String>,
+}
+
+/// A directed or undirected graph, with nodes and edges in the order they were added.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    directed: bool,
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+    edges: Vec<Edge>,
+    styles: BTreeMap<String, Style>,
+}
+
+impl Graph {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Draws the nodes of `kind` with `style`.
+    pub fn style(&mut self, kind: &str, style: Style) -> &mut Self {
+        self.styles.insert(kind.to_string(), style);
+        self
+    }
+
+    /// The node called `id`, which is added if it is new.
+    pub fn node(&mut self, id: impl Display) -> NodeMut<'_> {
+        let index = self.index_of(id.to_string());
+        NodeMut(&mut self.nodes[index])
+    }
+
+    /// Adds an edge, and the nodes it connects if they are new.
+    pub fn edge(&mut self, from: impl Display, to: impl Display) -> EdgeMut<'_> {
+        let from = self.index_of(from.to_string());
+        let to = self.index_of(to.to_string());
+        self.edges.push(Edge {
+            from,
+            to,
+            label: None,
+        });
+        EdgeMut(self.edges.last_mut().unwrap())
+    }
+
+    fn index_of(&mut self, id: String) -> usize {
+        if let Some(&index) = self.index.get(&id) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.index.insert(id.clone(), index);
+        self.nodes.push(Node {
+            id,
+            label: None,
+            kind: None,
+        });
+        index
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn node_style(&self, node: &Node) -> Option<&Style> {
+        node.kind.as_ref().and_then(|kind| self.styles.get(kind))
+    }
+
+    pub fn to_dot(&self) -> String {
+        dot::render(self)
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        mermaid::render(self)
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Dot => self.to_dot(),
+            Format::Mermaid => self.to_mermaid(),
+        }
+    }
+
+    /// Writes the graph in the format given by the extension of `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let format = Format::from_path(path)?;
+        fs::write(path, self.render(format))
+            .with_context(|| format!("cannot write {}", path.display()))
+    }
+}
+
+/// A node of a [`Graph`], to set its label and kind.
+pub struct NodeMut<'a>(&'a mut Node);
+
+impl NodeMut<'_> {
+    /// The text shown instead of the name. Line breaks are kept.
+    pub fn label(self, label: impl Display) -> Self {
+        self.0.label = Some(label.to_string());
+        self
+    }
+
+    pub fn kind(self, kind: &str) -> Self {
+        self.0.kind = Some(kind.to_string());
+        self
+    }
+}
+
+/// An edge of a [`Graph`], to set its label.
+pub struct EdgeMut<'a>(&'a mut Edge);
+
+impl EdgeMut<'_> {
+    pub fn label(self, label: impl Display) -> Self {
+        self.0.label = Some(label.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_nodes_once() {
+        let mut graph = Graph::undirected();
+        graph.edge("a", "b");
+        graph.edge("b", "c").label(3);
+        graph.node("b").kind("hub");
+        graph.node("d");
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.nodes[1].kind.as_deref(), Some("hub"));
+    }
+
+    #[test]
+    fn chooses_format_by_extension() {
+        assert_eq!(Format::from_path(Path::new("a.gv")).ok(), Some(Format::Dot));
+        assert_eq!(
+            Format::from_path(Path::new("out/a.mmd")).ok(),
+            Some(Format::Mermaid)
+        );
+        assert!(Format::from_path(Path::new("a.svg")).is_err());
+    }
+}
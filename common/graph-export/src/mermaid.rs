@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{Graph, Shape};
+
+/// Mermaid ids are restricted to a few characters, so the nodes are called `n0`, `n1`, ...
+/// and show their names as labels.
+pub(crate) fn render(graph: &Graph) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let shape = graph.node_style(node).and_then(|style| style.shape);
+        let (open, close) = match shape {
+            None | Some(Shape::Box) => ("[", "]"),
+            Some(Shape::Rounded) => ("(", ")"),
+            Some(Shape::Circle) => ("((", "))"),
+            Some(Shape::Diamond) => ("{", "}"),
+            Some(Shape::Hexagon) => ("{{", "}}"),
+        };
+        writeln!(out, "  n{i}{open}{}{close}", quote(node.label())).unwrap();
+    }
+    let arrow = if graph.directed { "-->" } else { "---" };
+    for edge in graph.edges.iter() {
+        match &edge.label {
+            Some(label) => writeln!(
+                out,
+                "  n{} {arrow}|{}| n{}",
+                edge.from,
+                quote(label),
+                edge.to
+            ),
+            None => writeln!(out, "  n{} {arrow} n{}", edge.from, edge.to),
+        }
+        .unwrap();
+    }
+
+    let mut classes: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (i, node) in graph.nodes.iter().enumerate() {
+        if let Some(kind) = &node.kind {
+            if graph.styles.get(kind).is_some_and(|s| s.fill.is_some()) {
+                classes.entry(kind).or_default().push(format!("n{i}"));
+            }
+        }
+    }
+    for (kind, nodes) in classes {
+        let fill = graph.styles[kind].fill.as_deref().unwrap_or_default();
+        let class = class_name(kind);
+        writeln!(out, "  classDef {class} fill:{fill}").unwrap();
+        writeln!(out, "  class {} {class}", nodes.join(",")).unwrap();
+    }
+    out
+}
+
+/// A quoted label, which may contain any character but quotes. Line breaks become `<br>`.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "#quot;").replace('\n', "<br>"))
+}
+
+fn class_name(kind: &str) -> String {
+    kind.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, Shape, Style};
+
+    #[test]
+    fn renders_mermaid() {
+        let mut graph = Graph::directed();
+        graph.style(
+            "flip-flop",
+            Style::new().shape(Shape::Rounded).fill("#ffd27f"),
+        );
+        graph.style("output", Style::new().shape(Shape::Circle));
+        graph.node("broadcaster");
+        graph.node("a").label("%a").kind("flip-flop");
+        graph.node("rx").kind("output");
+        graph.node("b").kind("flip-flop");
+        graph.edge("broadcaster", "a");
+        graph.edge("a", "rx").label("say \"hi\"\nnow");
+        assert_eq!(
+            graph.to_mermaid(),
+            r#"flowchart LR
+  n0["broadcaster"]
+  n1("%a")
+  n2(("rx"))
+  n3("b")
+  n0 --> n1
+  n1 -->|"say #quot;hi#quot;<br>now"| n2
+  classDef flip_flop fill:#ffd27f
+  class n1,n3 flip_flop
+"#
+        );
+
+        let mut graph = Graph::undirected();
+        graph.edge("a", "b").label(7);
+        assert!(graph.to_mermaid().ends_with("  n0 ---|\"7\"| n1\n"));
+    }
+}
@@ -0,0 +1,400 @@
+//! A dense two dimensional grid shared by the puzzles of all years.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+
+mod pos;
+
+pub use pos::{Dir, Pos};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row major order.
+    ///
+    /// Panics if the number of cells is not `width * height`.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> anyhow::Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => bail!(
+                    "row {} has {} cells, expected {width}",
+                    height + 1,
+                    row.len()
+                ),
+                _ => {}
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a grid with one row per line, converting every character with `cell`. Blank
+    /// lines before and after the grid are ignored, those between its rows are an error.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+        let first = lines.iter().position(|line| !line.is_empty());
+        let last = lines.iter().rposition(|line| !line.is_empty());
+        let lines = match (first, last) {
+            (Some(first), Some(last)) => &lines[first..=last],
+            _ => &[],
+        };
+        if let Some(blank) = lines.iter().position(|line| line.is_empty()) {
+            bail!("row {} is empty", blank + 1);
+        }
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        cell(c).with_context(|| {
+                            format!("invalid cell {c:?} in row {}, column {}", row + 1, col + 1)
+                        })
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Sets a cell and returns `false` if `pos` is out of bounds.
+    pub fn set(&mut self, pos: Pos, value: T) -> bool {
+        match self.get_mut(pos) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Appends a row below the last one, which lets a grid grow as a puzzle unfolds. The
+    /// first row of an empty grid sets its width. Fails without changing the grid if the row
+    /// is empty or has a different width than the grid.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> anyhow::Result<()> {
+        let row: Vec<T> = row.into_iter().collect();
+        if row.is_empty() {
+            bail!("cannot push an empty row");
+        }
+        if self.height > 0 && row.len() != self.width {
+            bail!("row has {} cells, expected {}", row.len(), self.width);
+        }
+        self.width = row.len();
+        self.cells.extend(row);
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let a = self.index_of(a).expect("position out of bounds");
+        let b = self.index_of(b).expect("position out of bounds");
+        self.cells.swap(a, b);
+    }
+
+    /// The cells in row major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.width, "column out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// All positions in row major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::from((row, col))))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> + '_ {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// The orthogonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours4()
+            .into_iter()
+            .filter(|next| self.contains(*next))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that lie inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8()
+            .into_iter()
+            .filter(|next| self.contains(*next))
+    }
+
+    /// The first position in row major order whose cell matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// A view that repeats the grid infinitely in every direction, or `None` if the grid is
+    /// empty and there is nothing to repeat.
+    pub fn wrapping(&self) -> Option<Wrapping<'_, T>> {
+        (self.width > 0 && self.height > 0).then_some(Wrapping { grid: self })
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        let row = usize::try_from(pos.row).ok()?;
+        let col = usize::try_from(pos.col).ok()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_vec(width, height, vec![value; width * height])
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transposed(&self) -> Self {
+        self.remapped(self.height, self.width, |row, col| (col, row))
+    }
+
+    pub fn rotated_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remapped(self.height, self.width, |row, col| (height - 1 - col, row))
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.remapped(self.height, self.width, |row, col| (col, width - 1 - row))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flipped_horizontally(&self) -> Self {
+        let width = self.width;
+        self.remapped(self.width, self.height, |row, col| (row, width - 1 - col))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flipped_vertically(&self) -> Self {
+        let height = self.height;
+        self.remapped(self.width, self.height, |row, col| (height - 1 - row, col))
+    }
+
+    /// Builds a grid of the given size whose cell `(row, col)` is this grid's cell at
+    /// `source(row, col)`.
+    fn remapped(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let (row, col) = source(row, col);
+                self.cells[row * self.width + col].clone()
+            })
+            .collect();
+        Self::from_vec(width, height, cells)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos} out of bounds"))
+    }
+}
+
+/// Parses one cell per character, see [`Grid::parse_with`].
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> anyhow::Result<Self> {
+        Self::parse_with(input, |c| T::try_from(c).map_err(|e| anyhow!("{e}")))
+    }
+}
+
+/// Prints one line per row, without separators between the cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A view of a non-empty grid tiled infinitely in all directions, see [`Grid::wrapping`].
+#[derive(Debug, Clone, Copy)]
+pub struct Wrapping<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Wrapping<'_, T> {
+    /// Maps `pos` to the corresponding position inside the grid.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        Pos::new(
+            pos.row.rem_euclid(self.grid.height as isize),
+            pos.col.rem_euclid(self.grid.width as isize),
+        )
+    }
+}
+
+impl<T> Index<Pos> for Wrapping<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        &self.grid[self.wrap(pos)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "ab\ncd\nef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert_eq!("\nab\ncd\n\n".parse::<Grid<char>>().unwrap().height(), 2);
+        assert!("ab\n\ncd".parse::<Grid<char>>().is_err());
+        let digits = Grid::parse_with("12\n34", |c| {
+            c.to_digit(10).ok_or_else(|| anyhow!("not a digit"))
+        })
+        .unwrap();
+        assert_eq!(digits.cells(), [1, 2, 3, 4]);
+        assert!(Grid::parse_with("1x", |c| c.to_digit(10).context("not a digit")).is_err());
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transposed().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotated_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.flipped_horizontally().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flipped_vertically().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.rotated_clockwise().rotated_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn grows_row_by_row() {
+        let mut grid = Grid::default();
+        assert!(grid.push_row("".chars()).is_err());
+        grid.push_row("ab".chars()).unwrap();
+        grid.push_row("cd".chars()).unwrap();
+        assert!(grid.push_row("efg".chars()).is_err());
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+        assert_eq!(grid.cells().len(), 4);
+    }
+
+    #[test]
+    fn neighbours_and_views() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbours4(Pos::ORIGIN).collect();
+        assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Pos::new(1, 0)).count(), 5);
+        assert_eq!(grid.find(|c| *c == 'd'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        let wrapping = grid.wrapping().unwrap();
+        assert_eq!(wrapping[Pos::new(-1, -1)], 'f');
+        assert_eq!(wrapping[Pos::new(7, 4)], 'c');
+        assert!(Grid::<char>::default().wrapping().is_none());
+        assert!(Grid::new(3, 0, '.').wrapping().is_none());
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A grid coordinate. Rows grow downwards and columns to the right. Coordinates may be
+/// negative or out of bounds; the grid decides what lies there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// The neighbouring position in direction `dir`.
+    pub fn step(self, dir: Dir) -> Pos {
+        self + dir.delta()
+    }
+
+    /// The four orthogonal neighbours in the order up, right, down, left.
+    pub fn neighbours4(self) -> [Pos; 4] {
+        Dir::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The eight orthogonal and diagonal neighbours, clockwise starting top left.
+    pub fn neighbours8(self) -> [Pos; 8] {
+        [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+        ]
+        .map(|(row, col)| self + Pos::new(row, col))
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(isize, isize)> for Pos {
+    fn from((row, col): (isize, isize)) -> Self {
+        Pos::new(row, col)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos::new(row as isize, col as isize)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Add<Dir> for Pos {
+    type Output = Pos;
+
+    fn add(self, dir: Dir) -> Pos {
+        self.step(dir)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, other: Pos) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Dir> for Pos {
+    fn add_assign(&mut self, dir: Dir) {
+        *self = self.step(dir);
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, other: Pos) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Pos {
+    type Output = Pos;
+
+    fn mul(self, factor: isize) -> Pos {
+        Pos::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Pos {
+        Pos::new(-self.row, -self.col)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// All directions in clockwise order starting with [`Dir::Up`].
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn delta(self) -> Pos {
+        match self {
+            Dir::Up => Pos::new(-1, 0),
+            Dir::Right => Pos::new(0, 1),
+            Dir::Down => Pos::new(1, 0),
+            Dir::Left => Pos::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Right => Dir::Up,
+            Dir::Down => Dir::Right,
+            Dir::Left => Dir::Down,
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Right => Dir::Left,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir::Left | Dir::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Parses `^>v<` as well as `U`, `R`, `D`, `L`.
+    pub fn from_char(c: char) -> Option<Dir> {
+        match c {
+            '^' | 'U' => Some(Dir::Up),
+            '>' | 'R' => Some(Dir::Right),
+            'v' | 'D' => Some(Dir::Down),
+            '<' | 'L' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Pos::ORIGIN);
+        }
+        assert_eq!(Pos::new(2, 3) + Dir::Up, Pos::new(1, 3));
+        assert_eq!(Pos::new(2, 3).manhattan(Pos::new(-1, 4)), 4);
+        assert_eq!(Dir::from_char('<'), Some(Dir::Left));
+    }
+}
@@ -0,0 +1,122 @@
+//! Range arithmetic on integers: single [`Interval`]s, sets of them in [`IntervalSet`] and
+//! piecewise shifts of them in [`RangeMap`].
+//!
+//! Intervals are half-open, `start..end`. Puzzles mostly give inclusive bounds, which
+//! [`Interval::inclusive`] and the `From<RangeInclusive>` conversion turn into half-open ones.
+
+mod map;
+mod set;
+
+use std::fmt::Debug;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+pub use map::RangeMap;
+pub use set::IntervalSet;
+
+/// The integer types intervals can be made of.
+pub trait Integer: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+impl_integer!(i32, i64, i128, u32, u64, u128, usize);
+
+/// The integers from `start` up to, but excluding, `end`. Empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// The half-open interval `start..end`.
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval `first..=last`, which must not end at the maximum of `T`.
+    pub fn inclusive(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+
+    /// The largest integer in a non-empty interval.
+    pub fn last(&self) -> T {
+        self.end - T::ONE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// The number of integers in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals have an integer in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Whether all integers of `other` are in this interval.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// The integers in both intervals, which may be an empty interval.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::inclusive(*range.start(), *range.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_bounds() {
+        let a = Interval::inclusive(2, 4);
+        assert_eq!(a, Interval::new(2, 5));
+        assert_eq!(Interval::from(2..=4), a);
+        assert_eq!(Interval::from(2..5), a);
+        assert_eq!((a.len(), a.last()), (3, 4));
+        assert!(a.contains(4) && !a.contains(5));
+        assert_eq!(Interval::new(5, 2).len(), 0);
+
+        let b = Interval::inclusive(4, 8);
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&Interval::new(5, 8)));
+        assert_eq!(a.intersection(&b), Interval::new(4, 5));
+        assert!(b.covers(&Interval::inclusive(6, 8)));
+        assert!(!a.covers(&b));
+    }
+}
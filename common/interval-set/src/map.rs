@@ -0,0 +1,111 @@
+use crate::{Integer, Interval, IntervalSet};
+
+/// A piecewise shift of the integers: each source interval moves to a destination start, all
+/// other integers map to themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// Source intervals with their destination start, sorted and non-overlapping.
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Integer> RangeMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Maps `source.start` to `destination` and the rest of `source` along with it.
+    ///
+    /// # Panics
+    ///
+    /// If `source` overlaps an interval inserted before.
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        if source.is_empty() {
+            return;
+        }
+        let idx = self.pieces.partition_point(|(s, _)| s.end <= source.start);
+        if let Some((next, _)) = self.pieces.get(idx) {
+            assert!(
+                !next.overlaps(&source),
+                "{source:?} overlaps the mapped interval {next:?}"
+            );
+        }
+        self.pieces.insert(idx, (source, destination));
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let idx = self.pieces.partition_point(|(s, _)| s.end <= value);
+        match self.pieces.get(idx) {
+            Some(&(source, destination)) if source.contains(value) => {
+                destination + (value - source.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// The images of all integers in `set`.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let sources: IntervalSet<T> = self.pieces.iter().map(|&(source, _)| source).collect();
+        let mut result = set.difference(&sources);
+        for interval in set.iter() {
+            for &(source, destination) in self.pieces.iter() {
+                let common = interval.intersection(&source);
+                if !common.is_empty() {
+                    result.insert(Interval::new(
+                        destination + (common.start - source.start),
+                        destination + (common.end - source.start),
+                    ));
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: Integer> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> FromIterator<(Interval<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shifts_ranges() {
+        // 2023 day 5: seed-to-soil map
+        let map: RangeMap<u64> = [(Interval::new(98, 100), 50), (Interval::new(50, 98), 52)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(14), 14);
+        assert_eq!(map.get(100), 100);
+
+        let seeds = IntervalSet::from(Interval::new(45, 100));
+        let soil: Vec<_> = map.map_set(&seeds).iter().collect();
+        assert_eq!(soil, [Interval::new(45, 100)]);
+        let seeds = IntervalSet::from(Interval::new(96, 102));
+        let soil: Vec<_> = map.map_set(&seeds).iter().collect();
+        // 96 and 97 move to 98 and 99, 98 and 99 to 50 and 51
+        assert_eq!(soil, [Interval::new(50, 52), Interval::new(98, 102)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_overlaps() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::new(0, 10), 20);
+        map.insert(Interval::new(5, 15), 40);
+    }
+}
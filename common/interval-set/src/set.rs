@@ -0,0 +1,186 @@
+use crate::{Integer, Interval};
+
+/// A set of integers, stored as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The maximal intervals of the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// The number of integers in the set.
+    pub fn coverage(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |sum, interval| sum + interval.len())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.start <= value)
+    }
+
+    /// The smallest integer in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// The largest integer in the set.
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.last())
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // everything from the first interval ending at or after the start up to the last
+        // starting at or before the end merges with the new one
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if from < to {
+            merged.start = merged.start.min(self.intervals[from].start);
+            merged.end = merged.end.max(self.intervals[to - 1].end);
+        }
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let from = self.intervals.partition_point(|i| i.end <= interval.start);
+        let to = self.intervals.partition_point(|i| i.start < interval.end);
+        if from >= to {
+            return;
+        }
+        let before = Interval::new(self.intervals[from].start, interval.start);
+        let after = Interval::new(interval.end, self.intervals[to - 1].end);
+        let rest = [before, after].into_iter().filter(|i| !i.is_empty());
+        self.intervals.splice(from..to, rest);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.extend(other.iter());
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (
+            self.intervals.iter().peekable(),
+            other.intervals.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let common = x.intersection(y);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for interval in other.iter() {
+            result.remove(interval);
+        }
+        result
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Integer> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(a, b)| Interval::new(a, b))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn merges_intervals() {
+        let s = set(&[(6, 9), (1, 3), (2, 6), (12, 14), (9, 10), (20, 20)]);
+        assert_eq!(intervals(&s), [(1, 10), (12, 14)]);
+        assert_eq!(s.coverage(), 11);
+        assert!(s.contains(1) && s.contains(9) && !s.contains(10) && !s.contains(0));
+        assert_eq!((s.min(), s.max()), (Some(1), Some(13)));
+
+        let mut s = s;
+        s.insert(Interval::new(9, 13));
+        assert_eq!(intervals(&s), [(1, 14)]);
+        s.remove(Interval::new(3, 5));
+        s.remove(Interval::new(13, 20));
+        assert_eq!(intervals(&s), [(1, 3), (5, 13)]);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(intervals(&a.union(&b)), [(0, 20)]);
+        assert_eq!(intervals(&a.intersection(&b)), [(3, 5), (10, 12), (14, 15)]);
+        assert_eq!(intervals(&a.difference(&b)), [(0, 3), (12, 14)]);
+        assert_eq!(intervals(&b.difference(&a)), [(5, 10), (15, 20)]);
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+}
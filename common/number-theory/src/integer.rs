@@ -0,0 +1,113 @@
+use std::fmt::Debug;
+
+/// The integer operations the algorithms need. The checked operations return `None` where the
+/// result doesn't fit the type, which never happens for big integers.
+pub trait Integer: Clone + Ord + Debug {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_neg(&self) -> Option<Self>;
+    /// Truncating division and remainder, `None` for a zero divisor.
+    fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+
+    fn checked_abs(&self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self.clone())
+        }
+    }
+
+    /// The remainder in `0..modulus`, for a positive `modulus`.
+    fn rem_euclid(&self, modulus: &Self) -> Self {
+        let (_, rem) = self
+            .checked_div_rem(modulus)
+            .expect("the modulus has to be positive");
+        if rem.is_negative() {
+            rem.checked_add(modulus).unwrap()
+        } else {
+            rem
+        }
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
+
+            fn checked_neg(&self) -> Option<Self> {
+                <$t>::checked_neg(*self)
+            }
+
+            fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+                Some((<$t>::checked_div(*self, *rhs)?, <$t>::checked_rem(*self, *rhs)?))
+            }
+        }
+    )*};
+}
+
+impl_primitive!(i32, i64, i128);
+
+#[cfg(feature = "bigint")]
+impl Integer for num_bigint::BigInt {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::from(1)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+
+    fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some((self / rhs, self % rhs))
+        }
+    }
+}
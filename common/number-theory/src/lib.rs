@@ -0,0 +1,229 @@
+//! Greatest common divisors, modular arithmetic and the chinese remainder theorem for `i32`,
+//! `i64`, `i128` and, with the `bigint` feature, `num_bigint::BigInt`.
+//!
+//! Nothing here overflows silently: functions whose result may not fit the integer type return
+//! `None` or [`CrtError::Overflow`] instead, so switching a day to `i128` is the fix.
+
+mod integer;
+
+use std::fmt::{self, Display};
+
+pub use integer::Integer;
+
+/// The non-negative greatest common divisor, with `gcd(0, 0) == 0`.
+///
+/// # Panics
+///
+/// If the result doesn't fit, which only happens for `gcd(MIN, 0)` and `gcd(MIN, MIN)`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while !b.is_zero() {
+        let rem = rem(&a, &b);
+        a = b;
+        b = rem;
+    }
+    a.checked_abs()
+        .expect("the gcd doesn't fit the integer type")
+}
+
+/// The non-negative least common multiple, or `None` if it doesn't fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+    let (quotient, _) = a.checked_div_rem(&gcd(a.clone(), b.clone()))?;
+    quotient.checked_mul(&b)?.checked_abs()
+}
+
+/// The least common multiple of all `numbers`, which is 1 for none at all.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::one(), lcm)
+}
+
+/// The extended euclidean algorithm: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+/// Returns `None` on overflow, which is only possible for arguments close to `MIN`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let (quotient, rem) = old_r.checked_div_rem(&r)?;
+        old_r = std::mem::replace(&mut r, rem);
+        let next_x = old_x.checked_sub(&quotient.checked_mul(&x)?)?;
+        old_x = std::mem::replace(&mut x, next_x);
+        let next_y = old_y.checked_sub(&quotient.checked_mul(&y)?)?;
+        old_y = std::mem::replace(&mut y, next_y);
+    }
+    if old_r.is_negative() {
+        Some((
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        ))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::zero() {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(&modulus), modulus.clone())?;
+    (g == T::one()).then(|| x.rem_euclid(&modulus))
+}
+
+/// `a * b mod modulus` in `0..modulus`, for a positive `modulus`. Works for any modulus that
+/// fits the type, even where the product doesn't.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    let (mut a, mut b) = (a.rem_euclid(&modulus), b.rem_euclid(&modulus));
+    if let Some(product) = a.checked_mul(&b) {
+        return product.rem_euclid(&modulus);
+    }
+    // double and add, which only ever needs numbers below the modulus
+    let two = T::one().checked_add(&T::one()).unwrap();
+    let mut result = T::zero();
+    while !b.is_zero() {
+        let (half, bit) = b.checked_div_rem(&two).unwrap();
+        if !bit.is_zero() {
+            result = add_mod(result, a.clone(), &modulus);
+        }
+        a = add_mod(a.clone(), a, &modulus);
+        b = half;
+    }
+    result
+}
+
+/// `a + b mod modulus` for `a` and `b` in `0..modulus`, without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, modulus: &T) -> T {
+    let gap = modulus.checked_sub(&b).unwrap();
+    if a >= gap {
+        a.checked_sub(&gap).unwrap()
+    } else {
+        a.checked_add(&b).unwrap()
+    }
+}
+
+/// The truncated remainder of `a / b` for a non-zero `b`. It always fits, but computing it
+/// overflows for `MIN % -1`, which is 0.
+fn rem<T: Integer>(a: &T, b: &T) -> T {
+    a.checked_div_rem(b).map_or_else(T::zero, |(_, rem)| rem)
+}
+
+/// Why a system of congruences couldn't be solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// Two congruences contradict each other, which is only possible if their moduli aren't
+    /// coprime.
+    NoSolution,
+    /// The combined modulus doesn't fit the integer type.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "the congruences have no common solution"),
+            CrtError::Overflow => write!(f, "the combined modulus doesn't fit the integer type"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves the system `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs. The moduli
+/// don't need to be coprime. Returns the smallest non-negative solution and the lcm of the
+/// moduli, the period of all solutions.
+///
+/// # Panics
+///
+/// If a modulus isn't positive.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Result<(T, T), CrtError> {
+    let mut solution = (T::zero(), T::one());
+    for (residue, modulus) in congruences {
+        assert!(modulus > T::zero(), "the moduli have to be positive");
+        solution = combine(solution, (residue.rem_euclid(&modulus), modulus))?;
+    }
+    Ok(solution)
+}
+
+/// Merges two congruences with residues in `0..modulus` into one.
+fn combine<T: Integer>((r1, m1): (T, T), (r2, m2): (T, T)) -> Result<(T, T), CrtError> {
+    let (g, p, _) = extended_gcd(m1.clone(), m2.clone()).ok_or(CrtError::Overflow)?;
+    let (quotient, rest) = r2.checked_sub(&r1).unwrap().checked_div_rem(&g).unwrap();
+    if !rest.is_zero() {
+        return Err(CrtError::NoSolution);
+    }
+    let (m2_g, _) = m2.checked_div_rem(&g).unwrap();
+    let modulus = m1.checked_mul(&m2_g).ok_or(CrtError::Overflow)?;
+    // x = r1 + m1 * k with m1 * k ≡ r2 - r1 (mod m2), which stays below the new modulus
+    let k = mul_mod(quotient, p, m2_g);
+    let x = r1.checked_add(&m1.checked_mul(&k).unwrap()).unwrap();
+    Ok((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -7), 7);
+        assert_eq!(gcd(i64::MIN, -1), 1);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm_all([3931i64, 3907, 3989, 3967]), Some(243037165713371));
+        assert_eq!(lcm(i64::MAX, 2), None);
+        assert_eq!(lcm(i64::MAX as i128, 2), Some(2 * i64::MAX as i128));
+    }
+
+    #[test]
+    fn computes_inverses() {
+        let (g, x, y) = extended_gcd(240, 46).unwrap();
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(mul_mod(i64::MAX - 1, i64::MAX - 2, i64::MAX), 2);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        // 2020 day 13: 7,13,x,x,59,x,31,19
+        let buses = [(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)];
+        assert_eq!(crt(buses), Ok((1068781i64, 7 * 13 * 59 * 31 * 19)));
+        // the moduli don't need to be coprime
+        assert_eq!(crt([(2, 6), (8, 10)]), Ok((8, 30)));
+        assert_eq!(crt([(1, 6), (2, 4)]), Err(CrtError::NoSolution));
+        assert_eq!(crt::<i32>([]), Ok((0, 1)));
+        let large = (i64::MAX - 25) as i128;
+        assert_eq!(
+            crt([(1, large), (3, large + 2)]),
+            Ok((1 + large * large / 2, large * (large + 2) / 2))
+        );
+        assert_eq!(
+            crt([(1i64, i64::MAX), (2, i64::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn supports_big_integers() {
+        use num_bigint::BigInt;
+
+        let big = |n: i128| BigInt::from(n);
+        let m1 = big(i128::MAX);
+        let m2 = big(i128::MAX - 1);
+        let (x, modulus) = crt([(big(1), m1.clone()), (big(2), m2.clone())]).unwrap();
+        assert_eq!(modulus, &m1 * &m2);
+        assert_eq!(x.rem_euclid(&m1), big(1));
+        assert_eq!(x.rem_euclid(&m2), big(2));
+        assert_eq!(gcd(big(-12), big(18)), big(6));
+    }
+}
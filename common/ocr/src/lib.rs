@@ -0,0 +1,107 @@
+//! Reads the block letters that some puzzles draw on a screen instead of printing an answer.
+//!
+//! Two fonts are known: the common one with letters 6 pixels high that are at most 5 pixels
+//! wide (usually 4 plus a blank column), and a large one with letters 10 pixels high on an
+//! 8 pixel pitch. Lit pixels are `#` or `█`, anything else is dark.
+
+use anyhow::{anyhow, bail};
+
+mod font;
+
+use font::{Font, LARGE, SMALL};
+
+/// Decodes an image whose first letter starts in its leftmost column. The font is picked
+/// from the number of rows.
+pub fn decode(image: &str) -> anyhow::Result<String> {
+    let rows: Vec<Vec<bool>> = image
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+    let font = match rows.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => bail!("no font is {height} pixels high"),
+    };
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let letters = width.div_ceil(font.advance);
+    let mut text = String::with_capacity(letters);
+    for letter in 0..letters {
+        let left = letter * font.advance;
+        let cell: Vec<Vec<bool>> = rows
+            .iter()
+            .map(|row| {
+                (left..left + font.advance)
+                    .map(|col| row.get(col).copied().unwrap_or(false))
+                    .collect()
+            })
+            .collect();
+        if cell.iter().flatten().all(|lit| !lit) && left + font.advance >= width {
+            break;
+        }
+        text.push(recognize(font, &cell).ok_or_else(|| {
+            anyhow!(
+                "unknown letter in columns {} to {}",
+                left,
+                left + font.advance - 1
+            )
+        })?);
+    }
+    Ok(text)
+}
+
+fn recognize(font: &Font, cell: &[Vec<bool>]) -> Option<char> {
+    font.glyphs
+        .iter()
+        .find(|(_, glyph)| {
+            glyph.lines().zip(cell).all(|(pattern, row)| {
+                let pattern: Vec<bool> = pattern.chars().map(|c| c == '#').collect();
+                row.iter()
+                    .enumerate()
+                    .all(|(col, lit)| pattern.get(col).copied().unwrap_or(false) == *lit)
+            })
+        })
+        .map(|(letter, _)| *letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_small_letters() {
+        let image = "\
+###..#..#.####.#...#
+#..#.#..#....#.#...#
+#..#.####...#...#.#.
+###..#..#..#.....#..
+#.#..#..#.#......#..
+#..#.#..#.####...#..";
+        assert_eq!(decode(image).unwrap(), "RHZY");
+        let spaced = image.replace('.', " ");
+        assert_eq!(decode(&spaced).unwrap(), "RHZY");
+    }
+
+    #[test]
+    fn decodes_large_letters() {
+        let image = "\
+#....#..#####.
+#....#..#....#
+.#..#...#....#
+.#..#...#....#
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..#.....";
+        assert_eq!(decode(image).unwrap(), "XP");
+    }
+
+    #[test]
+    fn rejects_unknown_shapes() {
+        assert!(decode("#\n#\n#").is_err());
+        let image = "#...\n.#..\n..#.\n...#\n..#.\n.#..";
+        assert!(decode(image).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+//! Parse errors that point at the offending spot of the puzzle input.
+//!
+//! The nom and winnow errors only know the unparsed rest of the input, which their `Display`
+//! dumps in full. [`ParseError`] turns that into a line and column and shows the line with a
+//! caret under the column where parsing failed. Enable the `nom` or `winnow` feature for the
+//! adapter matching the parser library of a day.
+
+use std::fmt::{self, Display};
+
+/// A failed parse of an input, located by line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    /// The line of the input the error is on.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at byte `offset` into `input`.
+    pub fn new(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |pos| offset + pos);
+        let message = message.into();
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: if message.is_empty() {
+                "unexpected input".to_string()
+            } else {
+                message
+            },
+        }
+    }
+
+    /// An error at the start of `rest`, which has to be a suffix of `input` as returned by
+    /// the parsers.
+    pub fn at_rest(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        Self::new(input, input.len().saturating_sub(rest.len()), message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{number} | {}", self.snippet)?;
+        write!(f, "{margin} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs a nom parser on the whole input. What the parser leaves unparsed is ignored.
+#[cfg(feature = "nom")]
+pub fn nom<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> nom::IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(
+            input,
+            input.len(),
+            "unexpected end of input",
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at_rest(
+            input,
+            e.input,
+            format!("expected {}", e.code.description().to_lowercase()),
+        )),
+    }
+}
+
+/// Runs a winnow parser, which has to consume the whole input.
+#[cfg(feature = "winnow")]
+pub fn winnow<'a, T, E>(
+    input: &'a str,
+    mut parser: impl winnow::Parser<&'a str, T, E>,
+) -> Result<T, ParseError>
+where
+    E: winnow::error::ParserError<&'a str>,
+    E::Inner: winnow::error::ParserError<&'a str> + Display,
+{
+    parser.parse(input).map_err(|e| {
+        let message = e.inner().to_string().replace('\n', ", ");
+        ParseError::new(input, e.offset(), message)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_column() {
+        let err = ParseError::new("1 2\n3 x 4\n", 6, "expected digit");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected digit\n2 | 3 x 4\n  |   ^"
+        );
+
+        let err = ParseError::new("1 2\n", 4, "");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: unexpected input\n2 | \n  | ^"
+        );
+    }
+
+    #[cfg(feature = "nom")]
+    #[test]
+    fn locates_nom_errors() {
+        use ::nom::character::complete::{i64, newline, space1};
+        use ::nom::multi::separated_list1;
+
+        let numbers = |input| separated_list1(newline, separated_list1(space1, i64))(input);
+        assert_eq!(
+            nom("1 2\n3 4", numbers).unwrap(),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+        let err = nom("x 2\n3 4", numbers).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected digit\n1 | x 2\n  | ^"
+        );
+    }
+
+    #[cfg(feature = "winnow")]
+    #[test]
+    fn locates_winnow_errors() {
+        use ::winnow::ascii::{dec_uint, newline};
+        use ::winnow::combinator::separated;
+        use ::winnow::{ModalResult, Parser};
+
+        fn numbers(input: &mut &str) -> ModalResult<Vec<u32>> {
+            separated(1.., dec_uint::<_, u32, _>, newline).parse_next(input)
+        }
+
+        assert_eq!(winnow("1\n2", numbers).unwrap(), vec![1, 2]);
+        let err = winnow("1\n2\n3x", numbers).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.snippet, "3x");
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::reconstruct;
+
+/// Breadth first search. Returns the shortest path from `start` to the first node for which
+/// `success` holds, including both ends, or `None` if no such node is reachable.
+pub fn bfs<N, FN, IN, FS>(start: &N, mut successors: FN, mut success: FS) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![usize::MAX];
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if success(&nodes[index]) {
+            return Some(reconstruct(&nodes, |i| parents[i], index));
+        }
+        for next in successors(&nodes[index]) {
+            if let Entry::Vacant(entry) = seen.entry(next) {
+                nodes.push(entry.key().clone());
+                parents.push(index);
+                entry.insert(nodes.len() - 1);
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{cell, successors};
+
+    #[test]
+    fn finds_path_with_fewest_steps() {
+        let neighbours = |c: &_| successors(c).into_iter().map(|(next, _)| next);
+        let path = bfs(&cell('S'), neighbours, |c| *c == cell('E')).unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[0], cell('S'));
+        assert_eq!(bfs(&cell('S'), neighbours, |_| false), None);
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Counts the distinct paths from `start` to nodes for which `success` holds.
+///
+/// The graph must be acyclic. A path ends at the first goal it reaches.
+pub fn count_paths<N, FN, IN, FS>(start: N, mut successors: FN, mut success: FS) -> usize
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    count(start, &mut successors, &mut success, &mut HashMap::new())
+}
+
+fn count<N, FN, IN, FS>(
+    node: N,
+    successors: &mut FN,
+    success: &mut FS,
+    cache: &mut HashMap<N, usize>,
+) -> usize
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+{
+    if success(&node) {
+        return 1;
+    }
+    if let Some(&paths) = cache.get(&node) {
+        return paths;
+    }
+    let paths = successors(&node)
+        .into_iter()
+        .map(|next| count(next, successors, success, cache))
+        .sum();
+    cache.insert(node, paths);
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_lattice_paths() {
+        // Moving only right or down through a 3x3 lattice.
+        let successors = |&(x, y): &(u32, u32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x <= 3 && y <= 3)
+        };
+        assert_eq!(count_paths((0, 0), successors, |&p| p == (3, 3)), 20);
+    }
+}
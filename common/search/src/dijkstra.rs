@@ -0,0 +1,329 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::reconstruct;
+
+/// A node waiting in the priority queue. The heap is a max-heap, so the ordering is reversed
+/// to pop the smallest estimate first. Ties go to the node that is furthest along.
+#[derive(Debug, PartialEq, Eq)]
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The nodes found by a search, numbered in the order they were discovered, with the best
+/// known parent and cost of each. The start node has index 0 and no parent.
+struct Explored<N, C> {
+    nodes: Vec<N>,
+    parents: Vec<(usize, C)>,
+}
+
+/// A* search from `start` until `stop` holds for a node taken off the queue, which is returned.
+/// Without a stop the whole reachable graph is explored.
+fn explore<N, C, FN, IN, FH, FS>(
+    start: &N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut stop: FS,
+) -> (Explored<N, C>, Option<usize>)
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let mut explored = Explored {
+        nodes: vec![start.clone()],
+        parents: vec![(usize::MAX, C::default())],
+    };
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Candidate {
+        estimate: heuristic(start),
+        cost: C::default(),
+        index: 0,
+    }]);
+
+    while let Some(Candidate { cost, index, .. }) = queue.pop() {
+        if cost > explored.parents[index].1 {
+            continue;
+        }
+        if stop(&explored.nodes[index]) {
+            return (explored, Some(index));
+        }
+        for (next, step) in successors(&explored.nodes[index]) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    explored.nodes.push(entry.key().clone());
+                    explored.parents.push((index, next_cost));
+                    *entry.insert(explored.nodes.len() - 1)
+                }
+                Entry::Occupied(entry) => {
+                    let known = &mut explored.parents[*entry.get()];
+                    if next_cost >= known.1 {
+                        continue;
+                    }
+                    *known = (index, next_cost);
+                    *entry.get()
+                }
+            };
+            queue.push(Candidate {
+                estimate: next_cost + heuristic(&explored.nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+    (explored, None)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal and must never overestimate
+/// it. Returns the cheapest path to a node for which `success` holds, including both ends,
+/// together with its cost.
+pub fn astar<N, C, FN, IN, FH, FS>(
+    start: &N,
+    successors: FN,
+    heuristic: FH,
+    success: FS,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FS: FnMut(&N) -> bool,
+{
+    let (explored, goal) = explore(start, successors, heuristic, success);
+    let goal = goal?;
+    let path = reconstruct(&explored.nodes, |i| explored.parents[i].0, goal);
+    Some((path, explored.parents[goal].1))
+}
+
+/// Dijkstra's algorithm. Returns the cheapest path to a node for which `success` holds,
+/// including both ends, together with its cost.
+pub fn dijkstra<N, C, FN, IN, FS>(start: &N, successors: FN, success: FS) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Runs Dijkstra's algorithm over everything reachable from `start`. Maps every reached node
+/// except the start to its parent on a cheapest path and the cost of that path. See
+/// [`build_path`](crate::build_path) to turn the result into paths.
+pub fn dijkstra_all<N, C, FN, IN>(start: &N, successors: FN) -> HashMap<N, (N, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (explored, _) = explore(start, successors, |_| C::default(), |_| false);
+    let Explored { nodes, parents } = explored;
+    parents
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(index, &(parent, cost))| (nodes[index].clone(), (nodes[parent].clone(), cost)))
+        .collect()
+}
+
+/// Every cheapest path from a start node to the goals, see [`all_shortest_paths`].
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    cost: C,
+    nodes: Vec<N>,
+    costs: Vec<C>,
+    parents: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord,
+{
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// The goals reached at the optimal cost.
+    pub fn goals(&self) -> impl Iterator<Item = &N> + '_ {
+        self.goals.iter().map(|&index| &self.nodes[index])
+    }
+
+    /// All nodes that lie on at least one cheapest path.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut seen: HashSet<usize> = self.goals.iter().copied().collect();
+        let mut stack = self.goals.clone();
+        while let Some(index) = stack.pop() {
+            for &parent in self.parents[index].iter() {
+                if seen.insert(parent) {
+                    stack.push(parent);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|index| self.nodes[index].clone())
+            .collect()
+    }
+
+    /// The number of distinct cheapest paths.
+    pub fn count(&self) -> usize {
+        // Edges have a positive cost, so every parent is cheaper than its children.
+        let mut order: Vec<usize> = (1..self.nodes.len()).collect();
+        order.sort_by_key(|&index| self.costs[index]);
+        let mut counts = vec![0; self.nodes.len()];
+        counts[0] = 1;
+        for index in order {
+            counts[index] = self.parents[index].iter().map(|&p| counts[p]).sum();
+        }
+        self.goals.iter().map(|&goal| counts[goal]).sum()
+    }
+}
+
+/// Dijkstra's algorithm keeping every cheapest path instead of just one. Finds all goals for
+/// which `success` holds that can be reached at the lowest cost.
+///
+/// Edges must have a positive cost.
+pub fn all_shortest_paths<N, C, FN, IN, FS>(
+    start: &N,
+    mut successors: FN,
+    mut success: FS,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut costs = vec![C::default()];
+    let mut parents: Vec<Vec<usize>> = vec![Vec::new()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Candidate {
+        estimate: C::default(),
+        cost: C::default(),
+        index: 0,
+    }]);
+    let mut best = None;
+    let mut goals = Vec::new();
+
+    while let Some(Candidate { cost, index, .. }) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if cost > costs[index] {
+            continue;
+        }
+        if success(&nodes[index]) {
+            best = Some(cost);
+            goals.push(index);
+            continue;
+        }
+        for (next, step) in successors(&nodes[index]) {
+            let next_cost = cost + step;
+            match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    nodes.push(entry.key().clone());
+                    costs.push(next_cost);
+                    parents.push(vec![index]);
+                    let next_index = *entry.insert(nodes.len() - 1);
+                    queue.push(Candidate {
+                        estimate: next_cost,
+                        cost: next_cost,
+                        index: next_index,
+                    });
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    match next_cost.cmp(&costs[next_index]) {
+                        Ordering::Less => {
+                            costs[next_index] = next_cost;
+                            parents[next_index] = vec![index];
+                            queue.push(Candidate {
+                                estimate: next_cost,
+                                cost: next_cost,
+                                index: next_index,
+                            });
+                        }
+                        Ordering::Equal => parents[next_index].push(index),
+                        Ordering::Greater => {}
+                    }
+                }
+            }
+        }
+    }
+
+    Some(ShortestPaths {
+        cost: best?,
+        nodes,
+        costs,
+        parents,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{cell, successors};
+
+    #[test]
+    fn finds_cheapest_path() {
+        let (path, cost) = dijkstra(&cell('S'), successors, |c| *c == cell('E')).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.last(), Some(&cell('E')));
+
+        let end = cell('E');
+        let manhattan = |&(r, c): &(usize, usize)| (r.abs_diff(end.0) + c.abs_diff(end.1)) as u32;
+        let (astar_path, astar_cost) =
+            astar(&cell('S'), successors, manhattan, |c| *c == end).unwrap();
+        assert_eq!((astar_path, astar_cost), (path, cost));
+    }
+
+    #[test]
+    fn keeps_all_cheapest_paths() {
+        // From 0 to 3 through 1 or 2 at the same cost, the direct edge is more expensive.
+        // Node 2 is found expensively first and improved later.
+        let successors = |n: &u32| match n {
+            0 => vec![(2, 5), (1, 1), (3, 4)],
+            1 => vec![(3, 2), (2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let paths = all_shortest_paths(&0, successors, |n| *n == 3).unwrap();
+        assert_eq!(paths.cost(), 3);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.nodes(), HashSet::from([0, 1, 2, 3]));
+        assert!(all_shortest_paths(&0, successors, |n| *n == 4).is_none());
+    }
+}
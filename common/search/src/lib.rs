@@ -0,0 +1,92 @@
+//! Graph searches over implicit graphs.
+//!
+//! None of the searches needs the graph up front. Nodes are any hashable values and the edges
+//! leaving a node are produced on demand by a `successors` closure, which returns the
+//! neighbouring nodes (for breadth first search) or the neighbours together with the cost of
+//! the edge (for the weighted searches). A `success` closure decides which nodes are goals.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+mod bfs;
+mod count;
+mod dijkstra;
+
+pub use bfs::bfs;
+pub use count::count_paths;
+pub use dijkstra::{all_shortest_paths, astar, dijkstra, dijkstra_all, ShortestPaths};
+
+/// Follows the parent links returned by [`dijkstra_all`] from `target` back to the start and
+/// returns the path from the start to `target`.
+pub fn build_path<N, C>(target: &N, parents: &HashMap<N, (N, C)>) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut path = vec![target.clone()];
+    while let Some((parent, _)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Walks the parent indices from `index` back to the root, whose parent is `usize::MAX`.
+fn reconstruct<N: Clone>(nodes: &[N], parents: impl Fn(usize) -> usize, index: usize) -> Vec<N> {
+    let mut path = Vec::new();
+    let mut index = index;
+    while index != usize::MAX {
+        path.push(nodes[index].clone());
+        index = parents(index);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The grid used by the tests. Walls are `#`, digits are the cost of entering a cell. The
+    /// direct route along the top is short but expensive.
+    pub(crate) const MAZE: &str = "\
+S99E
+1##1
+1111";
+
+    pub(crate) type Cell = (usize, usize);
+
+    pub(crate) fn cell(c: char) -> Cell {
+        MAZE.lines()
+            .enumerate()
+            .find_map(|(row, line)| line.find(c).map(|col| (row, col)))
+            .unwrap()
+    }
+
+    pub(crate) fn successors(&(row, col): &Cell) -> Vec<(Cell, u32)> {
+        let lines: Vec<&[u8]> = MAZE.lines().map(str::as_bytes).collect();
+        [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ]
+        .into_iter()
+        .filter_map(|(r, c)| match lines.get(r)?.get(c)? {
+            b'#' => None,
+            b'S' | b'E' => Some(((r, c), 1)),
+            digit => Some(((r, c), (digit - b'0') as u32)),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn builds_paths_from_parents() {
+        let parents = dijkstra_all(&cell('S'), successors);
+        assert_eq!(parents.len(), 9);
+        let end = cell('E');
+        assert_eq!(parents[&end].1, 7);
+        let path = build_path(&end, &parents);
+        assert_eq!(path.first(), Some(&cell('S')));
+        assert_eq!(path.len(), 8);
+    }
+}
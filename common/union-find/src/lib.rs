@@ -0,0 +1,192 @@
+//! A disjoint-set forest over the elements `0..n`, see
+//! <https://cp-algorithms.com/data_structures/disjoint_set_union.html>.
+
+/// Partitions `0..n` into components that can be merged, using union by size and path
+/// compression. The number of components and the size of each are kept up to date.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    /// Only valid for roots.
+    sizes: Vec<usize>,
+    count: usize,
+    /// The roots attached by each union, if unions can be rolled back.
+    history: Option<Vec<usize>>,
+}
+
+/// A state of a [`UnionFind`] to roll back to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot(usize);
+
+impl UnionFind {
+    /// `n` components with one element each.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            count: n,
+            history: None,
+        }
+    }
+
+    /// Like [`UnionFind::new`], but supports [`UnionFind::rollback`]. This gives up path
+    /// compression, so finding a root takes `O(log n)`.
+    pub fn with_rollback(n: usize) -> Self {
+        Self {
+            history: Some(Vec::new()),
+            ..Self::new(n)
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The root of the component containing `x`, which represents it until the next union.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        if self.history.is_none() {
+            let mut x = x;
+            while x != root {
+                x = std::mem::replace(&mut self.parents[x], root);
+            }
+        }
+        root
+    }
+
+    /// Merges the components containing `a` and `b`. Returns `false` if they already were the
+    /// same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] > self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[a] = b;
+        self.sizes[b] += self.sizes[a];
+        self.count -= 1;
+        if let Some(history) = &mut self.history {
+            history.push(a);
+        }
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the component containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.sizes[root]
+    }
+
+    /// The sizes of all components, in the order of their roots.
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.roots().map(|root| self.sizes[root])
+    }
+
+    /// The elements of all components, in the order of their roots.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut components = Vec::with_capacity(self.count);
+        for root in self.roots() {
+            index[root] = components.len();
+            components.push(Vec::with_capacity(self.sizes[root]));
+        }
+        for x in 0..self.len() {
+            let root = self.find(x);
+            components[index[root]].push(x);
+        }
+        components
+    }
+
+    fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|&x| self.parents[x] == x)
+    }
+
+    /// The current state, for [`UnionFind::rollback`].
+    ///
+    /// # Panics
+    ///
+    /// If not created by [`UnionFind::with_rollback`].
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.history().len())
+    }
+
+    /// Undoes all unions since `snapshot` was taken.
+    ///
+    /// # Panics
+    ///
+    /// If not created by [`UnionFind::with_rollback`].
+    pub fn rollback(&mut self, snapshot: Snapshot) {
+        while self.history().len() > snapshot.0 {
+            let child = self.history.as_mut().unwrap().pop().unwrap();
+            let root = self.parents[child];
+            self.sizes[root] -= self.sizes[child];
+            self.parents[child] = child;
+            self.count += 1;
+        }
+    }
+
+    fn history(&self) -> &[usize] {
+        self.history
+            .as_deref()
+            .expect("rollback needs a UnionFind created with_rollback")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_components() {
+        let mut uf = UnionFind::new(6);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert!(uf.union(1, 3));
+        assert!(!uf.union(0, 2));
+        assert!(uf.same(0, 3));
+        assert!(!uf.same(0, 4));
+        assert_eq!(uf.count(), 3);
+        assert_eq!(uf.size(2), 4);
+        let mut sizes: Vec<_> = uf.sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 1, 4]);
+        let mut components = uf.components();
+        components.sort();
+        assert_eq!(components, [vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn rolls_back() {
+        let mut uf = UnionFind::with_rollback(4);
+        uf.union(0, 1);
+        let snapshot = uf.snapshot();
+        uf.union(2, 3);
+        uf.union(0, 3);
+        assert_eq!(uf.count(), 1);
+        uf.rollback(snapshot);
+        assert_eq!(uf.count(), 3);
+        assert!(uf.same(0, 1));
+        assert!(!uf.same(0, 2));
+        assert!(!uf.same(2, 3));
+        assert_eq!(uf.size(1), 2);
+        assert_eq!(uf.size(3), 1);
+    }
+}
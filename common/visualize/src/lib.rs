@@ -0,0 +1,203 @@
+//! Renders grids and simulations as images: a [`Frame`] is a picture of one state, a
+//! [`Recorder`] collects the frames of a `step()` loop and writes them as PNG files or as an
+//! animated GIF.
+//!
+//! Most days already print their state with `Display`, so [`Frame::from_text`] turns that
+//! output into pixels by looking up every character in a [`Palette`].
+//!
+//! Days with a per-step API implement [`Simulation`], which can be recorded or, with the
+//! `terminal` feature, replayed live in the terminal.
+
+mod recorder;
+mod simulation;
+#[cfg(feature = "terminal")]
+pub mod terminal;
+
+use std::collections::HashMap;
+
+use grid::Grid;
+
+pub use recorder::Recorder;
+pub use simulation::{Playback, Simulation};
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// The colours of the characters in a text rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: HashMap<char, Rgb>,
+    background: Rgb,
+}
+
+impl Palette {
+    /// A palette that draws every character in `background`.
+    pub fn new(background: Rgb) -> Self {
+        Self {
+            colours: HashMap::new(),
+            background,
+        }
+    }
+
+    /// Draws `c` in `colour`.
+    pub fn with(mut self, c: char, colour: Rgb) -> Self {
+        self.colours.insert(c, colour);
+        self
+    }
+
+    pub fn colour(&self, c: char) -> Rgb {
+        self.colours.get(&c).copied().unwrap_or(self.background)
+    }
+
+    pub fn background(&self) -> Rgb {
+        self.background
+    }
+}
+
+/// An image with one pixel per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+    /// The pixel of the actor a camera should follow.
+    focus: Option<(usize, usize)>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, colour: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![colour; width * height],
+            focus: None,
+        }
+    }
+
+    /// Colours the pixel at column `x` and row `y` with `pixel(x, y)`.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut pixel: impl FnMut(usize, usize) -> Rgb,
+    ) -> Self {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| pixel(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+            focus: None,
+        }
+    }
+
+    /// One pixel per character and one row per line. Lines shorter than the longest one are
+    /// filled up with the background of the palette.
+    pub fn from_text(text: &str, palette: &Palette) -> Self {
+        let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let width = lines
+            .iter()
+            .map(|line| line.len())
+            .max()
+            .unwrap_or_default();
+        Self::from_fn(width, lines.len(), |x, y| match lines[y].get(x) {
+            Some(&c) => palette.colour(c),
+            None => palette.background(),
+        })
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.cells().iter().map(&mut colour).collect(),
+            focus: None,
+        }
+    }
+
+    /// Marks the pixel at column `x` and row `y` as the one to follow when only part of the
+    /// frame fits on the screen.
+    pub fn with_focus(mut self, x: usize, y: usize) -> Self {
+        self.focus = Some((x, y));
+        self
+    }
+
+    pub fn focus(&self) -> Option<(usize, usize)> {
+        self.focus
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    /// Every pixel becomes a square of `scale` by `scale` pixels.
+    pub fn scaled(&self, scale: usize) -> Self {
+        Self::from_fn(self.width * scale, self.height * scale, |x, y| {
+            self.get(x / scale, y / scale)
+        })
+    }
+
+    /// Places the frame in the top left corner of a `width` by `height` frame.
+    fn padded(&self, width: usize, height: usize, background: Rgb) -> Self {
+        Self::from_fn(width, height, |x, y| {
+            if x < self.width && y < self.height {
+                self.get(x, y)
+            } else {
+                background
+            }
+        })
+    }
+
+    /// The pixels as consecutive red, green and blue bytes.
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+    const BLACK: Rgb = [0, 0, 0];
+
+    #[test]
+    fn renders_text() {
+        let palette = Palette::new(BLACK).with('#', RED);
+        let frame = Frame::from_text("#.#\n.#", &palette);
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.get(0, 0), RED);
+        assert_eq!(frame.get(1, 0), BLACK);
+        assert_eq!(frame.get(1, 1), RED);
+        assert_eq!(frame.get(2, 1), BLACK);
+
+        let scaled = frame.scaled(3);
+        assert_eq!((scaled.width(), scaled.height()), (9, 6));
+        assert_eq!(scaled.get(5, 4), RED);
+        assert_eq!(scaled.get(5, 2), BLACK);
+    }
+
+    #[test]
+    fn renders_grids() -> anyhow::Result<()> {
+        let grid: Grid<char> = "#.\n.#".parse()?;
+        let frame = Frame::from_grid(&grid, |&c| if c == '#' { RED } else { BLACK });
+        assert_eq!(
+            frame,
+            Frame::from_text("#.\n.#", &Palette::new(BLACK).with('#', RED))
+        );
+        Ok(())
+    }
+}
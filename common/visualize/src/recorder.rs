@@ -0,0 +1,286 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+
+use crate::{Frame, Rgb, Simulation};
+
+/// Collects the frames of a simulation.
+///
+/// Long simulations can keep only every n-th state with [`Recorder::every`]. A typical loop
+/// captures each step and pushes the final state explicitly:
+///
+/// ```ignore
+/// let mut recorder = Recorder::new().every(10);
+/// while state.step() {
+///     recorder.capture(|| Frame::from_text(&state.to_string(), &palette));
+/// }
+/// recorder.push(Frame::from_text(&state.to_string(), &palette));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    scale: usize,
+    every: usize,
+    delay: u16,
+    background: Rgb,
+    captures: usize,
+}
+
+impl Recorder {
+    /// Records every frame at one pixel per cell, 10 frames per second.
+    pub fn new() -> Self {
+        Self {
+            frames: Vec::new(),
+            scale: 1,
+            every: 1,
+            delay: 10,
+            background: [0, 0, 0],
+            captures: 0,
+        }
+    }
+
+    /// Draws every cell as a square of `scale` by `scale` pixels.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Keeps only every `n`-th captured frame.
+    pub fn every(mut self, n: usize) -> Self {
+        self.every = n.max(1);
+        self
+    }
+
+    /// The time each frame of an animation is shown, in hundredths of a second.
+    pub fn delay(mut self, centiseconds: u16) -> Self {
+        self.delay = centiseconds;
+        self
+    }
+
+    /// Fills the space around frames smaller than the largest one.
+    pub fn background(mut self, colour: Rgb) -> Self {
+        self.background = colour;
+        self
+    }
+
+    /// Adds a frame unconditionally.
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    /// Adds the frame rendered by `render`, unless [`Recorder::every`] skips it. Skipped frames
+    /// are not rendered at all.
+    pub fn capture(&mut self, render: impl FnOnce() -> Frame) {
+        if self.captures.is_multiple_of(self.every) {
+            self.frames.push(render());
+        }
+        self.captures += 1;
+    }
+
+    /// Captures the current state of `simulation` and every state after a step until it has
+    /// finished. The final state is kept even if [`Recorder::every`] skips it.
+    pub fn record(mut self, simulation: &mut (impl Simulation + ?Sized)) -> Self {
+        self.capture(|| simulation.frame());
+        while simulation.step() {
+            self.capture(|| simulation.frame());
+        }
+        if !(self.captures - 1).is_multiple_of(self.every) {
+            self.push(simulation.frame());
+        }
+        self
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Writes an animated GIF to a `.gif` path, the last frame to a `.png` path and all frames
+    /// as numbered PNG files into any other path, which is taken as a directory.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gif") => self.write_gif(path),
+            Some("png") => self.write_png(path),
+            _ => self.write_pngs(path),
+        }
+    }
+
+    /// Writes the last frame.
+    pub fn write_png(&self, path: &Path) -> anyhow::Result<()> {
+        let Some(frame) = self.frames.last() else {
+            bail!("no frames recorded");
+        };
+        write_png(&frame.scaled(self.scale), path)
+    }
+
+    /// Writes the frames to `frame-0000.png`, `frame-0001.png`, ... in `dir`.
+    pub fn write_pngs(&self, dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            write_png(
+                &frame.scaled(self.scale),
+                &dir.join(format!("frame-{i:04}.png")),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes all frames as an animation that loops forever. GIFs have a single table of at
+    /// most 256 colours here, so the frames must not use more than that together.
+    pub fn write_gif(&self, path: &Path) -> anyhow::Result<()> {
+        if self.frames.is_empty() {
+            bail!("no frames recorded");
+        }
+        let width = self
+            .frames
+            .iter()
+            .map(|f| f.width)
+            .max()
+            .unwrap_or_default();
+        let height = self
+            .frames
+            .iter()
+            .map(|f| f.height)
+            .max()
+            .unwrap_or_default();
+        let gif_width = u16::try_from(width * self.scale).context("frames too wide for a GIF")?;
+        let gif_height = u16::try_from(height * self.scale).context("frames too high for a GIF")?;
+
+        let mut indices: HashMap<Rgb, u8> = HashMap::new();
+        let mut table = Vec::new();
+        let colours = self.frames.iter().flat_map(|f| f.pixels.iter());
+        for &colour in colours.chain([&self.background]) {
+            if indices.contains_key(&colour) {
+                continue;
+            }
+            let Ok(i) = u8::try_from(indices.len()) else {
+                bail!("frames use more than 256 colours");
+            };
+            indices.insert(colour, i);
+            table.extend(colour);
+        }
+
+        let file =
+            File::create(path).with_context(|| format!("cannot create {}", path.display()))?;
+        let mut encoder = gif::Encoder::new(BufWriter::new(file), gif_width, gif_height, &table)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for frame in self.frames.iter() {
+            let frame = frame
+                .padded(width, height, self.background)
+                .scaled(self.scale);
+            let buffer = frame.pixels.iter().map(|colour| indices[colour]).collect();
+            encoder.write_frame(&gif::Frame {
+                width: gif_width,
+                height: gif_height,
+                delay: self.delay,
+                buffer: Cow::Owned(buffer),
+                ..gif::Frame::default()
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_png(frame: &Frame, path: &Path) -> anyhow::Result<()> {
+    let file = File::create(path).with_context(|| format!("cannot create {}", path.display()))?;
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        u32::try_from(frame.width)?,
+        u32::try_from(frame.height)?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&frame.bytes())?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::Palette;
+
+    fn frames() -> Vec<Frame> {
+        let palette = Palette::new([0, 0, 0])
+            .with('#', [255, 0, 0])
+            .with('o', [0, 0, 255]);
+        ["#.\n.#", "#o\n.#", "#o\no#o"]
+            .iter()
+            .map(|text| Frame::from_text(text, &palette))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_every_nth_frame() {
+        let mut recorder = Recorder::new().every(2);
+        for frame in frames() {
+            recorder.capture(|| frame);
+        }
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(recorder.frames()[1], frames()[2]);
+    }
+
+    #[test]
+    fn writes_png() -> anyhow::Result<()> {
+        let mut recorder = Recorder::new().scale(2);
+        frames().into_iter().for_each(|f| recorder.push(f));
+        let path = env::temp_dir().join(format!("visualize-{}.png", std::process::id()));
+        recorder.save(&path)?;
+
+        let decoder = png::Decoder::new(File::open(&path)?);
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        fs::remove_file(&path)?;
+        assert_eq!((info.width, info.height), (6, 4));
+        assert_eq!(&buffer[2 * 3..3 * 3], [0, 0, 255]);
+        Ok(())
+    }
+
+    #[test]
+    fn writes_gif() -> anyhow::Result<()> {
+        let mut recorder = Recorder::new();
+        frames().into_iter().for_each(|f| recorder.push(f));
+        let path = env::temp_dir().join(format!("visualize-{}.gif", std::process::id()));
+        recorder.save(&path)?;
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path)?)?;
+        let mut sizes = Vec::new();
+        let mut last = Vec::new();
+        while let Some(frame) = decoder.read_next_frame()? {
+            sizes.push((frame.width, frame.height));
+            last = frame.buffer.to_vec();
+        }
+        fs::remove_file(&path)?;
+        // the first two frames are padded to the size of the last one
+        assert_eq!(sizes, [(3, 2); 3]);
+        assert_eq!(&last[5 * 4..6 * 4], [0, 0, 255, 255]);
+        Ok(())
+    }
+}
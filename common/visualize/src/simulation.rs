@@ -0,0 +1,95 @@
+use crate::Frame;
+
+/// A puzzle state that advances one step at a time, for recording or replaying it.
+pub trait Simulation {
+    /// Advances by one step. Returns `false`, without changing the state, once the simulation
+    /// has finished.
+    fn step(&mut self) -> bool;
+
+    /// A picture of the current state.
+    fn frame(&self) -> Frame;
+}
+
+/// Recorded frames played back as a simulation.
+#[derive(Debug, Clone)]
+pub struct Playback {
+    frames: Vec<Frame>,
+    current: usize,
+}
+
+impl Playback {
+    /// Plays `frames`, which must not be empty.
+    pub fn new(frames: Vec<Frame>) -> Self {
+        assert!(!frames.is_empty(), "nothing to play back");
+        Self { frames, current: 0 }
+    }
+}
+
+impl Simulation for Playback {
+    fn step(&mut self) -> bool {
+        if self.current + 1 < self.frames.len() {
+            self.current += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn frame(&self) -> Frame {
+        self.frames[self.current].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Recorder;
+
+    /// A dot moving right on a line of `width` pixels.
+    struct Dot {
+        x: usize,
+        width: usize,
+    }
+
+    impl Simulation for Dot {
+        fn step(&mut self) -> bool {
+            if self.x + 1 < self.width {
+                self.x += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        fn frame(&self) -> Frame {
+            let frame = Frame::from_fn(self.width, 1, |x, _| {
+                if x == self.x {
+                    [255, 255, 255]
+                } else {
+                    [0, 0, 0]
+                }
+            });
+            frame.with_focus(self.x, 0)
+        }
+    }
+
+    #[test]
+    fn records_simulations() {
+        let mut dot = Dot { x: 0, width: 6 };
+        let recorder = Recorder::new().every(2).record(&mut dot);
+        let positions: Vec<_> = recorder
+            .frames()
+            .iter()
+            .map(|f| f.focus().unwrap().0)
+            .collect();
+        // the final state is kept although every(2) skips it
+        assert_eq!(positions, [0, 2, 4, 5]);
+
+        let mut playback = Playback::new(recorder.frames().to_vec());
+        let mut played = vec![playback.frame()];
+        while playback.step() {
+            played.push(playback.frame());
+        }
+        assert_eq!(played, recorder.frames());
+    }
+}
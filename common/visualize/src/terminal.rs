@@ -0,0 +1,238 @@
+//! Live replay of a [`Simulation`] in the terminal.
+//!
+//! Every character cell shows two pixels stacked on top of each other, using a half block in
+//! the colour of the upper pixel on the background colour of the lower one. Frames larger than
+//! the terminal are shown through a viewport that follows the focus of the frame.
+
+use std::io::{self, BufWriter, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::{Frame, Rgb, Simulation};
+
+const HELP: &str = "space pause, n step, +/- speed, f follow, arrows move, q quit";
+
+/// Plays `simulation` at `fps` steps per second until it is quit. It starts paused, so that the
+/// initial state can be seen, and pauses again once the simulation has finished.
+pub fn replay(simulation: &mut dyn Simulation, fps: u32) -> anyhow::Result<()> {
+    let _screen = Screen::enter()?;
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    let mut frame = simulation.frame();
+    let mut delay = Duration::from_secs(1) / fps.max(1);
+    let mut steps = 0;
+    let mut paused = true;
+    let mut finished = false;
+    let mut follow = true;
+    let mut origin = (0, 0);
+    let mut next = Instant::now();
+
+    loop {
+        let (columns, rows) = terminal::size()?;
+        let view = (columns as usize, (rows as usize).saturating_sub(1) * 2);
+        let focus = frame.focus().filter(|_| follow);
+        origin = camera(origin, focus, (frame.width(), frame.height()), view);
+        draw(&mut out, &frame, origin, view)?;
+        let state = match (finished, paused) {
+            (true, _) => "finished",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        let status = format!(
+            "step {steps}, {state}, {:.1} fps{} | {HELP}",
+            1.0 / delay.as_secs_f64(),
+            if follow { ", following" } else { "" },
+        );
+        queue!(
+            out,
+            MoveTo(0, rows.saturating_sub(1)),
+            ResetColor,
+            Print(status.chars().take(columns as usize).collect::<String>()),
+            Clear(ClearType::UntilNewLine),
+        )?;
+        out.flush()?;
+
+        let mut advance = |frame: &mut Frame, steps: &mut usize| {
+            if simulation.step() {
+                *frame = simulation.frame();
+                *steps += 1;
+                false
+            } else {
+                true
+            }
+        };
+
+        let timeout = if paused {
+            Duration::from_secs(60)
+        } else {
+            next.saturating_duration_since(Instant::now())
+        };
+        if !event::poll(timeout)? {
+            if !paused {
+                finished = advance(&mut frame, &mut steps);
+                paused = finished;
+                next = Instant::now() + delay;
+            }
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let pan = (view.0 / 4).max(1) as isize;
+        let tilt = (view.1 / 4).max(1) as isize;
+        let mut moved = None;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char(' ') if !finished => {
+                paused = !paused;
+                next = Instant::now() + delay;
+            }
+            KeyCode::Char('n') | KeyCode::Char('.') if paused && !finished => {
+                finished = advance(&mut frame, &mut steps);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                delay = (delay / 2).max(Duration::from_millis(1));
+            }
+            KeyCode::Char('-') => delay = (delay * 2).min(Duration::from_secs(2)),
+            KeyCode::Char('f') => follow = !follow,
+            KeyCode::Left | KeyCode::Char('h') => moved = Some((-pan, 0)),
+            KeyCode::Right | KeyCode::Char('l') => moved = Some((pan, 0)),
+            KeyCode::Up | KeyCode::Char('k') => moved = Some((0, -tilt)),
+            KeyCode::Down | KeyCode::Char('j') => moved = Some((0, tilt)),
+            _ => {}
+        }
+        if let Some((dx, dy)) = moved {
+            follow = false;
+            origin = (
+                origin.0.saturating_add_signed(dx),
+                origin.1.saturating_add_signed(dy),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The top left pixel of a `view` sized part of a `size`d frame. When there is a `focus`, the
+/// view only moves once it is outside the middle half of the view, and then centres on it.
+fn camera(
+    origin: (usize, usize),
+    focus: Option<(usize, usize)>,
+    size: (usize, usize),
+    view: (usize, usize),
+) -> (usize, usize) {
+    let axis = |origin: usize, focus: Option<usize>, size: usize, view: usize| {
+        let origin = match focus {
+            Some(f) if f < origin + view / 4 || f >= origin + view - view / 4 => {
+                f.saturating_sub(view / 2)
+            }
+            _ => origin,
+        };
+        origin.min(size.saturating_sub(view))
+    };
+    (
+        axis(origin.0, focus.map(|f| f.0), size.0, view.0),
+        axis(origin.1, focus.map(|f| f.1), size.1, view.1),
+    )
+}
+
+/// Draws the `view` sized part of `frame` starting at `origin` from the top left corner of
+/// the terminal. Space outside the frame is left blank.
+fn draw(
+    out: &mut impl Write,
+    frame: &Frame,
+    origin: (usize, usize),
+    view: (usize, usize),
+) -> io::Result<()> {
+    let pixel =
+        |x: usize, y: usize| (x < frame.width() && y < frame.height()).then(|| frame.get(x, y));
+    let mut colours = None;
+    for row in 0..view.1 / 2 {
+        queue!(out, MoveTo(0, row as u16))?;
+        for column in 0..view.0 {
+            let (x, y) = (origin.0 + column, origin.1 + 2 * row);
+            let (c, foreground, background) = match (pixel(x, y), pixel(x, y + 1)) {
+                (Some(top), bottom) => ('▀', Some(top), bottom),
+                (None, Some(bottom)) => ('▄', Some(bottom), None),
+                (None, None) => (' ', None, None),
+            };
+            if colours != Some((foreground, background)) {
+                queue!(
+                    out,
+                    SetForegroundColor(colour(foreground)),
+                    SetBackgroundColor(colour(background)),
+                )?;
+                colours = Some((foreground, background));
+            }
+            queue!(out, Print(c))?;
+        }
+    }
+    queue!(out, ResetColor)
+}
+
+fn colour(rgb: Option<Rgb>) -> Color {
+    match rgb {
+        Some([r, g, b]) => Color::Rgb { r, g, b },
+        None => Color::Reset,
+    }
+}
+
+/// Raw mode on the alternate screen, restored when dropped, even when unwinding a panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn camera_follows_focus() {
+        let size = (100, 50);
+        let view = (20, 10);
+        // small moves inside the middle of the view keep the camera still
+        assert_eq!(camera((0, 0), Some((10, 5)), size, view), (0, 0));
+        assert_eq!(camera((0, 0), Some((14, 7)), size, view), (0, 0));
+        // leaving it recentres, but never beyond the edges of the frame
+        assert_eq!(camera((0, 0), Some((15, 5)), size, view), (5, 0));
+        assert_eq!(camera((0, 0), Some((99, 49)), size, view), (80, 40));
+        assert_eq!(camera((80, 40), Some((2, 2)), size, view), (0, 0));
+        // without focus the camera stays where it was panned to
+        assert_eq!(camera((30, 60), None, size, view), (30, 40));
+        // frames smaller than the view are not moved
+        assert_eq!(camera((0, 0), Some((8, 8)), (10, 10), view), (0, 0));
+    }
+
+    #[test]
+    fn draws_two_pixels_per_cell() -> io::Result<()> {
+        let frame = Frame::new(2, 3, [1, 2, 3]);
+        let mut out = Vec::new();
+        draw(&mut out, &frame, (0, 0), (3, 4))?;
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches('▀').count(), 4);
+        assert_eq!(out.matches(' ').count(), 2);
+        assert!(out.contains("2;1;2;3m"));
+        Ok(())
+    }
+}